@@ -4,6 +4,8 @@ use syn::meta::ParseNestedMeta;
 use syn::parse::ParseStream;
 use darling::ast::NestedMeta;
 use quote::format_ident;
+use darling::FromMeta;
+use crate::attrs::{HooksAttr, RawAttr, RelationAttr};
 
 pub trait AttrParser {
     fn extract_field_db_ident(field: &Field) -> Ident;
@@ -21,8 +23,17 @@ pub trait AttrParser {
 
     fn extract_template_sql(attrs: &Vec<Attribute>) -> Option<String>;
     fn extract_template_count_sql(attrs: &Vec<Attribute>) -> Option<String>;
+    fn extract_template_sql_file(attrs: &[Attribute]) -> Option<String>;
+    fn extract_template_count_sql_file(attrs: &[Attribute]) -> Option<String>;
+    fn extract_template_output(attrs: &[Attribute]) -> Option<String>;
 
     fn extract_unique_key(attrs: &Vec<Attribute>) -> Vec<Vec<String>>;
+
+    fn extract_relations(attrs: &[Attribute], name: &str) -> Vec<RelationAttr>;
+
+    fn extract_raw_attr(field: &Field) -> Option<RawAttr>;
+
+    fn extract_hooks(attrs: &[Attribute]) -> HooksAttr;
 }
 
 pub struct DefaultAttrParser {}
@@ -172,6 +183,15 @@ impl AttrParser for DefaultAttrParser {
     fn extract_template_count_sql(attrs: &Vec<Attribute>) -> Option<String> {
         <DefaultAttrParser as AttrParser>::extract_val_from_attrs(attrs, "count_sql")
     }
+    fn extract_template_sql_file(attrs: &[Attribute]) -> Option<String> {
+        attrs.iter().find_map(|attr| <DefaultAttrParser as AttrParser>::extract_val_from_attr(attr, "sql_file"))
+    }
+    fn extract_template_count_sql_file(attrs: &[Attribute]) -> Option<String> {
+        attrs.iter().find_map(|attr| <DefaultAttrParser as AttrParser>::extract_val_from_attr(attr, "count_sql_file"))
+    }
+    fn extract_template_output(attrs: &[Attribute]) -> Option<String> {
+        attrs.iter().find_map(|attr| <DefaultAttrParser as AttrParser>::extract_val_from_attr(attr, "output"))
+    }
 
     fn extract_unique_key(attrs: &Vec<Attribute>) -> Vec<Vec<String>> {
         let indexes =
@@ -182,4 +202,37 @@ impl AttrParser for DefaultAttrParser {
             .collect();
         return result;
     }
+
+    fn extract_relations(attrs: &[Attribute], name: &str) -> Vec<RelationAttr> {
+        attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(name))
+            .map(|attr| match RelationAttr::from_meta(&attr.meta) {
+                Ok(relation) => relation,
+                Err(err) => panic!("malformed #[{}] attribute: {}", name, err),
+            })
+            .collect()
+    }
+
+    fn extract_raw_attr(field: &Field) -> Option<RawAttr> {
+        field
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("raw"))
+            .map(|attr| match RawAttr::from_meta(&attr.meta) {
+                Ok(raw) => raw,
+                Err(err) => panic!("malformed #[raw] attribute, for example: #[raw(one_of = \"user,admin\")] or #[raw(keyword_of = \"ASC,DESC\")]: {}", err),
+            })
+    }
+
+    fn extract_hooks(attrs: &[Attribute]) -> HooksAttr {
+        attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("hooks"))
+            .map(|attr| match HooksAttr::from_meta(&attr.meta) {
+                Ok(hooks) => hooks,
+                Err(err) => panic!("malformed #[hooks] attribute, for example: #[hooks(entity, mutation)]: {}", err),
+            })
+            .unwrap_or_default()
+    }
 }
@@ -0,0 +1,23 @@
+use darling::FromMeta;
+
+/**
+#[hooks(entity, mutation, unique, location)]
+
+generated types listed return Some(self) from get_hooks, each of them must implement WriteHooks,
+and the entity must implement Clone, before hooks change a clone of the value borrowed by the write
+1. entity: the schema struct itself, hooks of insert
+2. mutation: the generated Mutation, hooks of update
+3. unique: the generated Primary and all unique key structs, hooks of delete
+4. location: the generated Location, hooks of purify
+*/
+#[derive(Debug, Clone, Default, FromMeta)]
+pub struct HooksAttr {
+    #[darling(default)]
+    pub entity: bool,
+    #[darling(default)]
+    pub mutation: bool,
+    #[darling(default)]
+    pub unique: bool,
+    #[darling(default)]
+    pub location: bool,
+}
@@ -1,4 +1,10 @@
 mod attr_parser;
+mod relation_attr;
+mod raw_attr;
+mod hooks_attr;
 
 pub use attr_parser::AttrParser;
 pub use attr_parser::DefaultAttrParser;
+pub use relation_attr::RelationAttr;
+pub use raw_attr::RawAttr;
+pub use hooks_attr::HooksAttr;
@@ -0,0 +1,25 @@
+use darling::FromMeta;
+
+/**
+#[raw(one_of = "user,admin")] or #[raw(keyword_of = "ASC,DESC")]
+
+field rendered by ${field} in template sql
+1. one_of: the value must be one of the listed identifiers, and is quoted by wrap char of the dialect
+2. keyword_of: the value must be one of the listed keywords, and is rendered verbatim without quoting
+*/
+#[derive(Debug, Clone, FromMeta)]
+pub struct RawAttr {
+    #[darling(default)]
+    pub one_of: Option<String>,
+    #[darling(default)]
+    pub keyword_of: Option<String>,
+}
+
+impl RawAttr {
+    pub fn allowed_values(list: &str) -> Vec<String> {
+        list.split(',')
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect()
+    }
+}
@@ -0,0 +1,20 @@
+use darling::FromMeta;
+
+/**
+#[has_many(name = "orders", table = "order", foreign_key = "user_id")]
+#[has_one(name = "profile", table = "profile", foreign_key = "user_id")]
+#[belongs_to(name = "user", table = "user", foreign_key = "user_id", references = "id")]
+
+1. name: suffix of the generated loaders, load_orders and load_many_orders
+2. table: table name of the related schema, its Location and SelectedEntity are used to load
+3. foreign_key: field of the child side, which is the related schema for has_many/has_one and self for belongs_to
+4. references: field of the parent side, default to the single primary key for has_many/has_one and id for belongs_to
+*/
+#[derive(Debug, Clone, FromMeta)]
+pub struct RelationAttr {
+    pub name: String,
+    pub table: String,
+    pub foreign_key: String,
+    #[darling(default)]
+    pub references: Option<String>,
+}
@@ -8,6 +8,7 @@ use crate::types::{DefaultTypeExtractor, TypeExtractor};
 use proc_macro2::Ident;
 use quote::quote;
 use syn::{Attribute, FieldsNamed};
+use super::hooks_expander::{generate_hooks_fn, HooksTarget};
 
 fn validate_primary_fields(fields: &FieldsNamed) {
     let primary_fields = FieldsParser::from_named(fields).filter_annotated_fields("primary_key");
@@ -16,7 +17,7 @@ fn validate_primary_fields(fields: &FieldsNamed) {
     }
 
     for field in primary_fields {
-        let is_generated = DefaultAttrParser::check_has_attr(&field.attrs, "Generated");
+        let is_generated = DefaultAttrParser::check_has_attr(&field.attrs, "generated");
         let is_auto = DefaultAttrParser::check_has_attr(&field.attrs, "auto_increment");
         if DefaultTypeChecker::type_is_option(&field.ty) {
             if (!is_generated) && (!is_auto) {
@@ -36,16 +37,16 @@ fn validate_primary_fields(fields: &FieldsNamed) {
     if auto_fields.len() == 1 {
         let auto_field = auto_fields.first().unwrap();
         if !<DefaultTypeChecker as TypeChecker>::type_is_option(&auto_field.ty) {
-            panic!("auto_increment Field should be Option<i64>");
+            panic!("auto_increment Field should be Option of integer, such as Option<i64>");
         }
         let auto_field_inner_type =
             <DefaultTypeExtractor as TypeExtractor>::get_option_inner_type(&auto_field.ty);
         let auto_field_inner_type = auto_field_inner_type.unwrap();
         if !<DefaultTypeChecker as TypeChecker>::type_has_one_of_names(
             auto_field_inner_type,
-            &["i64"],
+            &["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"],
         ) {
-            panic!("auto_increment Field should be Option<i64>");
+            panic!("auto_increment Field should be Option of integer, such as Option<i64>");
         }
     }
 }
@@ -67,12 +68,32 @@ pub fn generate_entity_impl(
     let upsert_set_fields_name = FieldsParser::from_named(fields).get_upsert_set_fields();
     let auto_field_token = FieldsParser::from_named(fields).get_auto_increment_field();
     let set_auto_field_token = FieldsParser::from_named(fields).set_auto_increment_field();
+    let primary_key_names = FieldsParser::from_named(fields).get_primary_key_names();
+    let generated_fields_name = FieldsParser::from_named(fields).get_generated_fields();
+    let set_generated_fields_token = FieldsParser::from_named(fields).set_generated_fields();
+    let fill_timestamps_token = FieldsParser::from_named(fields).fill_auto_timestamps();
+    let unique_keys = DefaultAttrParser::extract_unique_key(attrs);
+    let reselect_stream = generate_reselect_impl(fields, &unique_keys);
     let insert_args_sqlite = FieldsParser::from_named(fields).gen_insert_arguments_sqlite();
     let insert_args_mysql = FieldsParser::from_named(fields).gen_insert_arguments_mysql();
     let insert_args_postgres = FieldsParser::from_named(fields).gen_insert_arguments_postgres();
+    let add_insert_args = FieldsParser::from_named(fields).add_insert_arguments();
     let upsert_args_sqlite = FieldsParser::from_named(fields).gen_upsert_arguments_sqlite();
     let upsert_args_mysql = FieldsParser::from_named(fields).gen_upsert_arguments_mysql();
     let upsert_args_postgres = FieldsParser::from_named(fields).gen_upsert_arguments_postgres();
+    let entity_hooks = DefaultAttrParser::extract_hooks(attrs).entity;
+    let hooks_stream = generate_hooks_fn(entity_hooks, HooksTarget::Entity);
+    let has_auto_timestamps = !fill_timestamps_token.is_empty();
+    let fill_timestamps_stream = if has_auto_timestamps {
+        quote! {
+            fn fill_auto_timestamps(&mut self, now: sqlx::types::time::OffsetDateTime) {
+                #fill_timestamps_token
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let clone_stream = generate_clone_for_writes(entity_hooks || has_auto_timestamps);
 
     let output = quote! {
         impl taitan_orm::traits::Entity for #ident {
@@ -97,10 +118,40 @@ pub fn generate_entity_impl(
                 #set_auto_field_token
             }
 
+            fn get_primary_key_names(&self) -> &'static [&'static str] {
+                #primary_key_names
+            }
+
+            fn get_generated_fields(&self) -> Vec<taitan_orm::FieldName> {
+                #generated_fields_name
+            }
+
+            #fill_timestamps_stream
+
+            #clone_stream
+
+            #reselect_stream
+
+            fn set_generated_fields_sqlite(&mut self, row: &sqlx::sqlite::SqliteRow) -> Result<(), sqlx::Error> {
+                #set_generated_fields_token
+            }
+
+            fn set_generated_fields_mysql(&mut self, row: &sqlx::mysql::MySqlRow) -> Result<(), sqlx::Error> {
+                #set_generated_fields_token
+            }
+
+            fn set_generated_fields_postgres(&mut self, row: &sqlx::postgres::PgRow) -> Result<(), sqlx::Error> {
+                #set_generated_fields_token
+            }
+
             fn gen_insert_arguments_sqlite(&self) -> Result<sqlx::sqlite::SqliteArguments<'_>, sqlx::error::BoxDynError> {
                 #insert_args_sqlite
             }
 
+            fn add_insert_arguments_sqlite<'a>(&'a self, args: &mut sqlx::sqlite::SqliteArguments<'a>) -> Result<(), sqlx::error::BoxDynError> {
+                #add_insert_args
+            }
+
             fn gen_upsert_arguments_sqlite(&self) -> Result<sqlx::sqlite::SqliteArguments<'_>, sqlx::error::BoxDynError> {
                 #upsert_args_sqlite
             }
@@ -109,6 +160,10 @@ pub fn generate_entity_impl(
                 #insert_args_mysql
             }
 
+            fn add_insert_arguments_mysql(&self, args: &mut sqlx::mysql::MySqlArguments) -> Result<(), sqlx::error::BoxDynError> {
+                #add_insert_args
+            }
+
             fn gen_upsert_arguments_mysql(&self) -> Result<sqlx::mysql::MySqlArguments, sqlx::error::BoxDynError> {
                 #upsert_args_mysql
             }
@@ -117,15 +172,61 @@ pub fn generate_entity_impl(
                 #insert_args_postgres
             }
 
+            fn add_insert_arguments_postgres(&self, args: &mut sqlx::postgres::PgArguments) -> Result<(), sqlx::error::BoxDynError> {
+                #add_insert_args
+            }
+
             fn gen_upsert_arguments_postgres(&self) -> Result<sqlx::postgres::PgArguments, sqlx::error::BoxDynError> {
                 #upsert_args_postgres
             }
+
+            #hooks_stream
         }
     };
 
     output
 }
 
+// entity without a key given by itself keeps the default, generated fields are not re-selected
+// before hooks and auto timestamps change the clone, the entity borrowed by insert/upsert is kept as it is
+fn generate_clone_for_writes(enabled: bool) -> proc_macro2::TokenStream {
+    if !enabled {
+        return proc_macro2::TokenStream::new();
+    }
+    quote! {
+        fn clone_for_writes(&self) -> Option<Box<dyn taitan_orm::traits::Entity + '_>> {
+            Some(Box::new(Clone::clone(self)))
+        }
+    }
+}
+
+fn generate_reselect_impl(fields: &FieldsNamed, unique_keys: &[Vec<String>]) -> proc_macro2::TokenStream {
+    if FieldsParser::from_named(fields).get_reselect_key_fields(unique_keys).is_empty() {
+        return proc_macro2::TokenStream::new();
+    }
+    let reselect_key_names = FieldsParser::from_named(fields).get_reselect_key_names(unique_keys);
+    let reselect_args_sqlite = FieldsParser::from_named(fields).gen_reselect_arguments_sqlite(unique_keys);
+    let reselect_args_mysql = FieldsParser::from_named(fields).gen_reselect_arguments_mysql(unique_keys);
+    let reselect_args_postgres = FieldsParser::from_named(fields).gen_reselect_arguments_postgres(unique_keys);
+    quote! {
+        fn get_reselect_key_names(&self) -> &'static [&'static str] {
+            #reselect_key_names
+        }
+
+        fn gen_reselect_arguments_sqlite(&self) -> Result<sqlx::sqlite::SqliteArguments<'_>, sqlx::error::BoxDynError> {
+            #reselect_args_sqlite
+        }
+
+        fn gen_reselect_arguments_mysql(&self) -> Result<sqlx::mysql::MySqlArguments, sqlx::error::BoxDynError> {
+            #reselect_args_mysql
+        }
+
+        fn gen_reselect_arguments_postgres(&self) -> Result<sqlx::postgres::PgArguments, sqlx::error::BoxDynError> {
+            #reselect_args_postgres
+        }
+    }
+}
+
 // pub fn impl_entity_macro(input: TokenStream) -> TokenStream {
 //     let DeriveInput {
 //         attrs, ident, data, ..
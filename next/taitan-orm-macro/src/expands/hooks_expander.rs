@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+// generated impl which the hooks accessors belong to, decides the type of clone_for_hooks,
+// entity is also cloned for auto timestamps, its clone_for_writes is generated by entity_expander
+pub enum HooksTarget {
+    Entity,
+    Mutation,
+    Unique,
+    Location,
+}
+
+// get_hooks of generated impls, types not listed in #[hooks] keep the default None
+pub fn generate_hooks_fn(enabled: bool, target: HooksTarget) -> TokenStream {
+    if !enabled {
+        return TokenStream::new();
+    }
+    let clone_stream = match target {
+        HooksTarget::Entity => TokenStream::new(),
+        HooksTarget::Mutation => quote! {
+            fn clone_for_hooks(&self) -> Option<Self> {
+                Some(Clone::clone(self))
+            }
+        },
+        HooksTarget::Unique => quote! {
+            fn clone_for_hooks(&self) -> Option<Box<dyn taitan_orm::traits::Unique<Mutation = Self::Mutation> + '_>> {
+                Some(Box::new(Clone::clone(self)))
+            }
+        },
+        HooksTarget::Location => quote! {
+            fn clone_for_hooks(&self) -> Option<Box<dyn taitan_orm::traits::Location + '_>> {
+                Some(Box::new(Clone::clone(self)))
+            }
+        },
+    };
+    quote! {
+        fn get_hooks(&self) -> Option<&dyn taitan_orm::traits::WriteHooks> {
+            Some(self)
+        }
+
+        fn get_hooks_mut(&mut self) -> Option<&mut dyn taitan_orm::traits::WriteHooks> {
+            Some(self)
+        }
+
+        #clone_stream
+    }
+}
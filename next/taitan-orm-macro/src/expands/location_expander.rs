@@ -2,10 +2,13 @@ use case::CaseExt;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{Attribute, FieldsNamed};
+use super::soft_delete_expander::generate_soft_delete_fn;
+use super::hooks_expander::{generate_hooks_fn, HooksTarget};
 use crate::attrs::{AttrParser, DefaultAttrParser};
 use crate::fields::{FieldsFilter, FieldsParser, UniqueParser};
 use crate::fields::StructConstructor;
 use crate::fields::LocationParser;
+use crate::fields::{ColumnConstructor, FieldsContainer};
 
 
 pub fn generate_location_struct_and_impl(
@@ -17,20 +20,46 @@ pub fn generate_location_struct_and_impl(
     let parser = FieldsParser::from_named(fields);
 
     let where_clause = FieldsParser::from_named(fields).get_where_clause();
+    let qualified_where_clause = FieldsParser::from_named(fields).get_qualified_where_clause();
     let location_fields_name = parser.get_location_fields_name();
     let location_arguments_sqlite = FieldsParser::from_named(fields).gen_location_arguments_sqlite();
     let location_arguments_mysql = FieldsParser::from_named(fields).gen_location_arguments_mysql();
     let location_arguments_postgres = FieldsParser::from_named(fields).gen_location_arguments_postgres();
+    let add_location_arguments = FieldsParser::from_named(fields).add_location_arguments();
+    let soft_delete_stream = generate_soft_delete_fn(fields);
+    let hooks_stream = generate_hooks_fn(DefaultAttrParser::extract_hooks(attrs).location, HooksTarget::Location);
 
     let table_name = DefaultAttrParser::extract_table_name(ident, attrs);
     let struct_name =  format!("{}Location", table_name.to_camel());
     let struct_ident = Ident::new(&struct_name, Span::call_site());
     let struct_stream = FieldsParser::from_named(fields).of_location(&struct_name);
+    let columns_name = format!("{}Columns", table_name.to_camel());
+    let columns_ident = Ident::new(&columns_name, Span::call_site());
+    let column_fns = parser.map_field_vec(&|field| <FieldsParser as ColumnConstructor>::of_column_fn(&struct_ident, field));
 
     let output = quote! {
 
         #struct_stream
 
+        // typed column handles, kept out of the schema struct so they never collide with its own methods
+        #[derive(Debug, Clone, Copy)]
+        pub struct #columns_ident;
+
+        #[allow(dead_code)]
+        impl #columns_ident {
+            #(#column_fns)*
+        }
+
+        impl #struct_ident {
+            pub fn and<L: taitan_orm::traits::Location + 'static>(self, other: L) -> taitan_orm::traits::LocationTree {
+                taitan_orm::traits::LocationTree::leaf(self).and(other)
+            }
+
+            pub fn or<L: taitan_orm::traits::Location + 'static>(self, other: L) -> taitan_orm::traits::LocationTree {
+                taitan_orm::traits::LocationTree::leaf(self).or(other)
+            }
+        }
+
         impl taitan_orm::traits::Location for #struct_ident {
 
             fn get_table_name(&self) -> &'static str {
@@ -45,6 +74,14 @@ pub fn generate_location_struct_and_impl(
                 #where_clause
             }
 
+            fn get_qualified_where_clause(&self, wrap_char: char, place_holder: char) -> String {
+                #qualified_where_clause
+            }
+
+            #soft_delete_stream
+
+            #hooks_stream
+
             fn gen_location_arguments_sqlite(&self) -> Result<sqlx::sqlite::SqliteArguments<'_>, sqlx::error::BoxDynError> {
                 #location_arguments_sqlite
             }
@@ -56,6 +93,18 @@ pub fn generate_location_struct_and_impl(
             fn gen_location_arguments_postgres(&self) -> Result<sqlx::postgres::PgArguments, sqlx::error::BoxDynError> {
                 #location_arguments_postgres
             }
+
+            fn add_location_arguments_sqlite<'a>(&'a self, args: &mut sqlx::sqlite::SqliteArguments<'a>) -> Result<(), sqlx::error::BoxDynError> {
+                #add_location_arguments
+            }
+
+            fn add_location_arguments_mysql(&self, args: &mut sqlx::mysql::MySqlArguments) -> Result<(), sqlx::error::BoxDynError> {
+                #add_location_arguments
+            }
+
+            fn add_location_arguments_postgres(&self, args: &mut sqlx::postgres::PgArguments) -> Result<(), sqlx::error::BoxDynError> {
+                #add_location_arguments
+            }
         }
     };
 
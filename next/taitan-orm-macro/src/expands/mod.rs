@@ -6,6 +6,9 @@ mod selection_expander;
 mod selected_expander;
 mod ordering_expander;
 mod template_expander;
+mod relation_expander;
+mod soft_delete_expander;
+mod hooks_expander;
 
 pub use entity_expander::generate_entity_impl;
 pub use unique_expander::generate_unique_structs_and_impls;
@@ -14,4 +17,5 @@ pub use mutation_expander::generate_mutation_struct_and_impl;
 pub use selection_expander::generate_selection_struct_and_impl;
 pub use selected_expander::generate_selected_struct_and_impl;
 pub use ordering_expander::generate_ordering_struct_and_impl;
-pub use template_expander::generate_template_struct_and_impl;
\ No newline at end of file
+pub use template_expander::generate_template_struct_and_impl;
+pub use relation_expander::generate_relation_impl;
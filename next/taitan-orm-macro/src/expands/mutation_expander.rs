@@ -1,10 +1,11 @@
 use case::CaseExt;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{Attribute, FieldsNamed};
+use syn::{Attribute, Field, FieldsNamed, LitStr};
 use crate::attrs::{AttrParser, DefaultAttrParser};
 use crate::fields::{FieldsContainer, FieldsFilter, FieldsParser, NamesConstructor, StructConstructor, UniqueParser};
-use crate::fields::{ArgsConstructorPostgres, ArgsConstructorMySql, ArgsConstructorSqlite};
+use super::hooks_expander::{generate_hooks_fn, HooksTarget};
+use crate::fields::{ArgsAddConstructor, ArgsConstructorPostgres, ArgsConstructorMySql, ArgsConstructorSqlite};
 
 
 pub fn generate_mutation_struct_and_impl(
@@ -14,15 +15,22 @@ pub fn generate_mutation_struct_and_impl(
 ) -> TokenStream {
     let table_name = DefaultAttrParser::extract_table_name(ident, attrs);
     let fields_vec = FieldsParser::from_named(fields).filter_not_annotated_fields("primary_key");
+    let version_stream = generate_version_impl(&fields_vec);
+    let hooks_stream = generate_hooks_fn(DefaultAttrParser::extract_hooks(attrs).mutation, HooksTarget::Mutation);
+    let struct_stream_fields = fields_vec.clone();
+    // #[version] field holds the expected version, it is increased by generator instead of assigned
+    let fields_vec = FieldsParser::from_vec(&fields_vec).filter_not_annotated_fields("version");
 
     let parser = FieldsParser::from_named(fields);
     let location_fields_vec = parser.get_fields();
 
-    let fields_name_vec = FieldsParser::from_vec(&fields_vec).of_option_names_vec();
+    let fields_name_vec = FieldsParser::from_vec(&fields_vec).of_assign_names_vec();
+    let set_clause = FieldsParser::from_vec(&fields_vec).of_assign_set_clause();
 
     let change_args_sqlite = FieldsParser::from_vec(&fields_vec).of_change_args_sqlite(location_fields_vec);
     let change_args_mysql = FieldsParser::from_vec(&fields_vec).of_change_args_mysql(location_fields_vec);
     let change_args_postgres = FieldsParser::from_vec(&fields_vec).of_change_args_postgres(location_fields_vec);
+    let add_mutation_args_clause = FieldsParser::from_vec(&fields_vec).map_field_vec(&<FieldsParser as ArgsAddConstructor>::of_assign_buffer);
 
     let mutation_struct_name =  format!("{}Mutation", table_name.to_camel());
     let primary_struct_name =  format!("{}Primary", table_name.to_camel());
@@ -30,7 +38,7 @@ pub fn generate_mutation_struct_and_impl(
     let struct_ident = Ident::new(&mutation_struct_name, Span::call_site());
     let primary_struct_ident = Ident::new(&primary_struct_name, Span::call_site());
     let location_struct_ident = Ident::new(&location_struct_name, Span::call_site());
-    let struct_stream = FieldsParser::from_vec(&fields_vec).of_option(&mutation_struct_name);
+    let struct_stream = FieldsParser::from_vec(&struct_stream_fields).of_assign(&mutation_struct_name, &location_struct_name);
 
     let output = quote! {
 
@@ -44,6 +52,14 @@ pub fn generate_mutation_struct_and_impl(
                 #fields_name_vec
             }
 
+            fn get_set_clause(&self, wrap_char: char, place_holder: char) -> String {
+                #set_clause
+            }
+
+            #version_stream
+
+            #hooks_stream
+
             fn gen_change_arguments_sqlite<'a>(
                 &'a self,
                 location: &'a Self::Location,
@@ -62,8 +78,63 @@ pub fn generate_mutation_struct_and_impl(
             ) -> Result<sqlx::postgres::PgArguments, sqlx::error::BoxDynError> {
                 #change_args_postgres
             }
+
+            fn add_mutation_arguments_sqlite<'a>(&'a self, args: &mut sqlx::sqlite::SqliteArguments<'a>) -> Result<(), sqlx::error::BoxDynError> {
+                #(#add_mutation_args_clause)*
+                Ok(())
+            }
+
+            fn add_mutation_arguments_mysql(&self, args: &mut sqlx::mysql::MySqlArguments) -> Result<(), sqlx::error::BoxDynError> {
+                #(#add_mutation_args_clause)*
+                Ok(())
+            }
+
+            fn add_mutation_arguments_postgres(&self, args: &mut sqlx::postgres::PgArguments) -> Result<(), sqlx::error::BoxDynError> {
+                #(#add_mutation_args_clause)*
+                Ok(())
+            }
         }
     };
 
     output
-}
\ No newline at end of file
+}
+
+fn generate_version_impl(fields: &Vec<Field>) -> TokenStream {
+    let version_fields = FieldsParser::from_vec(fields).filter_annotated_fields("version");
+    if version_fields.len() > 1 {
+        panic!("only one field can be annotated with #[version]");
+    }
+    let Some(version_field) = version_fields.first() else {
+        return TokenStream::new();
+    };
+    let field_name = version_field.ident.as_ref().unwrap();
+    let field_alias = DefaultAttrParser::extract_field_db_ident(version_field);
+    let field_name_lit = LitStr::new(&field_alias.to_string(), field_name.span());
+    let add_version_clause = quote! {
+        if let Some(version) = &self.#field_name {
+            sqlx::Arguments::add(args, version)?;
+        }
+        Ok(())
+    };
+    quote! {
+        fn get_version_field_name(&self) -> Option<&'static str> {
+            Some(#field_name_lit)
+        }
+
+        fn is_version_checked(&self) -> bool {
+            self.#field_name.is_some()
+        }
+
+        fn add_version_arguments_sqlite<'a>(&'a self, args: &mut sqlx::sqlite::SqliteArguments<'a>) -> Result<(), sqlx::error::BoxDynError> {
+            #add_version_clause
+        }
+
+        fn add_version_arguments_mysql(&self, args: &mut sqlx::mysql::MySqlArguments) -> Result<(), sqlx::error::BoxDynError> {
+            #add_version_clause
+        }
+
+        fn add_version_arguments_postgres(&self, args: &mut sqlx::postgres::PgArguments) -> Result<(), sqlx::error::BoxDynError> {
+            #add_version_clause
+        }
+    }
+}
@@ -5,7 +5,7 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{Attribute, Field, FieldsNamed, LitStr};
 
-fn generate_struct_and_impl(struct_name: &str, all_fields: &Vec<String>, fields: &Vec<Vec<String>>) -> TokenStream {
+fn generate_struct_and_impl(struct_name: &str, table_name: &str, all_fields: &Vec<String>, fields: &Vec<Vec<String>>) -> TokenStream {
     let struct_ident = Ident::new(&struct_name, Span::call_site());
     let all_fields = transform_string_list(all_fields);
     let unique_fields_stream = transform_string_vec_list(fields);
@@ -15,9 +15,14 @@ fn generate_struct_and_impl(struct_name: &str, all_fields: &Vec<String>, fields:
         #[derive(Debug, Default)]
         pub struct #struct_ident<'a> {
             fields: Vec<std::borrow::Cow<'a, str>>,
+            orders: Vec<taitan_orm::traits::FieldOrder>,
         }
 
         impl<'a> taitan_orm::traits::OrderBy for #struct_ident<'a> {
+            fn get_table_name(&self) -> &'static str {
+                #table_name
+            }
+
             fn unique_fields(&self) -> &[&[&str]] {
                 #unique_fields_stream
             }
@@ -29,6 +34,10 @@ fn generate_struct_and_impl(struct_name: &str, all_fields: &Vec<String>, fields:
             fn get_fields(&self) -> &[std::borrow::Cow<'a, str>] {
                 &self.fields
             }
+
+            fn get_orders(&self) -> &[taitan_orm::traits::FieldOrder] {
+                &self.orders
+            }
         }
 
         impl<'a> #struct_ident<'a> {
@@ -44,9 +53,31 @@ fn generate_struct_and_impl(struct_name: &str, all_fields: &Vec<String>, fields:
                     taitan_orm::traits::OrderBy::unique_fields(&order_by)
                 )?;
 
-                Ok(Self {
-                    fields: fields.into_iter().map(Into::into).collect(),
-                })
+                let fields: Vec<std::borrow::Cow<'a, str>> = fields.into_iter().map(Into::into).collect();
+                let orders = vec![taitan_orm::traits::FieldOrder::default(); fields.len()];
+                Ok(Self { fields, orders })
+            }
+
+            pub fn build_with_orders<I, S>(fields: I) -> Result<Self, Box<dyn std::error::Error + 'static>>
+            where
+                I: IntoIterator<Item = (S, taitan_orm::traits::FieldOrder)>,
+                S: AsRef<str> + Into<std::borrow::Cow<'a, str>>,
+            {
+                let (fields, orders): (Vec<std::borrow::Cow<'a, str>>, Vec<taitan_orm::traits::FieldOrder>) =
+                    fields.into_iter().map(|(field, order)| (field.into(), order)).unzip();
+                let order_by = Self::default();
+                taitan_orm::traits::validate_order_by(
+                    fields.iter(),
+                    taitan_orm::traits::OrderBy::all_fields(&order_by),
+                    taitan_orm::traits::OrderBy::unique_fields(&order_by)
+                )?;
+                Ok(Self { fields, orders })
+            }
+
+            // parse query string like "-created_at,name", see taitan_orm::traits::parse_order_by
+            pub fn parse(order_by: &str) -> Result<Self, Box<dyn std::error::Error + 'static>> {
+                let fields = taitan_orm::traits::parse_order_by(order_by)?;
+                Self::build_with_orders(fields)
             }
         }
     };
@@ -93,6 +124,6 @@ pub fn generate_ordering_struct_and_impl(
     // order_fields_vec.push(vec!["id".to_owned(), "age".to_owned()]);
 
     let primary_order_struct_name = format!("{}Ordering", table_name.to_camel());
-    let ordering_stream = generate_struct_and_impl(&primary_order_struct_name, &all_fields_names, &order_fields_vec);
+    let ordering_stream = generate_struct_and_impl(&primary_order_struct_name, &table_name, &all_fields_names, &order_fields_vec);
     ordering_stream
 }
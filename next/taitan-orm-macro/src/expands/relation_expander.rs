@@ -0,0 +1,162 @@
+use crate::attrs::{AttrParser, DefaultAttrParser, RelationAttr};
+use crate::fields::{FieldsContainer, FieldsFilter, FieldsParser};
+use crate::types::{DefaultTypeChecker, TypeChecker};
+use case::CaseExt;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Field, FieldsNamed};
+
+#[derive(Clone, Copy, PartialEq)]
+enum RelationKind {
+    HasMany,
+    HasOne,
+    BelongsTo,
+}
+
+/**
+1. has_many:   parent.references == child.foreign_key, load children of self
+2. has_one:    same as has_many, but at most one child is loaded
+3. belongs_to: self.foreign_key == parent.references, load the parent of self
+
+every relation generates load_<name> and the batched load_many_<name>,
+batched loader fetches related rows of all given records by IN (...) queries, keys are chunked by the bind parameter limit.
+loaders are generated for both the entity and its SelectedEntity
+*/
+pub fn generate_relation_impl(ident: &Ident, attrs: &Vec<Attribute>, fields: &FieldsNamed) -> TokenStream {
+    let table_name = DefaultAttrParser::extract_table_name(ident, attrs);
+    let selected_ident = format_ident!("{}SelectedEntity", table_name.to_camel());
+    let parser = FieldsParser::from_named(fields);
+
+    let mut relations: Vec<(RelationKind, RelationAttr)> = Vec::new();
+    for (kind, attr_name) in [
+        (RelationKind::HasMany, "has_many"),
+        (RelationKind::HasOne, "has_one"),
+        (RelationKind::BelongsTo, "belongs_to"),
+    ] {
+        for relation in DefaultAttrParser::extract_relations(attrs, attr_name) {
+            relations.push((kind, relation));
+        }
+    }
+    if relations.is_empty() {
+        return TokenStream::new();
+    }
+
+    let mut entity_loaders: Vec<TokenStream> = Vec::new();
+    let mut selected_loaders: Vec<TokenStream> = Vec::new();
+    for (kind, relation) in relations.iter() {
+        let local_field = get_local_field(&parser, *kind, relation);
+        entity_loaders.push(generate_loaders(*kind, relation, &local_field, false));
+        selected_loaders.push(generate_loaders(*kind, relation, &local_field, true));
+    }
+
+    quote! {
+        impl #ident {
+            #(#entity_loaders)*
+        }
+
+        impl #selected_ident {
+            #(#selected_loaders)*
+        }
+    }
+}
+
+fn get_local_field(parser: &FieldsParser, kind: RelationKind, relation: &RelationAttr) -> Field {
+    let local_name = match kind {
+        RelationKind::BelongsTo => relation.foreign_key.clone(),
+        _ => match &relation.references {
+            Some(references) => references.clone(),
+            None => {
+                let primary_fields = parser.filter_annotated_fields("primary_key");
+                if primary_fields.len() != 1 {
+                    panic!("relation {} needs references when primary key is not a single field", relation.name);
+                }
+                primary_fields[0].ident.as_ref().unwrap().to_string()
+            }
+        },
+    };
+    parser
+        .get_fields()
+        .iter()
+        .find(|field| field.ident.as_ref().unwrap() == local_name.as_str())
+        .cloned()
+        .unwrap_or_else(|| panic!("relation {} refers to unknown field {}", relation.name, local_name))
+}
+
+fn generate_loaders(kind: RelationKind, relation: &RelationAttr, local_field: &Field, of_selected: bool) -> TokenStream {
+    let load_ident = format_ident!("load_{}", relation.name);
+    let load_many_ident = format_ident!("load_many_{}", relation.name);
+    let related_camel = relation.table.to_camel();
+    let related_selected = format_ident!("{}SelectedEntity", related_camel);
+    let related_location = format_ident!("{}Location", related_camel);
+    let related_field = match kind {
+        RelationKind::BelongsTo => relation.references.clone().unwrap_or("id".to_string()),
+        _ => relation.foreign_key.clone(),
+    };
+    let related_field = Ident::new(&related_field, Span::call_site());
+
+    let local_ident = local_field.ident.as_ref().unwrap();
+    // fields of SelectedEntity are always Optional
+    let local_key = if of_selected || DefaultTypeChecker::type_is_option(&local_field.ty) {
+        quote! { taitan_orm::traits::RelationKey::relation_key(&record.#local_ident) }
+    } else {
+        quote! { Some(&record.#local_ident) }
+    };
+
+    let (one_ty, many_ty, group_fn, empty_value) = if kind == RelationKind::HasMany {
+        (
+            quote! { Vec<#related_selected> },
+            quote! { Vec<Vec<#related_selected>> },
+            quote! { taitan_orm::traits::group_many },
+            quote! { Vec::new() },
+        )
+    } else {
+        (
+            quote! { Option<#related_selected> },
+            quote! { Vec<Option<#related_selected>> },
+            quote! { taitan_orm::traits::group_one },
+            quote! { None },
+        )
+    };
+    let take_first = if kind == RelationKind::HasMany {
+        quote! { loaded.into_iter().next().unwrap_or_default() }
+    } else {
+        quote! { loaded.into_iter().next().flatten() }
+    };
+
+    quote! {
+        pub async fn #load_ident<DB>(&self, db: &mut DB, selection: &#related_selected) -> taitan_orm::Result<#one_ty>
+        where
+            DB: taitan_orm::ReaderApi,
+            #related_selected: taitan_orm::traits::SelectedEntity<<DB as taitan_orm::SqlGenericExecutor>::DB> + Send + Unpin,
+        {
+            let loaded = Self::#load_many_ident(db, std::slice::from_ref(self), selection).await?;
+            Ok(#take_first)
+        }
+
+        pub async fn #load_many_ident<DB>(db: &mut DB, records: &[Self], selection: &#related_selected) -> taitan_orm::Result<#many_ty>
+        where
+            DB: taitan_orm::ReaderApi,
+            #related_selected: taitan_orm::traits::SelectedEntity<<DB as taitan_orm::SqlGenericExecutor>::DB> + Send + Unpin,
+        {
+            let record_keys: Vec<_> = records.iter().map(|record| #local_key).collect();
+            let keys = taitan_orm::traits::collect_relation_keys(&record_keys);
+            if keys.is_empty() {
+                return Ok(records.iter().map(|_| #empty_value).collect());
+            }
+            // related key is always selected, so that rows can be matched back to records
+            let mut selection = selection.clone();
+            selection.#related_field = taitan_orm::Optional::Selected;
+            // keys are split by the bind parameter limit of the database, one query for each chunk
+            let max_params = taitan_orm::SqlGenerator::get_max_bind_params(taitan_orm::SqlGeneratorContainer::get_generator(db));
+            let mut related: Vec<#related_selected> = Vec::new();
+            for chunk in keys.chunks(max_params) {
+                let location = #related_location {
+                    #related_field: taitan_orm::Optional::Some(taitan_orm::traits::LocationExpr::is_in(chunk.to_vec())),
+                    ..Default::default()
+                };
+                related.extend(taitan_orm::ReaderApi::search(db, &selection, &location, &None, &None).await?);
+            }
+            Ok(#group_fn(&record_keys, &related, |row| taitan_orm::traits::RelationKey::relation_key(&row.#related_field)))
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use crate::attrs::{AttrParser, DefaultAttrParser};
 use crate::fields::{
-    FieldsParser, NamesConstructor, RowConstructor, RowGetConstructor, StructConstructor,
+    CursorConstructor, FieldsParser, NamesConstructor, RowConstructor, RowGetConstructor, StructConstructor,
 };
 use case::CaseExt;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{Attribute, FieldsNamed};
+use super::soft_delete_expander::generate_soft_delete_fn;
 pub fn generate_selected_struct_and_impl(
     ident: &Ident,
     attrs: &Vec<Attribute>,
@@ -34,6 +35,7 @@ pub fn generate_selected_struct_and_impl(
 
     let bool_names_vec = parser.of_self_optional_names_vec();
     let full_fields_stream = parser.of_optional_selected();
+    let soft_delete_stream = generate_soft_delete_fn(fields);
 
     let output = quote! {
         #struct_stream
@@ -55,6 +57,8 @@ pub fn generate_selected_struct_and_impl(
                 #bool_names_vec
             }
 
+            #soft_delete_stream
+
             fn full_fields() -> Self
                 where Self: Sized,
             {
@@ -77,7 +81,10 @@ fn generate_selected_and_impl(
     let selected_row_construct = parser.gen_selected_row();
     let selected_bits_row_construct = parser.gen_selected_bits_row();
     let selected_self_row_construct = parser.gen_selected_self_row();
+    let selected_self_row_at_construct = parser.gen_selected_self_row_at();
     let full_row_construct = parser.gen_full_row();
+    let cursor_values = parser.gen_cursor_values();
+    let add_cursor_arguments = parser.gen_add_cursor_arguments();
 
     let output = quote! {
         impl taitan_orm::traits::SelectedEntity<sqlx::#db_ident> for #selected_ident {
@@ -107,6 +114,28 @@ fn generate_selected_and_impl(
             {
                 #full_row_construct
             }
+
+            fn select_from_row_at(selection: &Self, row: &<sqlx::#db_ident as sqlx::Database>::Row, offset: &mut usize) -> Result<Self, sqlx::Error>
+            where
+                Self: Sized,
+            {
+                #selected_self_row_at_construct
+            }
+
+            fn get_cursor_values(&self, fields: &[std::borrow::Cow<'_, str>]) -> Result<Vec<taitan_orm::traits::CursorValue>, taitan_orm::traits::NotValidCursorError> {
+                #cursor_values
+            }
+
+            fn add_cursor_arguments<'a>(
+                fields: &[std::borrow::Cow<'_, str>],
+                values: &[taitan_orm::traits::CursorValue],
+                args: &mut <sqlx::#db_ident as sqlx::Database>::Arguments<'a>,
+            ) -> Result<(), sqlx::error::BoxDynError>
+            where
+                Self: Sized,
+            {
+                #add_cursor_arguments
+            }
         }
     };
     output
@@ -4,6 +4,7 @@ use case::CaseExt;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{Attribute, FieldsNamed};
+use super::soft_delete_expander::generate_soft_delete_fn;
 
 pub fn generate_selection_struct_and_impl(
     ident: &Ident,
@@ -20,6 +21,7 @@ pub fn generate_selection_struct_and_impl(
     let struct_name = format!("{}Selection", table_name.to_camel());
     let struct_ident = Ident::new(&struct_name, Span::call_site());
     let struct_stream = FieldsParser::from_named(fields).of_bool(&struct_name);
+    let soft_delete_stream = generate_soft_delete_fn(fields);
 
     let output = quote! {
 
@@ -39,6 +41,8 @@ pub fn generate_selection_struct_and_impl(
                 #bool_names_vec
             }
 
+            #soft_delete_stream
+
             fn full_fields() -> Self
                 where Self: Sized,
             {
@@ -0,0 +1,30 @@
+use crate::attrs::{AttrParser, DefaultAttrParser};
+use crate::fields::{FieldsFilter, FieldsParser};
+use crate::types::{DefaultTypeChecker, DefaultTypeExtractor, TypeChecker, TypeExtractor};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{FieldsNamed, LitStr};
+
+// get_soft_delete of generated Unique/Location/Selection, bool field is a flag, others are timestamps
+pub fn generate_soft_delete_fn(fields: &FieldsNamed) -> TokenStream {
+    let soft_delete_fields = FieldsParser::from_named(fields).filter_annotated_fields("soft_delete");
+    if soft_delete_fields.len() > 1 {
+        panic!("only one field can be annotated with #[soft_delete]");
+    }
+    let Some(soft_delete_field) = soft_delete_fields.first() else {
+        return TokenStream::new();
+    };
+    let field_alias = DefaultAttrParser::extract_field_db_ident(soft_delete_field);
+    let field_name_lit = LitStr::new(&field_alias.to_string(), field_alias.span());
+    let field_type = DefaultTypeExtractor::get_option_inner_type(&soft_delete_field.ty).unwrap_or(&soft_delete_field.ty);
+    let soft_delete = if DefaultTypeChecker::type_has_one_of_names(field_type, &["bool::"]) {
+        quote! { taitan_orm::traits::SoftDelete::Flag(#field_name_lit) }
+    } else {
+        quote! { taitan_orm::traits::SoftDelete::Timestamp(#field_name_lit) }
+    };
+    quote! {
+        fn get_soft_delete(&self) -> Option<taitan_orm::traits::SoftDelete> {
+            Some(#soft_delete)
+        }
+    }
+}
@@ -1,10 +1,13 @@
-use crate::attrs::{AttrParser, DefaultAttrParser};
+use crate::attrs::{AttrParser, DefaultAttrParser, RawAttr};
 use crate::fields::{FieldsFilter, FieldsParser};
-use crate::util::{build_impl_trait_token, copy_to_template_struct, create_path_from_str};
+use crate::types::{DefaultTypeChecker, TypeChecker};
+use crate::util::{
+    build_impl_trait_token, copy_to_template_struct, create_path_from_str, gen_template_args_add, TemplateSource,
+};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{Attribute, Data, FieldsNamed, Generics};
-use taitan_orm_trait::ParsedTemplateSql;
+use syn::{Attribute, Data, FieldsNamed, Generics, Type};
+use taitan_orm_trait::{derive_count_sql, ParsedTemplateSql, StringEscape, TemplateArgument};
 
 pub fn generate_template_struct_and_impl(
     ident: &Ident,
@@ -13,11 +16,40 @@ pub fn generate_template_struct_and_impl(
     fields: &FieldsNamed,
     generics: &Generics,
 ) -> TokenStream {
-    let template_sql = DefaultAttrParser::extract_template_sql(&attrs)
-        .expect("TemplateRecord must have sql attribute, for example: #[sql = \" select name from user where id = #{id}\"]");
+    // #[backslash_escape]: the template is written for mysql, a backslash escapes the next char in strings
+    let escape = if DefaultAttrParser::check_has_attr(attrs, "backslash_escape") {
+        StringEscape::Backslash
+    } else {
+        StringEscape::Standard
+    };
+    let template_source = TemplateSource::load(
+        DefaultAttrParser::extract_template_sql(attrs),
+        DefaultAttrParser::extract_template_sql_file(attrs),
+        "sql",
+        escape,
+    )
+    .expect("TemplateRecord must have sql or sql_file attribute, for example: #[sql = \" select name from user where id = #{id}\"]");
+    let count_source = TemplateSource::load(
+        DefaultAttrParser::extract_template_count_sql(attrs),
+        DefaultAttrParser::extract_template_count_sql_file(attrs),
+        "count_sql",
+        escape,
+    );
+    let track_files_stream = template_source.gen_track_files_stream();
+    let count_track_files_stream = count_source
+        .as_ref()
+        .map(TemplateSource::gen_track_files_stream)
+        .unwrap_or_default();
+    let template_source_sql = template_source.sql;
 
-    let template_sql = ParsedTemplateSql::build(template_sql.as_str())
-        .expect(format!("Failed to parse template sql: {}", template_sql).as_str());
+    let list_fields = fields
+        .named
+        .iter()
+        .filter(|field| DefaultTypeChecker::type_is_list(&field.ty))
+        .map(|field| field.ident.as_ref().unwrap().to_string())
+        .collect::<Vec<String>>();
+    let template_sql = ParsedTemplateSql::build_with_list_fields(template_source_sql.as_str(), &list_fields, escape)
+        .expect(format!("Failed to parse template sql: {}", template_source_sql).as_str());
 
     let limit_fields = FieldsParser::from_named(fields).filter_annotated_fields("limit_field");
     let limit_fields_names = limit_fields
@@ -28,40 +60,69 @@ pub fn generate_template_struct_and_impl(
         panic!("there is more than one limit fields in the template record");
     }
 
-    let get_sql_render_fn_stream = gen_fn_get_sql(ident, data, generics, &template_sql);
+    let get_sql_render_fn_stream = gen_fn_get_sql(ident, data, fields, generics, &template_sql);
 
-    let count_sql = DefaultAttrParser::extract_template_count_sql(&attrs).unwrap_or_default();
-    let get_count_sql_render_fn_stream = if count_sql.is_empty() {
-        gen_fn_get_count_sql(ident, data, generics, None)
-    } else {
-        if limit_fields_names.len() < 1 {
-            panic!("you must specify at least one limit field");
-        }
-        let parsed_count_sql = ParsedTemplateSql::build(count_sql.as_str())
-            .expect(format!("Failed to parse template count sql: {}", count_sql).as_str());
-        gen_fn_get_count_sql(ident, data, generics, Some(&parsed_count_sql))
+    // count sql is derived from the select sql when it is not written by #[count_sql]
+    let (count_sql, is_derived_count_sql) = match count_source {
+        Some(count_source) => (count_source.sql, false),
+        None => match derive_count_sql(&template_source_sql, escape) {
+            Ok(Some(derived_count_sql)) => (derived_count_sql, true),
+            Ok(None) => (String::new(), false),
+            Err(err) => panic!("Failed to derive count sql from template sql: {}", err),
+        },
     };
-
-    let variables = template_sql.variables;
-    let args_add = gen_args_add_clause(&variables);
-
+    let field_names = fields
+        .named
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap().to_string())
+        .collect::<Vec<String>>();
     let limit_field: Option<&String> = limit_fields_names.first();
 
-    let count_args_add = match limit_field {
-        None=> gen_args_add_clause(&variables),
-        Some(limit_field) => {
-            // panic!("limit field {} {:?}", limit_field, variables);
+    // arguments are bound with the same control blocks as the sql is rendered
+    let (get_count_sql_render_fn_stream, count_args_add) = if count_sql.is_empty() {
+        let count_arguments = match limit_field {
+            None => template_sql.arguments.clone(),
+            Some(limit_field) => TemplateArgument::without_field(&template_sql.arguments, limit_field),
+        };
+        (
+            gen_fn_get_count_sql(ident, data, fields, generics, None),
+            gen_template_args_add(&count_arguments, &field_names, &list_fields),
+        )
+    } else if is_derived_count_sql {
+        let parsed_count_sql = ParsedTemplateSql::build_with_list_fields(count_sql.as_str(), &list_fields, escape)
+            .unwrap_or_else(|err| panic!("Failed to parse derived count sql: {}, {}", count_sql, err));
+        if let Some(limit_field) = limit_field {
             let limit_field_dot = format!("{}.", limit_field);
-            let count_variables = variables.clone()
-                .into_iter()
-                .filter(|variable| {
-                    variable.ne(limit_field) && !variable.starts_with(&limit_field_dot)
-                })
-                .collect::<Vec<String>>();
-            gen_args_add_clause(&count_variables)
+            if parsed_count_sql
+                .variables
+                .iter()
+                .any(|variable| variable.eq(limit_field) || variable.starts_with(&limit_field_dot))
+            {
+                panic!(
+                    "limit field {} is used before the ORDER BY/LIMIT tail, count sql can not be derived, write it by #[count_sql]",
+                    limit_field
+                );
+            }
         }
+        (
+            gen_fn_get_count_sql(ident, data, fields, generics, Some(&parsed_count_sql)),
+            gen_template_args_add(&parsed_count_sql.arguments, &field_names, &list_fields),
+        )
+    } else {
+        if limit_fields_names.is_empty() {
+            panic!("you must specify at least one limit field");
+        }
+        let parsed_count_sql = ParsedTemplateSql::build_with_list_fields(count_sql.as_str(), &list_fields, escape)
+            .expect(format!("Failed to parse template count sql: {}", count_sql).as_str());
+        (
+            gen_fn_get_count_sql(ident, data, fields, generics, Some(&parsed_count_sql)),
+            gen_template_args_add(&parsed_count_sql.arguments, &field_names, &list_fields),
+        )
     };
 
+    let args_add = gen_template_args_add(&template_sql.arguments, &field_names, &list_fields);
+    let variables = template_sql.variables.clone();
+
     let impl_ident = build_impl_trait_token(ident, generics, "taitan_orm::traits::TemplateRecord");
 
     let template_struct_stream = get_sql_render_fn_stream.struct_stream.unwrap_or_default();
@@ -72,13 +133,20 @@ pub fn generate_template_struct_and_impl(
         .unwrap_or_default();
     let get_count_sql_fn_stream = get_count_sql_render_fn_stream.fn_stream;
     let get_pagination_fn_stream = gen_get_pagination_fn_stream(&limit_field);
+    let output_stream = gen_output_stream(ident, generics, DefaultAttrParser::extract_template_output(attrs));
 
     let output = quote! {
 
+        #track_files_stream
+
+        #count_track_files_stream
+
         #template_struct_stream
 
         #count_template_struct_stream
 
+        #output_stream
+
         #impl_ident {
 
             #get_sql_fn_stream
@@ -95,37 +163,37 @@ pub fn generate_template_struct_and_impl(
 
             fn gen_template_count_arguments_sqlite(&self) -> Result<sqlx::sqlite::SqliteArguments<'_>, sqlx::error::BoxDynError> {
                 let mut args = sqlx::sqlite::SqliteArguments::default();
-                #(#count_args_add)*
+                #count_args_add
                 Ok(args)
             }
 
             fn gen_template_count_arguments_mysql(&self) -> Result<sqlx::mysql::MySqlArguments, sqlx::error::BoxDynError> {
                 let mut args = sqlx::mysql::MySqlArguments::default();
-                #(#count_args_add)*
+                #count_args_add
                 Ok(args)
             }
 
             fn gen_template_count_arguments_postgres(&self) -> Result<sqlx::postgres::PgArguments, sqlx::error::BoxDynError> {
                 let mut args = sqlx::postgres::PgArguments::default();
-                #(#count_args_add)*
+                #count_args_add
                 Ok(args)
             }
 
             fn gen_template_arguments_sqlite(&self) -> Result<sqlx::sqlite::SqliteArguments<'_>, sqlx::error::BoxDynError> {
                 let mut args = sqlx::sqlite::SqliteArguments::default();
-                #(#args_add)*
+                #args_add
                 Ok(args)
             }
 
             fn gen_template_arguments_mysql(&self) -> Result<sqlx::mysql::MySqlArguments, sqlx::error::BoxDynError> {
                 let mut args = sqlx::mysql::MySqlArguments::default();
-                #(#args_add)*
+                #args_add
                 Ok(args)
             }
 
             fn gen_template_arguments_postgres(&self) -> Result<sqlx::postgres::PgArguments, sqlx::error::BoxDynError> {
                 let mut args = sqlx::postgres::PgArguments::default();
-                #(#args_add)*
+                #args_add
                 Ok(args)
             }
 
@@ -135,6 +203,52 @@ pub fn generate_template_struct_and_impl(
 }
 
 
+/**
+#[output = "UserSelectedEntity"] decodes the full row by SelectedEntity::from_row_full,
+#[output = "i64"] decodes the first column, #[output = "(i64, String)"] decodes columns in order,
+template without #[output] only executes
+*/
+// only templates with #[output] implement OutputTemplateRecord, so query can not be called on the others
+fn gen_output_stream(ident: &Ident, generics: &Generics, output: Option<String>) -> TokenStream {
+    let Some(output) = output else {
+        return TokenStream::new();
+    };
+    let output_type: Type = syn::parse_str(&output)
+        .unwrap_or_else(|err| panic!("#[output = \"{}\"] is not a valid type: {}", output, err));
+    let decode_fns = [
+        ("decode_output_sqlite", quote! { sqlx::sqlite::SqliteRow }, quote! { sqlx::Sqlite }),
+        ("decode_output_mysql", quote! { sqlx::mysql::MySqlRow }, quote! { sqlx::MySql }),
+        ("decode_output_postgres", quote! { sqlx::postgres::PgRow }, quote! { sqlx::Postgres }),
+    ]
+    .into_iter()
+    .map(|(fn_name, row_type, db_type)| {
+        let fn_ident = format_ident!("{}", fn_name);
+        let decode_stream = match &output_type {
+            Type::Tuple(tuple) => {
+                let values = tuple.elems.iter().enumerate().map(|(index, elem)| {
+                    quote! { sqlx::Row::try_get::<#elem, _>(&row, #index)? }
+                });
+                quote! { Ok(( #(#values,)* )) }
+            }
+            ty if DefaultTypeChecker::type_is_scalar(ty) => quote! { sqlx::Row::try_get::<#ty, _>(&row, 0) },
+            ty => quote! { <#ty as taitan_orm::traits::SelectedEntity<#db_type>>::from_row_full(row) },
+        };
+        quote! {
+            fn #fn_ident(&self, row: #row_type) -> Result<Self::Output, sqlx::Error> {
+                #decode_stream
+            }
+        }
+    });
+    let impl_ident = build_impl_trait_token(ident, generics, "taitan_orm::traits::OutputTemplateRecord");
+    quote! {
+        #impl_ident {
+            type Output = #output_type;
+
+            #(#decode_fns)*
+        }
+    }
+}
+
 fn gen_get_pagination_fn_stream(limit_field_name: &Option<&String>)-> TokenStream {
     match limit_field_name {
         None=> quote! {
@@ -153,52 +267,67 @@ fn gen_get_pagination_fn_stream(limit_field_name: &Option<&String>)-> TokenStrea
     }
 }
 
-fn generate_dot_variables(idents: &Vec<Ident>) -> TokenStream {
-    let mut idents_iter = idents.into_iter().peekable();
-    let tokens = std::iter::from_fn(|| {
-        if let Some(ident) = idents_iter.next() {
-            // Check if there is a next item after this one
-            let has_next = idents_iter.peek().is_some();
-            let dot = if has_next { Some(quote!(.)) } else { None };
-            Some(quote! {
-                #ident #dot
-            })
-        } else {
-            None
-        }
-    }).collect::<TokenStream>();
-    tokens
-}
-fn gen_args_add_clause<T: AsRef<str>>(fields: &Vec<T>) -> Vec<TokenStream> {
-    fields
-        .iter()
-        .map(|variable| {
-            let variable_names = variable.as_ref().split('.').collect::<Vec<&str>>();
-            // panic!("{:?}", variable_names);
-            let variable_idents = variable_names.iter().map(|name| format_ident!("{}", name)).collect::<Vec<Ident>>();
-            let variable_stream = generate_dot_variables(&variable_idents);
-            // panic!("{}", variable_stream);
-            quote! {
-                sqlx::Arguments::add(&mut args, &self.#variable_stream )?;
-            }
-        })
-        .collect::<Vec<TokenStream>>()
-}
-
 struct SqlRenderFnStream {
     fn_stream: TokenStream,
     struct_stream: Option<TokenStream>,
 }
 
+/**
+every ${field} is validated before rendering, quoted by taitan_orm::traits::quote_raw_ident,
+or rendered verbatim by taitan_orm::traits::keyword_raw_ident when declared by #[raw(keyword_of)],
+so only fields, or sub fields of fields, can be used by ${}, loop variables are not allowed
+*/
+fn gen_raw_idents_stream(parsed_template_sql: &ParsedTemplateSql, fields: &FieldsNamed) -> TokenStream {
+    if parsed_template_sql.dollar_signs.is_empty() {
+        return TokenStream::new();
+    }
+    let raw_idents = format_ident!("{}", ParsedTemplateSql::RAW_IDENTS);
+    let quoted_values = parsed_template_sql.dollar_signs.iter().map(|variable| {
+        let segs: Vec<&str> = variable.split('.').collect();
+        let field = fields
+            .named
+            .iter()
+            .find(|field| field.ident.as_ref().unwrap() == segs[0])
+            .unwrap_or_else(|| panic!("${{{}}} is not a field of the template record, only fields can be used by ${{}}", variable));
+        let idents = segs.iter().map(|seg| format_ident!("{}", seg)).collect::<Vec<_>>();
+        let value = quote! { &self.#(#idents).*.to_string() };
+        match DefaultAttrParser::extract_raw_attr(field) {
+            None => quote! {
+                taitan_orm::traits::quote_raw_ident(#variable, #value, None, wrap_char)?
+            },
+            Some(RawAttr { one_of: Some(_), keyword_of: Some(_) }) => {
+                panic!("#[raw] of {} can not have both one_of and keyword_of", variable)
+            }
+            Some(RawAttr { one_of: Some(one_of), .. }) => {
+                let allowed = RawAttr::allowed_values(&one_of);
+                quote! {
+                    taitan_orm::traits::quote_raw_ident(#variable, #value, Some(&[#(#allowed),*]), wrap_char)?
+                }
+            }
+            Some(RawAttr { keyword_of: Some(keyword_of), .. }) => {
+                let keywords = RawAttr::allowed_values(&keyword_of);
+                quote! {
+                    taitan_orm::traits::keyword_raw_ident(#variable, #value, &[#(#keywords),*])?
+                }
+            }
+            Some(_) => panic!("#[raw] of {} needs one_of or keyword_of, for example: #[raw(one_of = \"user,admin\")]", variable),
+        }
+    });
+    quote! {
+        template.#raw_idents = vec![ #(#quoted_values),* ];
+    }
+}
+
 fn gen_fn_get_count_sql(
     ident: &Ident,
     data: &Data,
+    fields: &FieldsNamed,
     generics: &Generics,
     parsed_template_sql_opt: Option<&ParsedTemplateSql>,
 ) -> SqlRenderFnStream {
     if parsed_template_sql_opt.is_none() {
         let fn_stream = quote! {
-            fn get_count_sql(&self) -> Option<String> { None }
+            fn get_count_sql(&self) -> Result<Option<String>, taitan_orm::traits::NotValidTemplateError> { Ok(None) }
         };
         return SqlRenderFnStream {
             fn_stream,
@@ -210,13 +339,23 @@ fn gen_fn_get_count_sql(
     let marked_sql = &parsed_template_sql.sql;
 
     if parsed_template_sql.need_render() {
+        let with_raw_idents = !parsed_template_sql.dollar_signs.is_empty();
         let template_struct_stream =
-            copy_to_template_struct(ident, data, generics, &marked_sql, "CountTemplate");
+            copy_to_template_struct(ident, data, generics, marked_sql, "CountTemplate", with_raw_idents);
         let template_struct_name = format_ident!("{}CountTemplate", ident);
+        let raw_idents_stream = gen_raw_idents_stream(parsed_template_sql, fields);
         let fn_stream = quote! {
-            fn get_count_sql(&self) -> Option<String> {
-                let template = #template_struct_name::from(self);
-                rinja::Template::render(&template).ok()
+            fn get_count_sql(&self) -> Result<Option<String>, taitan_orm::traits::NotValidTemplateError> {
+                self.render_count_sql('`')
+            }
+
+            fn render_count_sql(&self, wrap_char: char) -> Result<Option<String>, taitan_orm::traits::NotValidTemplateError> {
+                #[allow(unused_mut)]
+                let mut template = #template_struct_name::from(self);
+                #raw_idents_stream
+                let count_sql = rinja::Template::render(&template)
+                    .map_err(|err| taitan_orm::traits::NotValidTemplateError(err.to_string()))?;
+                Ok(Some(count_sql))
             }
         };
         SqlRenderFnStream {
@@ -225,8 +364,8 @@ fn gen_fn_get_count_sql(
         }
     } else {
         let fn_stream = quote! {
-            fn get_count_sql(&self) -> Option<String> {
-                Some(String::from(#marked_sql))
+            fn get_count_sql(&self) -> Result<Option<String>, taitan_orm::traits::NotValidTemplateError> {
+                Ok(Some(String::from(#marked_sql)))
             }
         };
         SqlRenderFnStream {
@@ -239,20 +378,21 @@ fn gen_fn_get_count_sql(
 fn gen_fn_get_sql(
     ident: &Ident,
     data: &Data,
+    fields: &FieldsNamed,
     generics: &Generics,
     parsed_template_sql: &ParsedTemplateSql,
 ) -> SqlRenderFnStream {
     let marked_sql = &parsed_template_sql.sql;
     let template_struct_name = format_ident!("{}Template", ident);
-    if parsed_template_sql.dollar_signs.is_empty() {
+    if !parsed_template_sql.need_render() {
         let fn_stream = quote! {
-            fn get_sql(&self, page: Option<&taitan_orm::traits::Pagination>) -> String {
+            fn get_sql(&self, page: Option<&taitan_orm::traits::Pagination>) -> Result<String, taitan_orm::traits::NotValidTemplateError> {
                     if let Some(page) = page {
                         let offset = page.page_size * page.page_num;
                         let count = page.page_size;
-                        format!("{} LIMIT {}, {}", #marked_sql, offset, count)
+                        Ok(format!("{} LIMIT {}, {}", #marked_sql, offset, count))
                     } else {
-                        String::from(#marked_sql)
+                        Ok(String::from(#marked_sql))
                     }
                 }
         };
@@ -261,22 +401,31 @@ fn gen_fn_get_sql(
             struct_stream: None,
         }
     } else {
+        let with_raw_idents = !parsed_template_sql.dollar_signs.is_empty();
         let template_struct_stream =
-            copy_to_template_struct(ident, data, generics, &marked_sql, "Template");
+            copy_to_template_struct(ident, data, generics, marked_sql, "Template", with_raw_idents);
+        let raw_idents_stream = gen_raw_idents_stream(parsed_template_sql, fields);
         let fn_stream = quote! {
-            fn get_sql(&self, page: Option<&taitan_orm::traits::Pagination>) -> String {
-                    let template = #template_struct_name::from(self);
-                    let marked_sql_result = rinja::Template::render(&template);
-                    if marked_sql_result.is_err() {
-                        return String::default();
-                    }
-                    let marked_sql = marked_sql_result.unwrap();
+            fn get_sql(&self, page: Option<&taitan_orm::traits::Pagination>) -> Result<String, taitan_orm::traits::NotValidTemplateError> {
+                    self.render_sql(page, '`')
+                }
+
+            fn render_sql(
+                &self,
+                page: Option<&taitan_orm::traits::Pagination>,
+                wrap_char: char,
+            ) -> Result<String, taitan_orm::traits::NotValidTemplateError> {
+                    #[allow(unused_mut)]
+                    let mut template = #template_struct_name::from(self);
+                    #raw_idents_stream
+                    let marked_sql = rinja::Template::render(&template)
+                        .map_err(|err| taitan_orm::traits::NotValidTemplateError(err.to_string()))?;
                     if let Some(page) = page {
                         let offset = page.page_size * page.page_num;
                         let count = page.page_size;
-                        format!("{} LIMIT {}, {}", marked_sql, offset, count)
+                        Ok(format!("{} LIMIT {}, {}", marked_sql, offset, count))
                     } else {
-                        marked_sql
+                        Ok(marked_sql)
                     }
                 }
         };
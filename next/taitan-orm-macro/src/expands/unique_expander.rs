@@ -6,12 +6,16 @@ use syn::{Attribute, Field, FieldsNamed};
 
 use crate::attrs::{AttrParser, DefaultAttrParser};
 use crate::fields::UniqueParser;
+use super::soft_delete_expander::generate_soft_delete_fn;
+use super::hooks_expander::{generate_hooks_fn, HooksTarget};
 
 fn generate_struct_and_impl(
     table_name: &str,
     struct_name: &str,
     fields: &Vec<Field>,
     mutation_fields: &Vec<Field>,
+    soft_delete_stream: &TokenStream,
+    hooks_stream: &TokenStream,
 ) -> TokenStream {
     let unique_field_names = FieldsParser::from_vec(fields).get_unique_field_names();
     let unique_arguments_sqlite = FieldsParser::from_vec(fields).gen_unique_arguments_sqlite();
@@ -44,6 +48,10 @@ fn generate_struct_and_impl(
                 #unique_field_names
             }
 
+            #soft_delete_stream
+
+            #hooks_stream
+
             fn gen_update_arguments_sqlite<'a>(
                 &'a self,
                 mutation: &'a Self::Mutation,
@@ -90,9 +98,12 @@ pub fn generate_unique_structs_and_impls(
     let table_name = DefaultAttrParser::extract_table_name(ident, attrs);
     let fields_vec = FieldsParser::from_named(fields).filter_annotated_fields("primary_key");
     let mutation_fields_vec = FieldsParser::from_named(fields).filter_not_annotated_fields("primary_key");
+    let mutation_fields_vec = FieldsParser::from_vec(&mutation_fields_vec).filter_not_annotated_fields("version");
+    let soft_delete_stream = generate_soft_delete_fn(fields);
+    let hooks_stream = generate_hooks_fn(DefaultAttrParser::extract_hooks(attrs).unique, HooksTarget::Unique);
     let primary_struct_name = format!("{}Primary", table_name.to_camel());
     let mut all_unique_stream =
-        generate_struct_and_impl(&table_name, &primary_struct_name, &fields_vec, &mutation_fields_vec);
+        generate_struct_and_impl(&table_name, &primary_struct_name, &fields_vec, &mutation_fields_vec, &soft_delete_stream, &hooks_stream);
 
 
 
@@ -107,7 +118,7 @@ pub fn generate_unique_structs_and_impls(
         });
         unique_struct_name.push_str("Unique");
         let unique_stream =
-            generate_struct_and_impl(&table_name, &unique_struct_name, &unique_fields, &mutation_fields_vec);
+            generate_struct_and_impl(&table_name, &unique_struct_name, &unique_fields, &mutation_fields_vec, &soft_delete_stream, &hooks_stream);
         all_unique_stream.extend(unique_stream);
     });
 
@@ -1,11 +1,14 @@
 
 use crate::fields::table_name_parser::TableNameParser;
 use crate::fields::FieldsParser;
-use crate::fields::{DefaultFieldMapper, FieldMapType, FieldMapper, FieldsContainer, FieldsFilter};
+use crate::fields::{FieldsContainer, FieldsFilter};
 use proc_macro2::{TokenStream};
 use quote::quote;
+use syn::{Field, LitStr};
+use crate::attrs::{AttrParser, DefaultAttrParser};
+use crate::types::{DefaultTypeChecker, DefaultTypeExtractor, TypeChecker, TypeExtractor};
 use crate::fields::fields_mapper::FieldsMapper;
-use crate::fields::mappers::{ArgsConstructorMySql, ArgsConstructorPostgres, ArgsConstructorSqlite, NamesConstructor};
+use crate::fields::mappers::{of_timestamp_fill, ArgsAddConstructor, ArgsConstructorMySql, ArgsConstructorPostgres, ArgsConstructorSqlite, NamesConstructor};
 
 /**
 和trait Entity 一一对应
@@ -14,8 +17,17 @@ pub trait EntityParser: FieldsContainer + TableNameParser {
     fn get_insert_fields(&self) -> TokenStream;
     fn get_upsert_set_fields(&self) -> TokenStream;
     fn get_auto_increment_field(&self) -> TokenStream;
+    fn get_primary_key_names(&self) -> TokenStream;
     fn set_auto_increment_field(&mut self) -> TokenStream;
+    fn get_generated_fields(&self) -> TokenStream;
+    fn set_generated_fields(&self) -> TokenStream;
+    fn fill_auto_timestamps(&self) -> TokenStream;
+    fn get_reselect_key_names(&self, unique_keys: &[Vec<String>]) -> TokenStream;
+    fn gen_reselect_arguments_sqlite(&self, unique_keys: &[Vec<String>]) -> TokenStream;
+    fn gen_reselect_arguments_mysql(&self, unique_keys: &[Vec<String>]) -> TokenStream;
+    fn gen_reselect_arguments_postgres(&self, unique_keys: &[Vec<String>]) -> TokenStream;
     fn gen_insert_arguments_sqlite(&self) -> TokenStream;
+    fn add_insert_arguments(&self) -> TokenStream;
     fn gen_upsert_arguments_sqlite(&self) -> TokenStream;
     fn gen_insert_arguments_mysql(&self) -> TokenStream;
     fn gen_upsert_arguments_mysql(&self) -> TokenStream;
@@ -40,8 +52,8 @@ impl EntityParser for FieldsParser {
             quote! { None }
         } else {
             let auto_field = auto_field_opt.clone().unwrap();
-            let auto_field_name =
-                DefaultFieldMapper::map_field(auto_field, FieldMapType::Str);
+            let auto_field_alias = DefaultAttrParser::extract_field_db_ident(&auto_field);
+            let auto_field_name = LitStr::new(&auto_field_alias.to_string(), auto_field_alias.span());
             quote! {
                 Some(#auto_field_name)
             }
@@ -54,22 +66,105 @@ impl EntityParser for FieldsParser {
             quote! { false }
         } else {
             let auto_field = auto_field_opt.unwrap();
+            let auto_field_type = DefaultTypeExtractor::get_option_inner_type(&auto_field.ty).unwrap().clone();
             let auto_field_name = auto_field.ident.unwrap();
-            // quote! {
-            //     self.#auto_field_name = value;
-            //     true
-            // }
             quote! {
-                true
+                let Some(value) = value else {
+                    self.#auto_field_name = None.into();
+                    return true;
+                };
+                match <#auto_field_type as std::convert::TryFrom<i64>>::try_from(value) {
+                    Ok(value) => {
+                        self.#auto_field_name = Some(value).into();
+                        true
+                    }
+                    Err(_) => false,
+                }
             }
         }
     }
 
+    fn get_generated_fields(&self) -> TokenStream {
+        let generated_fields = self.filter_annotated_fields("generated");
+        let tokens = generated_fields.iter().map(|field| {
+            let field_alias = DefaultAttrParser::extract_field_db_ident(field);
+            let field_alias_lit = LitStr::new(&field_alias.to_string(), field_alias.span());
+            quote! { taitan_orm::FieldName::from_str(#field_alias_lit, false) }
+        });
+        quote! {
+            vec![ #(#tokens,)* ]
+        }
+    }
+
+    // the row is expected to contain auto increment field and every generated field, named by its database alias
+    fn set_generated_fields(&self) -> TokenStream {
+        let mut generated_fields: Vec<Field> = self.get_auto_increment_field_opt().into_iter().collect();
+        generated_fields.extend(self.filter_annotated_fields("generated"));
+        let tokens = generated_fields.iter().map(|field| {
+            let field_alias = DefaultAttrParser::extract_field_db_ident(field);
+            let field_alias_lit = LitStr::new(&field_alias.to_string(), field_alias.span());
+            let field_name = field.ident.as_ref().unwrap();
+            if DefaultTypeChecker::type_is_option(&field.ty) {
+                let inner_type = DefaultTypeExtractor::get_option_inner_type(&field.ty).unwrap();
+                quote! {
+                    let #field_name: Option<#inner_type> = sqlx::Row::try_get(row, #field_alias_lit)?;
+                    self.#field_name = #field_name.into();
+                }
+            } else {
+                quote! {
+                    self.#field_name = sqlx::Row::try_get(row, #field_alias_lit)?;
+                }
+            }
+        });
+        quote! {
+            #(#tokens)*
+            Ok(())
+        }
+    }
+
+    fn fill_auto_timestamps(&self) -> TokenStream {
+        let tokens = self.get_fields().iter().filter_map(of_timestamp_fill);
+        quote! {
+            #(#tokens)*
+        }
+    }
+
+    fn get_primary_key_names(&self) -> TokenStream {
+        FieldsParser::from_vec(&self.filter_annotated_fields("primary_key")).of_names_array()
+    }
+
+    fn get_reselect_key_names(&self, unique_keys: &[Vec<String>]) -> TokenStream {
+        FieldsParser::from_vec(&self.get_reselect_key_fields(unique_keys)).of_names_array()
+    }
+
+    fn gen_reselect_arguments_sqlite(&self, unique_keys: &[Vec<String>]) -> TokenStream {
+        FieldsParser::from_vec(&self.get_reselect_key_fields(unique_keys)).of_not_option_args_sqlite()
+    }
+
+    fn gen_reselect_arguments_mysql(&self, unique_keys: &[Vec<String>]) -> TokenStream {
+        FieldsParser::from_vec(&self.get_reselect_key_fields(unique_keys)).of_not_option_args_mysql()
+    }
+
+    fn gen_reselect_arguments_postgres(&self, unique_keys: &[Vec<String>]) -> TokenStream {
+        FieldsParser::from_vec(&self.get_reselect_key_fields(unique_keys)).of_not_option_args_postgres()
+    }
+
     fn gen_insert_arguments_sqlite(&self) -> TokenStream {
         let all_fields = self.get_insert_fields_vec();
         FieldsParser::from_vec(&all_fields).of_maybe_option_args_sqlite()
     }
 
+    // the generated body is the same for all databases, only the buffer type differs
+    fn add_insert_arguments(&self) -> TokenStream {
+        let all_fields = self.get_insert_fields_vec();
+        let args_add_clause = FieldsParser::from_vec(&all_fields)
+            .map_field_vec(&<FieldsParser as ArgsAddConstructor>::of_maybe_option_buffer);
+        quote! {
+            #(#args_add_clause)*
+            Ok(())
+        }
+    }
+
     fn gen_upsert_arguments_sqlite(&self) -> TokenStream {
         let all_fields = self.get_upsert_fields_vec();
         FieldsParser::from_vec(&all_fields).of_maybe_option_args_sqlite()
@@ -75,7 +75,9 @@ pub trait FieldMapper {
         let span = field_name.span();
         quote_spanned! { span =>
             if let Some(#field_name) = &self.#field_name {
-                args.add(#field_name.val)?;
+                for value in #field_name.get_values()? {
+                    args.add(value)?;
+                }
             }
         }
     }
@@ -149,13 +151,15 @@ pub trait FieldMapper {
         quote_spanned! { span =>
             match &self.#field_name {
                 Optional::Some(#field_name) => {
-                    sql.push(wrap_char);
-                    sql.push_str(#field_name_lit);
-                    sql.push(wrap_char);
-                    sql.push_str(#field_name.cmp.get_sql());
-                    sql.push(place_holder);
+                    if !sql.is_empty() {
+                        sql.push_str(" AND ");
+                    }
+                    sql.push_str(&#field_name.get_where_sql(#field_name_lit, wrap_char, place_holder));
                 },
                 Optional::Null => {
+                    if !sql.is_empty() {
+                        sql.push_str(" AND ");
+                    }
                     sql.push(wrap_char);
                     sql.push_str(#field_name_lit);
                     sql.push(wrap_char);
@@ -166,6 +170,30 @@ pub trait FieldMapper {
         }
     }
 
+    // same as map_to_where_field, but field is qualified by table_name: `user`.`age`
+    fn map_to_qualified_where_field(field: Field) -> TokenStream {
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        let field_name_lit = LitStr::new(&field_name.to_string(), span);
+        quote_spanned! { span =>
+            match &self.#field_name {
+                Optional::Some(#field_name) => {
+                    if !sql.is_empty() {
+                        sql.push_str(" AND ");
+                    }
+                    sql.push_str(&#field_name.get_qualified_where_sql(table_name, #field_name_lit, wrap_char, place_holder));
+                },
+                Optional::Null => {
+                    if !sql.is_empty() {
+                        sql.push_str(" AND ");
+                    }
+                    sql.push_str(&format!("{}{}{}.{}{}{} IS NULL ", wrap_char, table_name, wrap_char, wrap_char, #field_name_lit, wrap_char));
+                }
+                _=>{}
+            }
+        }
+    }
+
     fn map_field(field: Field, map_type: FieldMapType) -> TokenStream {
         let field_name = field.ident.unwrap();
         let span = field_name.span();
@@ -240,7 +268,9 @@ pub trait FieldMapper {
             FieldMapType::ArgsAddOptionLocationRef => {
                 quote_spanned! { span =>
                     if let Some(#field_name) = &self.#field_name {
-                        luna_add_arg(&mut arguments, &#field_name.val);
+                        for value in #field_name.val.as_vec() {
+                            luna_add_arg(&mut arguments, value);
+                        }
                     }
                 }
             }
@@ -1,5 +1,6 @@
 use crate::attrs::{AttrParser, DefaultAttrParser};
 use crate::fields::{FieldsContainer, FieldsParser};
+use crate::types::{DefaultTypeChecker, TypeChecker};
 use syn::Field;
 
 pub trait FieldsFilter: FieldsContainer {
@@ -12,6 +13,7 @@ pub trait FieldsFilter: FieldsContainer {
     fn get_upsert_fields_vec(&self) -> Vec<Field>;
     fn get_upsert_set_fields_vec(&self) -> Vec<Field>;
     fn get_auto_increment_field_opt(&self) -> Option<Field>;
+    fn get_reselect_key_fields(&self, unique_keys: &[Vec<String>]) -> Vec<Field>;
 }
 
 impl FieldsFilter for FieldsParser {
@@ -89,16 +91,18 @@ impl FieldsFilter for FieldsParser {
             FieldsParser::from_vec(self.get_fields()).filter_not_annotated_fields("primary_key");
         let mut all_fields: Vec<Field> = Vec::new();
         all_fields.extend(primary_fields);
-        all_fields.extend(body_fields.clone());
         all_fields.extend(body_fields);
         all_fields = FieldsParser::from_vec(&all_fields).filter_not_auto_generated();
+        all_fields.extend(self.get_upsert_set_fields_vec());
         all_fields
     }
 
+    // #[created_at] is written by insert only, it is never overwritten by upsert
     fn get_upsert_set_fields_vec(&self) -> Vec<Field> {
         let mut body_fields =
             FieldsParser::from_vec(self.get_fields()).filter_not_annotated_fields("primary_key");
         body_fields = FieldsParser::from_vec(&body_fields).filter_not_auto_generated();
+        body_fields = FieldsParser::from_vec(&body_fields).filter_not_annotated_fields("created_at");
         body_fields
     }
 
@@ -112,4 +116,21 @@ impl FieldsFilter for FieldsParser {
             return Some(first_one.unwrap().to_owned());
         }
     }
+
+    // primary key first, then unique keys in declaration order, skipping keys with values filled by database
+    fn get_reselect_key_fields(&self, unique_keys: &[Vec<String>]) -> Vec<Field> {
+        let primary_fields = self.filter_annotated_fields("primary_key");
+        let unique_fields = unique_keys.iter().map(|names| self.filter_named_fields(names));
+        std::iter::once(primary_fields)
+            .chain(unique_fields)
+            .find(|fields| {
+                !fields.is_empty()
+                    && fields.iter().all(|field| {
+                        !DefaultTypeChecker::type_is_option(&field.ty)
+                            && !DefaultAttrParser::check_has_attr(&field.attrs, "generated")
+                            && !DefaultAttrParser::check_has_attr(&field.attrs, "auto_increment")
+                    })
+            })
+            .unwrap_or_default()
+    }
 }
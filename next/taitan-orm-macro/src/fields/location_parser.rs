@@ -1,9 +1,10 @@
 use crate::fields::mappers::{
-    ArgsConstructorMySql, ArgsConstructorPostgres, ArgsConstructorSqlite, NamesAddConstructor,
+    ArgsAddConstructor, ArgsConstructorMySql, ArgsConstructorPostgres, ArgsConstructorSqlite, NamesAddConstructor,
     NamesConstructor,
 };
 use crate::fields::{FieldsContainer, TableNameParser};
 use proc_macro2::TokenStream;
+use quote::quote;
 
 pub trait LocationParser:
     FieldsContainer
@@ -13,6 +14,7 @@ pub trait LocationParser:
     + ArgsConstructorPostgres
     + ArgsConstructorSqlite
     + ArgsConstructorMySql
+    + ArgsAddConstructor
 {
     fn get_location_fields_name(&self) -> TokenStream {
         self.of_option_names_vec()
@@ -33,4 +35,13 @@ pub trait LocationParser:
     fn gen_location_arguments_postgres(&self) -> TokenStream {
         self.of_location_args_postgres()
     }
+
+    // the generated body is the same for all databases, only the buffer type differs
+    fn add_location_arguments(&self) -> TokenStream {
+        let args_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_location_buffer);
+        quote! {
+            #(#args_add_clause)*
+            Ok(())
+        }
+    }
 }
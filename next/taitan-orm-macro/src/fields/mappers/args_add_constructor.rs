@@ -1,15 +1,17 @@
 use crate::types::{DefaultTypeChecker, TypeChecker};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote_spanned;
+use syn::spanned::Spanned;
 use syn::{Field};
+use super::timestamp::{is_updated_at, of_timestamp_now};
 
 /**
 1. entity,    maybe option -> #field_name
 2. unique,    not option   -> #field_name
-3. location,  option,      -> #field_name.val
+3. location,  option,      -> #field_name.get_values()
 4. location + page, option + not option -> #field_name
 
-5. mutation + location, option + option -> #field_name, #field_name.val
+5. mutation + location, option + option -> #field_name, #field_name.get_values()
 6. mutation + primary,  option + not option -> #field_name
 -- 7. mutation + unique -> 2个encode Vec<Encode>
 */
@@ -32,6 +34,24 @@ pub trait ArgsAddConstructor {
         }
     }
 
+    // same as of_maybe_option, but append to an existing buffer: args: &mut Arguments
+    fn of_maybe_option_buffer(field: Field) -> TokenStream {
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        let field_type = field.ty;
+        if DefaultTypeChecker::type_is_option(&field_type) {
+            quote_spanned! { span =>
+                if let taitan_orm::Optional::Some(#field_name) = &self.#field_name {
+                    sqlx::Arguments::add(args, #field_name)?;
+                }
+            }
+        } else {
+            quote_spanned! { span =>
+                sqlx::Arguments::add(args, &self.#field_name)?;
+            }
+        }
+    }
+
     // treat field as not option, no matter weather field is actually option or not
     fn of_not_option(field: Field) -> TokenStream {
         let field_name = field.ident.unwrap();
@@ -50,6 +70,72 @@ pub trait ArgsAddConstructor {
         }
     }
 
+    // value of Assign::Set/Incr/Decr is bound, Null/Copy have no placeholder
+    fn of_assign(field: Field) -> TokenStream {
+        let now = of_timestamp_now(&field);
+        let updated_at = is_updated_at(&field);
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        if updated_at {
+            return quote_spanned! { span =>
+                match self.#field_name.get_value() {
+                    Some(#field_name) => sqlx::Arguments::add(&mut args, #field_name)?,
+                    None if self.#field_name.is_none() => sqlx::Arguments::add(&mut args, #now)?,
+                    None => {}
+                }
+            };
+        }
+        quote_spanned! { span =>
+            if let Some(#field_name) = self.#field_name.get_value() {
+                sqlx::Arguments::add(&mut args, #field_name)?;
+            }
+        }
+    }
+
+    fn of_assign_with(param_name: &str, field: Field) -> TokenStream {
+        let now = of_timestamp_now(&field);
+        let updated_at = is_updated_at(&field);
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        let param_ident = Ident::new(param_name, Span::call_site());
+        if updated_at {
+            return quote_spanned! { span =>
+                match #param_ident.#field_name.get_value() {
+                    Some(#field_name) => sqlx::Arguments::add(&mut args, #field_name)?,
+                    None if #param_ident.#field_name.is_none() => sqlx::Arguments::add(&mut args, #now)?,
+                    None => {}
+                }
+            };
+        }
+        quote_spanned! { span =>
+            if let Some(#field_name) = #param_ident.#field_name.get_value() {
+                sqlx::Arguments::add(&mut args, #field_name)?;
+            }
+        }
+    }
+
+    // same as of_assign, but append to an existing buffer: args: &mut Arguments
+    fn of_assign_buffer(field: Field) -> TokenStream {
+        let now = of_timestamp_now(&field);
+        let updated_at = is_updated_at(&field);
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        if updated_at {
+            return quote_spanned! { span =>
+                match self.#field_name.get_value() {
+                    Some(#field_name) => sqlx::Arguments::add(args, #field_name)?,
+                    None if self.#field_name.is_none() => sqlx::Arguments::add(args, #now)?,
+                    None => {}
+                }
+            };
+        }
+        quote_spanned! { span =>
+            if let Some(#field_name) = self.#field_name.get_value() {
+                sqlx::Arguments::add(args, #field_name)?;
+            }
+        }
+    }
+
     // treat field as option, no matter weather field is actually option or not
     fn of_option(field: Field) -> TokenStream {
         let field_name = field.ident.unwrap();
@@ -61,24 +147,28 @@ pub trait ArgsAddConstructor {
         }
     }
 
-    fn of_option_with(param_name: &str, field: Field) -> TokenStream {
+    // treat field as option, no matter weather field is actually option or not, bind every value of #field_name.get_values()
+    fn of_location(field: Field) -> TokenStream {
         let field_name = field.ident.unwrap();
         let span = field_name.span();
-        let param_ident = Ident::new(&param_name, Span::call_site());
         quote_spanned! { span =>
-            if let taitan_orm::Optional::Some(#field_name) = &#param_ident.#field_name {
-                sqlx::Arguments::add(&mut args, #field_name)?;
+            if let taitan_orm::Optional::Some(#field_name) = &self.#field_name {
+                for value in #field_name.get_values()? {
+                    sqlx::Arguments::add(&mut args, value)?;
+                }
             }
         }
     }
 
-    // treat field as option, no matter weather field is actually option or not, get value from #field_name.val
-    fn of_location(field: Field) -> TokenStream {
+    // same as of_location, but append to an existing buffer: args: &mut Arguments
+    fn of_location_buffer(field: Field) -> TokenStream {
         let field_name = field.ident.unwrap();
         let span = field_name.span();
         quote_spanned! { span =>
             if let taitan_orm::Optional::Some(#field_name) = &self.#field_name {
-                sqlx::Arguments::add(&mut args, &#field_name.val)?;
+                for value in #field_name.get_values()? {
+                    sqlx::Arguments::add(args, value)?;
+                }
             }
         }
     }
@@ -89,7 +179,9 @@ pub trait ArgsAddConstructor {
         let location_ident = Ident::new(&location_name, Span::call_site());
         quote_spanned! { span =>
             if let taitan_orm::Optional::Some(#field_name) = &#location_ident.#field_name {
-                sqlx::Arguments::add(&mut args, &#field_name.val)?;
+                for value in #field_name.get_values()? {
+                    sqlx::Arguments::add(&mut args, value)?;
+                }
             }
         }
     }
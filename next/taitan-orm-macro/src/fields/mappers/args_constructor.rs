@@ -45,7 +45,7 @@ pub trait ArgsConstructorSqlite: FieldsContainer + ArgsAddConstructor {
         let unique_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_not_option);
         let mutation_add_clause =
             FieldsParser::from_vec(mutation_fields).map_field_vec(&|field: Field| {
-                <Self as ArgsAddConstructor>::of_option_with("mutation", field)
+                <Self as ArgsAddConstructor>::of_assign_with("mutation", field)
             });
         quote! {
             let mut args = sqlx::sqlite::SqliteArguments::default();
@@ -56,7 +56,7 @@ pub trait ArgsConstructorSqlite: FieldsContainer + ArgsAddConstructor {
     }
 
     fn of_update_args_sqlite(&self, primary_fields: &Vec<Field>) -> TokenStream {
-        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_option);
+        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_assign);
         let primary_add_clause =
             FieldsParser::from_vec(primary_fields).map_field_vec(&|field: Field| {
                 <Self as ArgsAddConstructor>::of_not_option_with("primary", field)
@@ -69,7 +69,7 @@ pub trait ArgsConstructorSqlite: FieldsContainer + ArgsAddConstructor {
         }
     }
     fn of_change_args_sqlite(&self, location_fields: &Vec<Field>) -> TokenStream {
-        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_option);
+        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_assign);
         let location_add_clause =
             FieldsParser::from_vec(location_fields).map_field_vec(&|field: Field| {
                 <Self as ArgsAddConstructor>::of_location_with("location", field)
@@ -120,7 +120,7 @@ pub trait ArgsConstructorMySql: FieldsContainer + ArgsAddConstructor {
     }
 
     fn of_update_args_mysql(&self, primary_fields: &Vec<Field>) -> TokenStream {
-        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_option);
+        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_assign);
         let primary_add_clause =
             FieldsParser::from_vec(primary_fields).map_field_vec(&|field: Field| {
                 <Self as ArgsAddConstructor>::of_not_option_with("primary", field)
@@ -137,7 +137,7 @@ pub trait ArgsConstructorMySql: FieldsContainer + ArgsAddConstructor {
         let unique_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_not_option);
         let mutation_add_clause =
             FieldsParser::from_vec(mutation_fields).map_field_vec(&|field: Field| {
-                <Self as ArgsAddConstructor>::of_option_with("mutation", field)
+                <Self as ArgsAddConstructor>::of_assign_with("mutation", field)
             });
         quote! {
             let mut args = sqlx::mysql::MySqlArguments::default();
@@ -148,7 +148,7 @@ pub trait ArgsConstructorMySql: FieldsContainer + ArgsAddConstructor {
     }
 
     fn of_change_args_mysql(&self, location_fields: &Vec<Field>) -> TokenStream {
-        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_option);
+        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_assign);
         let location_add_clause =
             FieldsParser::from_vec(location_fields).map_field_vec(&|field: Field| {
                 <Self as ArgsAddConstructor>::of_location_with("location", field)
@@ -200,7 +200,7 @@ pub trait ArgsConstructorPostgres: FieldsContainer + ArgsAddConstructor {
     }
 
     fn of_update_args_postgres(&self, primary_fields: &Vec<Field>) -> TokenStream {
-        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_option);
+        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_assign);
         let primary_add_clause =
             FieldsParser::from_vec(primary_fields).map_field_vec(&|field: Field| {
                 <Self as ArgsAddConstructor>::of_not_option_with("primary", field)
@@ -217,7 +217,7 @@ pub trait ArgsConstructorPostgres: FieldsContainer + ArgsAddConstructor {
         let unique_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_not_option);
         let mutation_add_clause =
             FieldsParser::from_vec(mutation_fields).map_field_vec(&|field: Field| {
-                <Self as ArgsAddConstructor>::of_option_with("mutation", field)
+                <Self as ArgsAddConstructor>::of_assign_with("mutation", field)
             });
         quote! {
             let mut args = sqlx::postgres::PgArguments::default();
@@ -228,7 +228,7 @@ pub trait ArgsConstructorPostgres: FieldsContainer + ArgsAddConstructor {
     }
 
     fn of_change_args_postgres(&self, location_fields: &Vec<Field>) -> TokenStream {
-        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_option);
+        let mutation_add_clause = self.map_field_vec(&<Self as ArgsAddConstructor>::of_assign);
         let location_add_clause =
             FieldsParser::from_vec(location_fields).map_field_vec(&|field: Field| {
                 <Self as ArgsAddConstructor>::of_location_with("location", field)
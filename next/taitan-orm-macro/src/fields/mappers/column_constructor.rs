@@ -0,0 +1,30 @@
+use crate::fields::FieldsContainer;
+use crate::types::{DefaultTypeChecker, DefaultTypeExtractor, TypeChecker, TypeExtractor};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote_spanned;
+use syn::{Field, LitStr};
+
+/**
+typed column handles of each field in the generated {Table}Columns: UserColumns::age() -> Column<UserLocation, i32>
+the condition is written into the same field of location, so value type is checked by the compiler
+*/
+pub trait ColumnConstructor: FieldsContainer {
+    fn of_column_fn(location_ident: &Ident, field: Field) -> TokenStream {
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        let field_name_lit = LitStr::new(&field_name.to_string(), span);
+        let field_ty = if DefaultTypeChecker::type_is_option(&field.ty) {
+            DefaultTypeExtractor::get_option_inner_type(&field.ty).unwrap().clone()
+        } else {
+            field.ty
+        };
+        quote_spanned! { span =>
+            pub fn #field_name() -> taitan_orm::traits::Column<#location_ident, #field_ty> {
+                taitan_orm::traits::Column::new(#field_name_lit, |expr| #location_ident {
+                    #field_name: taitan_orm::Optional::Some(expr),
+                    ..Default::default()
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::fields::FieldsContainer;
+use crate::types::{DefaultTypeChecker, DefaultTypeExtractor, TypeChecker, TypeExtractor};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{Field, LitStr};
+
+/**
+cursor of keyset pagination, order by fields are matched by struct field name, same as OrderBy
+1. selected -> cursor values: selected.#field_name must be Some
+2. cursor values -> args: restore typed value of #field_name and bind it
+*/
+pub trait CursorConstructor: FieldsContainer {
+    fn of_cursor_value(field: Field) -> TokenStream {
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        let field_name_lit = LitStr::new(&field_name.to_string(), span);
+        quote_spanned! { span =>
+            #field_name_lit => match &self.#field_name {
+                taitan_orm::Optional::Some(value) => values.push(taitan_orm::traits::CursorCodec::to_cursor_value(value)),
+                _ => return Err(taitan_orm::traits::NotValidCursorError(format!("{} is not selected or is null", field))),
+            },
+        }
+    }
+
+    fn of_cursor_argument(field: Field) -> TokenStream {
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        let field_name_lit = LitStr::new(&field_name.to_string(), span);
+        let field_ty = if DefaultTypeChecker::type_is_option(&field.ty) {
+            DefaultTypeExtractor::get_option_inner_type(&field.ty).unwrap().clone()
+        } else {
+            field.ty
+        };
+        quote_spanned! { span =>
+            #field_name_lit => {
+                let value: #field_ty = taitan_orm::traits::CursorCodec::from_cursor_value(value)?;
+                sqlx::Arguments::add(args, value)?;
+            }
+        }
+    }
+
+    fn gen_cursor_values(&self) -> TokenStream {
+        let tokens = self.map_field_vec(&<Self as CursorConstructor>::of_cursor_value);
+        quote! {
+            let mut values = Vec::new();
+            for field in fields {
+                match field.as_ref() {
+                    #(#tokens)*
+                    _ => return Err(taitan_orm::traits::NotValidCursorError(field.to_string())),
+                }
+            }
+            Ok(values)
+        }
+    }
+
+    fn gen_add_cursor_arguments(&self) -> TokenStream {
+        let tokens = self.map_field_vec(&<Self as CursorConstructor>::of_cursor_argument);
+        quote! {
+            if fields.len() != values.len() {
+                return Err(taitan_orm::traits::NotValidCursorError("cursor does not match order by fields".to_string()).into());
+            }
+            for (field, value) in fields.iter().zip(values) {
+                match field.as_ref() {
+                    #(#tokens)*
+                    _ => return Err(taitan_orm::traits::NotValidCursorError(field.to_string()).into()),
+                }
+            }
+            Ok(())
+        }
+    }
+}
@@ -6,6 +6,9 @@ mod names_constructor;
 mod names_add_constructor;
 mod row_get_constructor;
 mod row_constructor;
+mod cursor_constructor;
+mod column_constructor;
+mod timestamp;
 
 
 pub use struct_field_constructor::StructFieldConstructor;
@@ -20,4 +23,7 @@ pub use args_constructor::ArgsConstructorPostgres;
 pub use args_constructor::ArgsConstructorSqlite;
 
 pub use row_constructor::RowConstructor;
-pub use row_get_constructor::RowGetConstructor;
\ No newline at end of file
+pub use row_get_constructor::RowGetConstructor;
+pub use cursor_constructor::CursorConstructor;
+pub use column_constructor::ColumnConstructor;
+pub use timestamp::of_timestamp_fill;
@@ -4,6 +4,7 @@ use quote::quote_spanned;
 use syn::spanned::Spanned;
 use syn::{Field, LitStr};
 use crate::attrs::{AttrParser, DefaultAttrParser};
+use super::timestamp::is_updated_at;
 
 pub trait NamesAddConstructor {
     fn of_str(field: Field) -> TokenStream {
@@ -41,7 +42,7 @@ pub trait NamesAddConstructor {
         if DefaultTypeChecker::type_is_option(field_type) {
             quote_spanned! { span=>
                 match &self.#field_name {
-                    taitan_orm::Optional::Some(#field_name) => {
+                    taitan_orm::Optional::Some(_) => {
                         fields.push(taitan_orm::FieldName::from_str(#field_name_string, false));
                     }
                     taitan_orm::Optional::Null => {
@@ -57,6 +58,59 @@ pub trait NamesAddConstructor {
         }
     }
 
+    fn of_assign(field: Field) -> TokenStream {
+        let field_alias = DefaultAttrParser::extract_field_db_ident(&field);
+        let span = field.span();
+        let updated_at = is_updated_at(&field);
+        let field_name = field.ident.unwrap();
+        let field_name_string = LitStr::new(&field_alias.to_string(), span);
+        let none_arm = if updated_at {
+            quote_spanned! { span=>
+                fields.push(taitan_orm::FieldName::from_str(#field_name_string, false));
+            }
+        } else {
+            TokenStream::new()
+        };
+        quote_spanned! { span=>
+            match &self.#field_name {
+                taitan_orm::Assign::None => { #none_arm }
+                taitan_orm::Assign::Null => {
+                    fields.push(taitan_orm::FieldName::from_str(#field_name_string, true));
+                }
+                _ => {
+                    fields.push(taitan_orm::FieldName::from_str(#field_name_string, false));
+                }
+            };
+        }
+    }
+
+    // `age` = ? | `views` = `views` + ? | `memo` = NULL
+    fn of_assign_set_sql(field: Field) -> TokenStream {
+        let field_alias = DefaultAttrParser::extract_field_db_ident(&field);
+        let span = field.span();
+        let updated_at = is_updated_at(&field);
+        let field_name = field.ident.unwrap();
+        let field_name_string = LitStr::new(&field_alias.to_string(), span);
+        if updated_at {
+            // `updated_at` = ? bound with the current time when it is not assigned
+            return quote_spanned! { span=>
+                match &self.#field_name {
+                    taitan_orm::Assign::None => sets.push(format!("{}{}{} = {}", wrap_char, #field_name_string, wrap_char, place_holder)),
+                    value => {
+                        if let Some(set_sql) = value.get_set_sql(#field_name_string, wrap_char, place_holder) {
+                            sets.push(set_sql);
+                        }
+                    }
+                }
+            };
+        }
+        quote_spanned! { span=>
+            if let Some(set_sql) = self.#field_name.get_set_sql(#field_name_string, wrap_char, place_holder) {
+                sets.push(set_sql);
+            }
+        }
+    }
+
     fn of_option(field: Field) -> TokenStream {
         let field_alias = DefaultAttrParser::extract_field_db_ident(&field);
         let span = field.span();
@@ -70,7 +124,7 @@ pub trait NamesAddConstructor {
         // }
         quote_spanned! { span=>
             match &self.#field_name {
-                taitan_orm::Optional::Some(#field_name) => {
+                taitan_orm::Optional::Some(_) => {
                     fields.push(taitan_orm::FieldName::from_str(#field_name_string, false));
                 }
                 taitan_orm::Optional::Null => {
@@ -119,7 +173,7 @@ pub trait NamesAddConstructor {
         let field_name = field.ident.unwrap();
         let field_name_string = LitStr::new(&field_alias.to_string(), span);
         quote_spanned! { span=>
-            if self.#field_name.is_null() {
+            if self.#field_name.is_selected() {
                 fields.push(#field_name_string.to_string());
             }
         }
@@ -142,11 +196,10 @@ pub trait NamesAddConstructor {
         let field_name_lit = LitStr::new(&field_alias.to_string(), span);
         quote_spanned! { span =>
             if let taitan_orm::Optional::Some(#field_name) = &self.#field_name {
-                sql.push(wrap_char);
-                sql.push_str(#field_name_lit);
-                sql.push(wrap_char);
-                sql.push_str(#field_name.cmp.get_sql());
-                sql.push(place_holder);
+                if !sql.is_empty() {
+                    sql.push_str(" AND ");
+                }
+                sql.push_str(&#field_name.get_where_sql(#field_name_lit, wrap_char, place_holder));
             }
         }
     }
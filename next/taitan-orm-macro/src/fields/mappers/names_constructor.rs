@@ -36,6 +36,24 @@ pub trait NamesConstructor : FieldsContainer + NamesAddConstructor {
         )
     }
 
+    fn of_assign_names_vec(&self) -> TokenStream {
+        let tokens =self.map_field_vec(&<Self as NamesAddConstructor>::of_assign);
+        quote!(
+            let mut fields = Vec::new();
+            #(#tokens;)*
+            return fields;
+        )
+    }
+
+    fn of_assign_set_clause(&self) -> TokenStream {
+        let tokens =self.map_field_vec(&<Self as NamesAddConstructor>::of_assign_set_sql);
+        quote!(
+            let mut sets: Vec<String> = Vec::new();
+            #(#tokens)*
+            sets.join(",")
+        )
+    }
+
     fn of_not_option_names_vec(&self) -> TokenStream {
         let tokens =self.map_field_vec(&<Self as NamesAddConstructor>::of_not_option);
         quote!(
@@ -19,6 +19,8 @@ pub trait RowConstructor : FieldsContainer + RowGetConstructor {
             let mut selected = Self::default();
             let mut i = 0;
             #(#tokens;)*
+            // the last field increases i without reading it
+            let _ = i;
             Ok(selected)
         )
     }
@@ -29,6 +31,8 @@ pub trait RowConstructor : FieldsContainer + RowGetConstructor {
             let mut selected = Self::default();
             let mut i = 0;
             #(#tokens;)*
+            // the last field increases i without reading it
+            let _ = i;
             Ok(selected)
         )
     }
@@ -39,6 +43,19 @@ pub trait RowConstructor : FieldsContainer + RowGetConstructor {
             let mut selected = Self::default();
             let mut i = 0;
             #(#tokens;)*
+            // the last field increases i without reading it
+            let _ = i;
+            Ok(selected)
+        )
+    }
+
+    fn gen_selected_self_row_at(&self) -> TokenStream {
+        let tokens = self.map_field_vec(&<Self as RowGetConstructor>::of_selected_self_row_ref_i);
+        quote!(
+            let mut selected = Self::default();
+            let mut i = *offset;
+            #(#tokens;)*
+            *offset = i;
             Ok(selected)
         )
     }
@@ -58,6 +75,8 @@ pub trait RowConstructor : FieldsContainer + RowGetConstructor {
             let mut selected = Self::default();
             let mut i = 0;
             #(#tokens;)*
+            // the last field increases i without reading it
+            let _ = i;
             Ok(selected)
         )
     }
@@ -41,6 +41,18 @@ pub trait RowGetConstructor {
         }
     }
 
+    // row is a reference here, used when several entities are decoded from one row
+    fn of_selected_self_row_ref_i(field: Field) -> TokenStream {
+        let field_name = field.ident.unwrap();
+        let span = field_name.span();
+        quote_spanned! { span =>
+            if selection.#field_name.is_selected() {
+                selected.#field_name = sqlx::Row::try_get(row, i).ok().into();
+                i += 1;
+            }
+        }
+    }
+
     fn of_selected_bits_row_i(field: Field) -> TokenStream {
         let field_name = field.ident.unwrap();
         let span = field_name.span();
@@ -26,6 +26,20 @@ pub trait StructConstructor: FieldsContainer + StructFieldConstructor {
         }
     }
 
+    // field_name: Assign<T, Location>, Assign::copy_from only accepts columns of the location
+    fn of_assign(&self, struct_name: &str, location_name: &str) -> TokenStream {
+        let struct_ident = Ident::new(struct_name, Span::call_site());
+        let location_ident = Ident::new(location_name, Span::call_site());
+        let fields_tokens =
+            self.map_field_vec(&|field| <Self as StructFieldConstructor>::get_assign_field(field, &location_ident));
+        quote! {
+            #[derive(Default, Debug, Clone)]
+            pub struct #struct_ident {
+                #(#fields_tokens,)*
+            }
+        }
+    }
+
     // field_name: Option<LocationExpr<T>>
     fn of_location(&self, struct_name: &str) -> TokenStream {
         let struct_ident = Ident::new(&struct_name, Span::call_site());
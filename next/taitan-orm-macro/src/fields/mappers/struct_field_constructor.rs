@@ -1,6 +1,7 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use syn::Field;
 use quote::quote;
+use crate::attrs::{AttrParser, DefaultAttrParser};
 use crate::types::{DefaultTypeChecker, DefaultTypeExtractor, TypeChecker, TypeExtractor};
 
 pub trait StructFieldConstructor {
@@ -40,6 +41,29 @@ pub trait StructFieldConstructor {
         }
     }
 
+    // field_name: Assign<T, Location>, used by mutation, #[version] field is the expected version: Option<T>
+    fn get_assign_field(field: Field, location_ident: &Ident) -> TokenStream {
+        let is_version = DefaultAttrParser::check_has_attr(&field.attrs, "version");
+        let field_ident = field.ident;
+        let field_ty = field.ty;
+        if is_version {
+            let version_type = DefaultTypeExtractor::get_option_inner_type(&field_ty).unwrap_or(&field_ty);
+            return quote! {
+                pub #field_ident: Option<#version_type>
+            };
+        }
+        if DefaultTypeChecker::type_is_option(&field_ty) {
+            let inner_type = DefaultTypeExtractor::get_option_inner_type(&field_ty).unwrap();
+            quote! {
+                pub #field_ident: taitan_orm::Assign<#inner_type, #location_ident>
+            }
+        } else {
+            quote! {
+                pub #field_ident: taitan_orm::Assign<#field_ty, #location_ident>
+            }
+        }
+    }
+
     // field_name: Option<LocationExpr<T>>
     fn get_location_field(field: Field) -> TokenStream {
         let field_ident = field.ident;
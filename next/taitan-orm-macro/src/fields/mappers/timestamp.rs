@@ -0,0 +1,42 @@
+use crate::attrs::{AttrParser, DefaultAttrParser};
+use crate::types::{DefaultTypeChecker, DefaultTypeExtractor, TypeChecker, TypeExtractor};
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+use syn::Field;
+
+// #[created_at]/#[updated_at] fields of entity are written with the current time when they are not given
+pub fn is_auto_timestamp(field: &Field) -> bool {
+    DefaultAttrParser::check_has_attr(&field.attrs, "created_at")
+        || DefaultAttrParser::check_has_attr(&field.attrs, "updated_at")
+}
+
+// #[updated_at] field of mutation is written with the current time unless it is assigned explicitly
+pub fn is_updated_at(field: &Field) -> bool {
+    DefaultAttrParser::check_has_attr(&field.attrs, "updated_at")
+}
+
+// <OffsetDateTime as AutoTimestamp>::now(), Optional<T> is written as T
+pub fn of_timestamp_now(field: &Field) -> TokenStream {
+    let field_type = DefaultTypeExtractor::get_option_inner_type(&field.ty).unwrap_or(&field.ty);
+    quote_spanned! { field.span() =>
+        <#field_type as taitan_orm::traits::AutoTimestamp>::now()
+    }
+}
+
+// entity.created_at = Optional::Some(now) when it is None/Null, so the entity holds the written time, and binds it
+pub fn of_timestamp_fill(field: &Field) -> Option<TokenStream> {
+    if !is_auto_timestamp(field) || !DefaultTypeChecker::type_is_option(&field.ty) {
+        return None;
+    }
+    let field_type = DefaultTypeExtractor::get_option_inner_type(&field.ty).unwrap_or(&field.ty);
+    let now = quote_spanned! { field.span() =>
+        <#field_type as taitan_orm::traits::AutoTimestamp>::at(now)
+    };
+    let field_name = field.ident.as_ref().unwrap();
+    Some(quote_spanned! { field.span() =>
+        if !matches!(self.#field_name, taitan_orm::Optional::Some(_)) {
+            self.#field_name = taitan_orm::Optional::Some(#now);
+        }
+    })
+}
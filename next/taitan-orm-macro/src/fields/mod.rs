@@ -25,6 +25,9 @@ pub use parser::FieldsParser;
 
 pub use mappers::RowConstructor;
 pub use mappers::RowGetConstructor;
+pub use mappers::CursorConstructor;
+pub use mappers::ArgsAddConstructor;
+pub use mappers::ColumnConstructor;
 
 pub use mappers::NamesConstructor;
 pub use mappers::NamesAddConstructor;
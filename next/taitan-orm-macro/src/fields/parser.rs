@@ -2,7 +2,7 @@ use crate::attrs::{AttrParser, DefaultAttrParser};
 use crate::fields::fields_filter::FieldsFilter;
 use crate::fields::mappers::{
     ArgsAddConstructor, ArgsConstructorMySql, ArgsConstructorPostgres, ArgsConstructorSqlite,
-    NamesAddConstructor, NamesConstructor, RowConstructor, RowGetConstructor, StructConstructor,
+    NamesAddConstructor, NamesConstructor, RowConstructor, CursorConstructor, ColumnConstructor, RowGetConstructor, StructConstructor,
     StructFieldConstructor,
 };
 use crate::fields::{DefaultFieldMapper, FieldMapType, FieldMapper, LocationParser, UniqueParser};
@@ -82,6 +82,10 @@ impl RowGetConstructor for FieldsParser {}
 
 impl RowConstructor for FieldsParser {}
 
+impl CursorConstructor for FieldsParser {}
+
+impl ColumnConstructor for FieldsParser {}
+
 impl FieldsParser {
     pub fn map_with<F>(self, map_fn: &F) -> Vec<TokenStream>
     where
@@ -328,7 +332,9 @@ impl FieldsParser {
                 let span = field_name.span();
                 quote_spanned! { span =>
                     if let Some(#field_name) = &self.#field_name {
-                        luna_add_arg(&mut arguments, &#field_name.val);
+                        for value in #field_name.val.as_vec() {
+                            luna_add_arg(&mut arguments, value);
+                        }
                     }
                 }
             });
@@ -351,6 +357,19 @@ impl FieldsParser {
         }
     }
 
+    pub fn get_qualified_where_clause(&self) -> TokenStream {
+        let where_clause_members = <DefaultFieldMapper as FieldMapper>::map_field_vec(
+            &self.fields,
+            &<DefaultFieldMapper as FieldMapper>::map_to_qualified_where_field,
+        );
+        quote! {
+            let table_name = taitan_orm::traits::Location::get_table_name(self);
+            let mut sql = String::default();
+            #(#where_clause_members )*
+            return sql;
+        }
+    }
+
     pub fn get_unique_index_check(&self, unique_indexes: &Vec<Vec<String>>) -> TokenStream {
         if unique_indexes.is_empty() {
             quote!(fields.len() == 0)
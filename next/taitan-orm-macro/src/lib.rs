@@ -12,13 +12,28 @@ mod template;
 
 #[proc_macro_derive(
     Schema,
-    attributes(table_name, primary_key, unique_key, auto_increment, generated, field_name)
+    attributes(
+        table_name,
+        primary_key,
+        unique_key,
+        auto_increment,
+        generated,
+        field_name,
+        version,
+        soft_delete,
+        created_at,
+        updated_at,
+        has_many,
+        has_one,
+        belongs_to,
+        hooks
+    )
 )]
 pub fn expand_schema_macro(input: TokenStream) -> TokenStream {
     impl_schema_macro(input)
 }
 
-#[proc_macro_derive(TemplateRecord, attributes(sql, count_sql, limit_field))]
+#[proc_macro_derive(TemplateRecord, attributes(sql, count_sql, sql_file, count_sql_file, limit_field, raw, output, backslash_escape))]
 pub fn expand_template_record(input: TokenStream) -> TokenStream {
     impl_template_macro(input)
 }
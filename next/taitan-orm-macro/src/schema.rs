@@ -1,4 +1,4 @@
-use crate::expands::{generate_entity_impl, generate_location_struct_and_impl, generate_mutation_struct_and_impl, generate_ordering_struct_and_impl, generate_selected_struct_and_impl, generate_selection_struct_and_impl, generate_unique_structs_and_impls};
+use crate::expands::{generate_entity_impl, generate_location_struct_and_impl, generate_mutation_struct_and_impl, generate_ordering_struct_and_impl, generate_selected_struct_and_impl, generate_relation_impl, generate_selection_struct_and_impl, generate_unique_structs_and_impls};
 use crate::util::extract_fields;
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
@@ -17,6 +17,7 @@ pub fn impl_schema_macro(input: TokenStream) -> TokenStream {
     let selection_struct_stream = generate_selection_struct_and_impl(&ident, &attrs, &fields);
     let selected_struct_stream = generate_selected_struct_and_impl(&ident, &attrs, &fields);
     let ordering_struct_stream = generate_ordering_struct_and_impl(&ident, &attrs, &fields);
+    let relation_stream = generate_relation_impl(&ident, &attrs, &fields);
 
     output.extend(primary_struct_stream);
     output.extend(location_struct_stream);
@@ -24,6 +25,7 @@ pub fn impl_schema_macro(input: TokenStream) -> TokenStream {
     output.extend(selection_struct_stream);
     output.extend(selected_struct_stream);
     output.extend(ordering_struct_stream);
+    output.extend(relation_stream);
     // panic!("{}", output);
     output.into()
 }
@@ -1,4 +1,4 @@
-use syn::{Field, Type};
+use syn::{Field, GenericArgument, PathArguments, Type};
 
 pub trait TypeChecker {
     fn type_has_prefix(ty: &Type, name: &str) -> bool;
@@ -7,6 +7,10 @@ pub trait TypeChecker {
 
     fn type_is_option(ty: &Type) -> bool;
 
+    fn type_is_list(ty: &Type) -> bool;
+
+    fn type_is_scalar(ty: &Type) -> bool;
+
     fn get_field_type_name(field: &Field) -> String;
 
     fn field_is_option(field: &Field) -> bool;
@@ -54,6 +58,52 @@ impl TypeChecker for DefaultTypeChecker {
         )
     }
 
+    // Vec<T>, &Vec<T> or &[T], bytes such as Vec<u8> are a single value instead of a list
+    fn type_is_list(ty: &Type) -> bool {
+        match ty {
+            Type::Reference(type_ref) => match type_ref.elem.as_ref() {
+                Type::Slice(type_slice) => !is_u8_type(&type_slice.elem),
+                elem => <DefaultTypeChecker as TypeChecker>::type_is_list(elem),
+            },
+            Type::Path(type_path) => {
+                if !<DefaultTypeChecker as TypeChecker>::type_has_one_of_names(
+                    ty,
+                    &["Vec::", "std::vec::Vec::", "alloc::vec::Vec::"],
+                ) {
+                    return false;
+                }
+                match &type_path.path.segments.last().unwrap().arguments {
+                    PathArguments::AngleBracketed(args) => match args.args.first() {
+                        Some(GenericArgument::Type(elem)) => !is_u8_type(elem),
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    // a single column value, such as i64, String, Option<BigDecimal>, instead of a SelectedEntity
+    fn type_is_scalar(ty: &Type) -> bool {
+        let Type::Path(type_path) = ty else {
+            return false;
+        };
+        let last = type_path.path.segments.last().unwrap();
+        let inner = match &last.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(GenericArgument::Type(inner)) => Some(inner),
+                _ => None,
+            },
+            _ => None,
+        };
+        match last.ident.to_string().as_str() {
+            "Option" => inner.is_some_and(<DefaultTypeChecker as TypeChecker>::type_is_scalar),
+            "Vec" => inner.is_some_and(is_u8_type),
+            name => SCALAR_TYPE_NAMES.contains(&name),
+        }
+    }
+
     fn get_field_type_name(field: &Field) -> String {
         let ty = &field.ty;
         match ty {
@@ -83,3 +133,13 @@ impl TypeChecker for DefaultTypeChecker {
         <DefaultTypeChecker as TypeChecker>::type_has_prefix(ty, type_name)
     }
 }
+
+const SCALAR_TYPE_NAMES: [&str; 26] = [
+    "bool", "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "f32", "f64", "String",
+    "BigDecimal", "Decimal", "Uuid", "OffsetDateTime", "PrimitiveDateTime", "Date", "Time",
+    "NaiveDate", "NaiveTime", "NaiveDateTime", "DateTime", "Json", "JsonValue", "Value",
+];
+
+fn is_u8_type(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("u8"))
+}
@@ -3,8 +3,16 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericParam, Generics, Lifetime};
 use crate::util::extract_generic_lifetimes;
+use taitan_orm_trait::ParsedTemplateSql;
 
-pub fn copy_to_template_struct(struct_ident: &Ident, data: &Data, generics: &Generics, sql: &str, struct_suffix: &str) -> TokenStream {
+pub fn copy_to_template_struct(
+    struct_ident: &Ident,
+    data: &Data,
+    generics: &Generics,
+    sql: &str,
+    struct_suffix: &str,
+    with_raw_idents: bool,
+) -> TokenStream {
     let mut lifetimes: Vec<Lifetime> = extract_generic_lifetimes(generics);
     let struct_name = struct_ident;
     let template_struct_ident = format_ident!("{}{}", struct_name, struct_suffix);
@@ -22,7 +30,7 @@ pub fn copy_to_template_struct(struct_ident: &Ident, data: &Data, generics: &Gen
 
 
     // 生成字段定义
-    let field_defs: Vec<_> = fields
+    let mut field_defs: Vec<_> = fields
         .iter()
         .map(|f| {
             let field_name = &f.ident;
@@ -32,7 +40,7 @@ pub fn copy_to_template_struct(struct_ident: &Ident, data: &Data, generics: &Gen
         .collect();
 
     // 生成字段初始化
-    let field_inits: Vec<_> = fields
+    let mut field_inits: Vec<_> = fields
         .iter()
         .map(|f| {
             let field_name = f.ident.as_ref().unwrap();
@@ -40,8 +48,17 @@ pub fn copy_to_template_struct(struct_ident: &Ident, data: &Data, generics: &Gen
         })
         .collect();
 
+    // validated and quoted ${} values, filled before rendering
+    if with_raw_idents {
+        let raw_idents = format_ident!("{}", ParsedTemplateSql::RAW_IDENTS);
+        field_defs.push(quote! { #raw_idents: Vec<String> });
+        field_inits.push(quote! { #raw_idents: Vec::new() });
+    }
+
     if lifetimes.is_empty() {
         quote! {
+            // every field is copied, the template reads only those it renders
+            #[allow(dead_code)]
             #[derive(Clone, rinja::Template)]
             #[template(source = #sql, ext="txt")]
             pub struct #template_struct_ident {
@@ -58,6 +75,8 @@ pub fn copy_to_template_struct(struct_ident: &Ident, data: &Data, generics: &Gen
         }
     } else {
         quote! {
+            // every field is copied, the template reads only those it renders
+            #[allow(dead_code)]
             #[derive(Clone, rinja::Template)]
             #[template(source = #sql, ext="txt")]
             pub struct #template_struct_ident <#(#lifetimes),*> {
@@ -2,6 +2,8 @@ mod parser;
 mod utils;
 mod life_time_checker;
 mod copy_struct;
+mod template_args;
+mod template_file;
 
 pub use utils::extract_fields;
 pub use utils::create_path_from_str;
@@ -10,4 +12,6 @@ pub use life_time_checker::check_type_lifetime;
 pub use life_time_checker::extract_generic_lifetimes;
 pub use life_time_checker::build_struct_ident;
 pub use life_time_checker::build_impl_trait_token;
-pub use copy_struct::copy_to_template_struct;
\ No newline at end of file
+pub use copy_struct::copy_to_template_struct;
+pub use template_args::gen_template_args_add;
+pub use template_file::TemplateSource;
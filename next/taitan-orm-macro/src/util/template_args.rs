@@ -0,0 +1,159 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::Parser;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use syn::{parse_quote, Expr, ExprIf, Pat, PatIdent};
+use taitan_orm_trait::TemplateArgument;
+
+/**
+generate argument binding code with the same control blocks as the template sql,
+conditions and loops are compiled the same way as rinja does:
+1. bare field name in expression is self.field, unless it is a local of for/if let
+2. for loop borrows field before into_iter(), so loop variable is a reference into self
+3. blocks without bound variable inside are skipped
+4. list field, such as ids: Vec<i64>, binds every element, unless it is shadowed by a local
+*/
+pub fn gen_template_args_add(arguments: &[TemplateArgument], field_names: &[String], list_fields: &[String]) -> TokenStream {
+    let generator = TemplateArgsGenerator { field_names, list_fields };
+    generator.gen_arguments(arguments, &[])
+}
+
+struct TemplateArgsGenerator<'a> {
+    field_names: &'a [String],
+    list_fields: &'a [String],
+}
+
+impl TemplateArgsGenerator<'_> {
+    fn gen_arguments(&self, arguments: &[TemplateArgument], locals: &[String]) -> TokenStream {
+        let streams = arguments
+            .iter()
+            .filter(|argument| argument.has_variable())
+            .map(|argument| match argument {
+                TemplateArgument::Variable(variable) => self.gen_variable(variable, locals),
+                TemplateArgument::If { branches, otherwise } => self.gen_if(branches, otherwise, locals),
+                TemplateArgument::For { target, iter, body } => self.gen_for(target, iter, body, locals),
+            });
+        quote! { #(#streams)* }
+    }
+
+    fn gen_variable(&self, variable: &str, locals: &[String]) -> TokenStream {
+        let segs: Vec<&str> = variable.split('.').collect();
+        let idents = segs.iter().map(|seg| format_ident!("{}", seg)).collect::<Vec<_>>();
+        let first = &idents[0];
+        let rest = &idents[1..];
+        if locals.iter().any(|local| local == segs[0]) {
+            if rest.is_empty() {
+                quote! { sqlx::Arguments::add(&mut args, #first)?; }
+            } else {
+                quote! { sqlx::Arguments::add(&mut args, &#first #(.#rest)*)?; }
+            }
+        } else if self.list_fields.iter().any(|field| field == variable) {
+            quote! {
+                for value in self.#first.iter() {
+                    sqlx::Arguments::add(&mut args, value)?;
+                }
+            }
+        } else {
+            quote! { sqlx::Arguments::add(&mut args, &self.#first #(.#rest)*)?; }
+        }
+    }
+
+    fn gen_if(
+        &self,
+        branches: &[(String, Vec<TemplateArgument>)],
+        otherwise: &[TemplateArgument],
+        locals: &[String],
+    ) -> TokenStream {
+        let branch_streams = branches.iter().map(|(cond, body)| {
+            let expr_if: ExprIf = syn::parse_str(&format!("if {} {{}}", cond))
+                .unwrap_or_else(|err| panic!("invalid condition in sql template: {}, {}", cond, err));
+            match *expr_if.cond {
+                Expr::Let(expr_let) => {
+                    let pat = expr_let.pat;
+                    let expr = self.rewrite_expr(*expr_let.expr, locals);
+                    let mut branch_locals = locals.to_vec();
+                    branch_locals.extend(collect_pat_idents(&pat));
+                    let body_stream = self.gen_arguments(body, &branch_locals);
+                    quote! { if let #pat = &(#expr) { #body_stream } }
+                }
+                cond => {
+                    let expr = self.rewrite_expr(cond, locals);
+                    let body_stream = self.gen_arguments(body, locals);
+                    quote! { if #expr { #body_stream } }
+                }
+            }
+        });
+        let otherwise_stream = self.gen_arguments(otherwise, locals);
+        quote! {
+            #(#branch_streams)else* else { #otherwise_stream }
+        }
+    }
+
+    fn gen_for(&self, target: &str, iter: &str, body: &[TemplateArgument], locals: &[String]) -> TokenStream {
+        let pat = Pat::parse_single
+            .parse_str(target)
+            .unwrap_or_else(|err| panic!("invalid loop variable in sql template: {}, {}", target, err));
+        let iter_expr: Expr = syn::parse_str(iter)
+            .unwrap_or_else(|err| panic!("invalid loop expression in sql template: {}, {}", iter, err));
+        let iter_expr = self.rewrite_expr(iter_expr, locals);
+        let iter_stream = match &iter_expr {
+            Expr::Range(_) => quote! { #iter_expr },
+            Expr::Array(_) => quote! { #iter_expr.iter() },
+            Expr::Call(_) | Expr::MethodCall(_) | Expr::Index(_) => quote! { (#iter_expr).into_iter() },
+            Expr::Field(_) => quote! { (&#iter_expr).into_iter() },
+            _ => quote! { (#iter_expr).into_iter() },
+        };
+        let mut body_locals = locals.to_vec();
+        body_locals.extend(collect_pat_idents(&pat));
+        let body_stream = self.gen_arguments(body, &body_locals);
+        quote! {
+            for #pat in #iter_stream { #body_stream }
+        }
+    }
+
+    fn rewrite_expr(&self, mut expr: Expr, locals: &[String]) -> Expr {
+        let mut rewriter = FieldRewriter {
+            field_names: self.field_names,
+            locals,
+        };
+        rewriter.visit_expr_mut(&mut expr);
+        expr
+    }
+}
+
+struct FieldRewriter<'a> {
+    field_names: &'a [String],
+    locals: &'a [String],
+}
+
+impl VisitMut for FieldRewriter<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Path(expr_path) = expr {
+            if expr_path.qself.is_none() {
+                if let Some(ident) = expr_path.path.get_ident() {
+                    let name = ident.to_string();
+                    if self.field_names.contains(&name) && !self.locals.contains(&name) {
+                        let ident = ident.clone();
+                        *expr = parse_quote! { self.#ident };
+                        return;
+                    }
+                }
+            }
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+fn collect_pat_idents(pat: &Pat) -> Vec<String> {
+    struct PatIdentCollector(Vec<String>);
+    impl<'ast> Visit<'ast> for PatIdentCollector {
+        fn visit_pat_ident(&mut self, pat_ident: &'ast PatIdent) {
+            self.0.push(pat_ident.ident.to_string());
+            syn::visit::visit_pat_ident(self, pat_ident);
+        }
+    }
+    let mut collector = PatIdentCollector(Vec::new());
+    collector.visit_pat(pat);
+    collector.0
+}
@@ -0,0 +1,55 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::PathBuf;
+use taitan_orm_trait::{resolve_template_includes, NotValidTemplateError, StringEscape};
+
+/**
+template sql from #[sql = "..."] or #[sql_file = "sql/report.sql"], with {% include "..." %} resolved.
+files are resolved relative to CARGO_MANIFEST_DIR of the crate deriving TemplateRecord,
+and every file read is tracked by include_str!, so editing the file rebuilds the template
+*/
+pub struct TemplateSource {
+    pub sql: String,
+    pub files: Vec<String>,
+}
+
+impl TemplateSource {
+    pub fn load(
+        inline_sql: Option<String>,
+        sql_file: Option<String>,
+        attr_name: &str,
+        escape: StringEscape,
+    ) -> Option<Self> {
+        let mut files: Vec<String> = Vec::new();
+        let sql = match (inline_sql, sql_file) {
+            (Some(_), Some(_)) => panic!("#[{0}] and #[{0}_file] can not be used at the same time", attr_name),
+            (Some(sql), None) => sql,
+            (None, Some(sql_file)) => Self::read_file(&sql_file, &mut files)
+                .unwrap_or_else(|err| panic!("Failed to load #[{}_file]: {}", attr_name, err)),
+            (None, None) => return None,
+        };
+        let sql = resolve_template_includes(&sql, escape, &mut |path| Self::read_file(path, &mut files))
+            .unwrap_or_else(|err| panic!("Failed to resolve includes of {}: {}", attr_name, err));
+        Some(Self { sql, files })
+    }
+
+    fn read_file(path: &str, files: &mut Vec<String>) -> Result<String, NotValidTemplateError> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+            .map_err(|_| NotValidTemplateError("CARGO_MANIFEST_DIR is not set".to_string()))?;
+        let full_path = PathBuf::from(manifest_dir).join(path);
+        let content = std::fs::read_to_string(&full_path)
+            .map_err(|err| NotValidTemplateError(format!("{}: {}", full_path.display(), err)))?;
+        let full_path = full_path.to_string_lossy().to_string();
+        if !files.contains(&full_path) {
+            files.push(full_path);
+        }
+        Ok(content)
+    }
+
+    pub fn gen_track_files_stream(&self) -> TokenStream {
+        let files = &self.files;
+        quote! {
+            #(const _: &str = include_str!(#files);)*
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::location::Having;
+use crate::{CmpOperator, LocationExpr};
+use sqlx::types::BigDecimal;
+
+/**
+aggregate function over one column of a table, filtered by a location
+1. SUM/AVG are casted by the dialect, so the value is decoded as Aggregated::Sum/Avg
+2. MIN/MAX keep the type of the column
+3. COUNT/COUNT(DISTINCT) is decoded as the count type of the database
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AggregateFunction {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+    CountDistinct,
+}
+
+/**
+numeric kind of SUM/AVG result, each dialect maps it to a cast type,
+sqlite is dynamically typed and needs no cast, BigDecimal is not supported by sqlite
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NumericKind {
+    Integer,
+    Float,
+    Decimal,
+}
+
+impl AggregateFunction {
+    pub fn get_sql(&self) -> &'static str {
+        match self {
+            AggregateFunction::Count => "COUNT",
+            AggregateFunction::Sum => "SUM",
+            AggregateFunction::Avg => "AVG",
+            AggregateFunction::Min => "MIN",
+            AggregateFunction::Max => "MAX",
+            AggregateFunction::CountDistinct => "COUNT",
+        }
+    }
+
+    // SUM(`amount`), COUNT(DISTINCT `user_id`), CAST(SUM(`qty`) AS BIGINT)
+    pub fn get_expr(&self, field_name: &str, wrap_char: char, cast_type: Option<&str>) -> String {
+        let distinct = match self {
+            AggregateFunction::CountDistinct => "DISTINCT ",
+            _ => "",
+        };
+        let expr = format!("{}({}{}{}{})", self.get_sql(), distinct, wrap_char, field_name, wrap_char);
+        match cast_type {
+            Some(cast_type) => format!("CAST({} AS {})", expr, cast_type),
+            None => expr,
+        }
+    }
+}
+
+/**
+aggregate expression in the select list of grouped search, built from typed column handles
+1. AggregateExpr::count_all() -> COUNT(*) AS `count`
+2. OrderColumns::amount().sum() -> SUM(`amount`) AS `sum_amount`, alias can be changed by alias("total")
+3. OrderColumns::amount().sum().gt(100) -> HAVING SUM(`amount`)>?
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AggregateExpr {
+    function: AggregateFunction,
+    field_name: Option<&'static str>,
+    kind: Option<NumericKind>,
+    alias: Option<&'static str>,
+}
+
+impl AggregateExpr {
+    pub const fn new(function: AggregateFunction, field_name: &'static str, kind: Option<NumericKind>) -> Self {
+        Self { function, field_name: Some(field_name), kind, alias: None }
+    }
+
+    pub const fn count_all() -> Self {
+        Self { function: AggregateFunction::Count, field_name: None, kind: None, alias: None }
+    }
+
+    pub const fn alias(self, alias: &'static str) -> Self {
+        Self { alias: Some(alias), ..self }
+    }
+
+    pub fn get_function(&self) -> AggregateFunction {
+        self.function
+    }
+
+    pub fn get_kind(&self) -> Option<NumericKind> {
+        self.kind
+    }
+
+    // count, sum_amount, count_distinct_user_id if no alias is given
+    pub fn get_alias(&self) -> String {
+        if let Some(alias) = self.alias {
+            return alias.to_string();
+        }
+        let function_name = match self.function {
+            AggregateFunction::CountDistinct => "count_distinct".to_string(),
+            function => function.get_sql().to_lowercase(),
+        };
+        match self.field_name {
+            Some(field_name) => format!("{}_{}", function_name, field_name),
+            None => function_name,
+        }
+    }
+
+    pub fn get_expr(&self, wrap_char: char, cast_type: Option<&str>) -> String {
+        match self.field_name {
+            Some(field_name) => self.function.get_expr(field_name, wrap_char, cast_type),
+            None => format!("{}(*)", self.function.get_sql()),
+        }
+    }
+
+    pub fn eq<T>(self, value: T) -> Having<T> {
+        Having::new(self, LocationExpr::new(CmpOperator::Eq, value))
+    }
+
+    pub fn ne<T>(self, value: T) -> Having<T> {
+        Having::new(self, LocationExpr::new(CmpOperator::NotEq, value))
+    }
+
+    pub fn lt<T>(self, value: T) -> Having<T> {
+        Having::new(self, LocationExpr::new(CmpOperator::LessThan, value))
+    }
+
+    pub fn le<T>(self, value: T) -> Having<T> {
+        Having::new(self, LocationExpr::new(CmpOperator::LessOrEq, value))
+    }
+
+    pub fn gt<T>(self, value: T) -> Having<T> {
+        Having::new(self, LocationExpr::new(CmpOperator::GreaterThan, value))
+    }
+
+    pub fn ge<T>(self, value: T) -> Having<T> {
+        Having::new(self, LocationExpr::new(CmpOperator::GreaterOrEq, value))
+    }
+
+    pub fn between<T>(self, start: T, end: T) -> Having<T> {
+        Having::new(self, LocationExpr::between(start, end))
+    }
+}
+
+/**
+rust type of SUM/AVG over a column of Self,
+integer sums are widened to i64, averages of integers are f64, decimal columns stay BigDecimal,
+u64 is summed as i64 too, as sqlite stores it, a sum beyond i64::MAX is a decode error
+*/
+pub trait Aggregated {
+    type Sum;
+    type Avg;
+    const SUM_KIND: NumericKind;
+    const AVG_KIND: NumericKind;
+}
+
+macro_rules! impl_aggregated {
+    ($sum:ty, $sum_kind:expr, $avg:ty, $avg_kind:expr, $($t:ty),+) => {
+        $(
+            impl Aggregated for $t {
+                type Sum = $sum;
+                type Avg = $avg;
+                const SUM_KIND: NumericKind = $sum_kind;
+                const AVG_KIND: NumericKind = $avg_kind;
+            }
+        )+
+    };
+}
+
+impl_aggregated!(i64, NumericKind::Integer, f64, NumericKind::Float, i8, i16, i32, i64, u8, u16, u32, u64);
+impl_aggregated!(f64, NumericKind::Float, f64, NumericKind::Float, f32, f64);
+impl_aggregated!(BigDecimal, NumericKind::Decimal, BigDecimal, NumericKind::Decimal, BigDecimal);
+
+#[cfg(test)]
+mod test {
+    use crate::aggregate::{AggregateExpr, AggregateFunction, Aggregated, NumericKind};
+    use crate::{Column, FieldName, Location};
+    use sqlx::types::BigDecimal;
+
+    #[derive(Debug)]
+    struct OrderLocation;
+
+    impl Location for OrderLocation {
+        fn get_table_name(&self) -> &'static str {
+            "order"
+        }
+
+        fn get_location_fields_name(&self) -> Vec<FieldName> {
+            Vec::new()
+        }
+
+        fn get_where_clause(&self, _wrap_char: char, _place_holder: char) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    pub fn test_aggregate_expr() {
+        assert_eq!(AggregateFunction::Max.get_expr("age", '`', None), "MAX(`age`)");
+        assert_eq!(
+            AggregateFunction::CountDistinct.get_expr("user_id", '"', None),
+            "COUNT(DISTINCT \"user_id\")"
+        );
+        assert_eq!(
+            AggregateFunction::Sum.get_expr("qty", '`', Some("SIGNED")),
+            "CAST(SUM(`qty`) AS SIGNED)"
+        );
+        assert_eq!(<i32 as Aggregated>::SUM_KIND, NumericKind::Integer);
+        assert_eq!(<i32 as Aggregated>::AVG_KIND, NumericKind::Float);
+        assert_eq!(<BigDecimal as Aggregated>::SUM_KIND, NumericKind::Decimal);
+
+        let count = AggregateExpr::count_all();
+        assert_eq!(count.get_expr('`', None), "COUNT(*)");
+        assert_eq!(count.get_alias(), "count");
+        let distinct = AggregateExpr::new(AggregateFunction::CountDistinct, "user_id", None);
+        assert_eq!(distinct.get_alias(), "count_distinct_user_id");
+        let sum = AggregateExpr::new(AggregateFunction::Sum, "qty", Some(NumericKind::Integer));
+        assert_eq!(sum.get_alias(), "sum_qty");
+        assert_eq!(sum.alias("total").get_alias(), "total");
+        assert_eq!(sum.get_expr('"', Some("BIGINT")), "CAST(SUM(\"qty\") AS BIGINT)");
+    }
+    #[test]
+    pub fn test_aggregated_kind() {
+        // decimal is summed without cast and decoded as BigDecimal
+        let price: Column<OrderLocation, BigDecimal> = Column::new("price", |_| OrderLocation);
+        let sum = price.sum();
+        assert_eq!(sum.get_kind(), Some(NumericKind::Decimal));
+        assert_eq!(sum.get_expr('"', None), "SUM(\"price\")");
+        assert_eq!(price.avg().get_kind(), Some(NumericKind::Decimal));
+        let total: <BigDecimal as Aggregated>::Sum = "12.50".parse().unwrap();
+        assert_eq!(total, BigDecimal::from(25) / BigDecimal::from(2));
+
+        let views: Column<OrderLocation, u64> = Column::new("views", |_| OrderLocation);
+        assert_eq!(views.sum().get_kind(), Some(NumericKind::Integer));
+        assert_eq!(views.avg().get_kind(), Some(NumericKind::Float));
+        let total: <u64 as Aggregated>::Sum = i64::MAX;
+        assert_eq!(total, i64::MAX);
+    }
+}
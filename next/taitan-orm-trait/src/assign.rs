@@ -0,0 +1,189 @@
+use crate::{Column, Location, Optional};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+/**
+value of a field in generated Mutation, rendered in the SET clause of update/change
+1. None: field is not updated
+2. Null: `age` = NULL
+3. Set(v): `age` = ?
+4. Incr(v)/Decr(v): `views` = `views` + ?, evaluated by the database, so concurrent updates are not lost
+5. Copy(source): `age` = `init_age`, built by Assign::copy_from(UserColumns::init_age()) only
+
+L is the location of the mutated table in generated Mutation, so copy_from checks both the type and the table of column
+*/
+#[derive(Default)]
+pub enum Assign<T, L = ()> {
+    #[default]
+    None,
+    Null,
+    Set(T),
+    Incr(T),
+    Decr(T),
+    Copy(CopySource<L>),
+}
+
+/**
+source column of Assign::Copy, it can not be built from a raw name
+*/
+pub struct CopySource<L> {
+    name: &'static str,
+    table: PhantomData<fn() -> L>,
+}
+
+impl<L> CopySource<L> {
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<L> Clone for CopySource<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L> Copy for CopySource<L> {}
+
+impl<L> Debug for CopySource<L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CopySource").field(&self.name).finish()
+    }
+}
+
+impl<L> PartialEq for CopySource<L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl<L> Eq for CopySource<L> {}
+
+// implemented by hand, as derive would require L to implement them too
+impl<T: Clone, L> Clone for Assign<T, L> {
+    fn clone(&self) -> Self {
+        match self {
+            Assign::None => Assign::None,
+            Assign::Null => Assign::Null,
+            Assign::Set(value) => Assign::Set(value.clone()),
+            Assign::Incr(value) => Assign::Incr(value.clone()),
+            Assign::Decr(value) => Assign::Decr(value.clone()),
+            Assign::Copy(source) => Assign::Copy(*source),
+        }
+    }
+}
+
+impl<T: Debug, L> Debug for Assign<T, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Assign::None => f.write_str("None"),
+            Assign::Null => f.write_str("Null"),
+            Assign::Set(value) => f.debug_tuple("Set").field(value).finish(),
+            Assign::Incr(value) => f.debug_tuple("Incr").field(value).finish(),
+            Assign::Decr(value) => f.debug_tuple("Decr").field(value).finish(),
+            Assign::Copy(source) => f.debug_tuple("Copy").field(source).finish(),
+        }
+    }
+}
+
+impl<T: PartialEq, L> PartialEq for Assign<T, L> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Assign::None, Assign::None) | (Assign::Null, Assign::Null) => true,
+            (Assign::Set(a), Assign::Set(b)) | (Assign::Incr(a), Assign::Incr(b)) | (Assign::Decr(a), Assign::Decr(b)) => {
+                a == b
+            }
+            (Assign::Copy(a), Assign::Copy(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Eq, L> Eq for Assign<T, L> {}
+
+impl<T, L> From<Optional<T>> for Assign<T, L> {
+    fn from(optional: Optional<T>) -> Self {
+        match optional {
+            Optional::Some(value) => Assign::Set(value),
+            Optional::Null => Assign::Null,
+            Optional::None | Optional::Selected => Assign::None,
+        }
+    }
+}
+
+impl<T, L: Location> Assign<T, L> {
+    pub fn copy_from(column: Column<L, T>) -> Self {
+        Assign::Copy(CopySource { name: column.get_name(), table: PhantomData })
+    }
+}
+
+impl<T, L> Assign<T, L> {
+
+    pub fn is_none(&self) -> bool {
+        matches!(self, Assign::None)
+    }
+
+    // value bound to the placeholder of set sql
+    pub fn get_value(&self) -> Option<&T> {
+        match self {
+            Assign::Set(value) | Assign::Incr(value) | Assign::Decr(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_set_sql(&self, field_name: &str, wrap_char: char, place_holder: char) -> Option<String> {
+        let field = format!("{}{}{}", wrap_char, field_name, wrap_char);
+        match self {
+            Assign::None => None,
+            Assign::Null => Some(format!("{} = NULL", field)),
+            Assign::Set(_) => Some(format!("{} = {}", field, place_holder)),
+            Assign::Incr(_) => Some(format!("{} = {} + {}", field, field, place_holder)),
+            Assign::Decr(_) => Some(format!("{} = {} - {}", field, field, place_holder)),
+            Assign::Copy(source) => Some(format!("{} = {}{}{}", field, wrap_char, source.get_name(), wrap_char)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Assign, Column, FieldName, Location, LocationExpr, Optional};
+
+    #[derive(Debug, Default)]
+    struct UserLocation;
+
+    impl Location for UserLocation {
+        fn get_table_name(&self) -> &'static str {
+            "user"
+        }
+
+        fn get_location_fields_name(&self) -> Vec<FieldName> {
+            Vec::new()
+        }
+
+        fn get_where_clause(&self, _wrap_char: char, _place_holder: char) -> String {
+            String::new()
+        }
+    }
+
+    fn locate_init_age(_expr: LocationExpr<i32>) -> UserLocation {
+        UserLocation
+    }
+
+    #[test]
+    pub fn test_assign() {
+        assert_eq!(Assign::<i32>::None.get_set_sql("age", '`', '?'), None);
+        assert_eq!(Assign::<i32>::Null.get_set_sql("age", '`', '?'), Some("`age` = NULL".to_string()));
+        assert_eq!(Assign::<i32>::Set(1).get_set_sql("age", '`', '?'), Some("`age` = ?".to_string()));
+        assert_eq!(Assign::<i32>::Incr(1).get_set_sql("views", '"', '?'), Some("\"views\" = \"views\" + ?".to_string()));
+        assert_eq!(Assign::<i32>::Decr(1).get_set_sql("views", '`', '?'), Some("`views` = `views` - ?".to_string()));
+        let init_age = Column::new("init_age", locate_init_age);
+        let copy = Assign::copy_from(init_age);
+        assert_eq!(copy.get_set_sql("age", '`', '?'), Some("`age` = `init_age`".to_string()));
+        assert_eq!(copy.get_value(), None);
+        assert_eq!(copy.clone(), Assign::<i32, UserLocation>::copy_from(init_age));
+        assert_eq!(Assign::<i32>::Decr(2).get_value(), Some(&2));
+        assert_eq!(Assign::<i32>::Null.get_value(), None);
+        assert_eq!(Assign::<i32>::from(Optional::Some(3)), Assign::Set(3));
+        assert_eq!(Assign::<i32>::from(Optional::Null), Assign::Null);
+    }
+}
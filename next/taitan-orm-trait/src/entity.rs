@@ -2,9 +2,12 @@ use crate::error::NotImplementError;
 use sqlx::error::BoxDynError;
 use sqlx::mysql::MySqlArguments;
 use sqlx::postgres::PgArguments;
-use sqlx::sqlite::SqliteArguments;
+use sqlx::sqlite::{SqliteArguments, SqliteRow};
+use sqlx::mysql::MySqlRow;
+use sqlx::postgres::PgRow;
 use std::fmt::Debug;
-use crate::FieldName;
+use crate::{FieldName, WriteHooks};
+use time::OffsetDateTime;
 
 pub trait Entity: Sync + Debug {
     fn get_table_name(&self) -> &str;
@@ -15,17 +18,84 @@ pub trait Entity: Sync + Debug {
 
     fn get_auto_increment_field(&self) -> Option<&str>;
 
+    // columns of primary key, conflict target of upsert_all
+    fn get_primary_key_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    // false when there is no auto increment field, or value is out of range of the field type
     fn set_auto_increment_field(&mut self, value: Option<i64>) -> bool;
 
+    // fields annotated with #[generated], filled by database and written back after create
+    fn get_generated_fields(&self) -> Vec<FieldName> {
+        Vec::new()
+    }
+
+    // Optional #[created_at]/#[updated_at] fields which are None/Null are set to now, called by every insert/upsert before binding
+    fn fill_auto_timestamps(&mut self, _now: OffsetDateTime) {}
+
+    // Some(self) with #[hooks(entity)], insert/create/upsert run its before/after_insert
+    fn get_hooks(&self) -> Option<&dyn WriteHooks> {
+        None
+    }
+
+    fn get_hooks_mut(&mut self) -> Option<&mut dyn WriteHooks> {
+        None
+    }
+
+    /**
+    insert/upsert/insert_all/upsert_all borrow the value, before hooks and auto timestamps change this clone which is written instead
+    Some with #[hooks(entity)] or Optional #[created_at]/#[updated_at] fields, both of which require the entity to be Clone
+    */
+    fn clone_for_writes(&self) -> Option<Box<dyn Entity + '_>> {
+        None
+    }
+
+    /**
+    primary key, or the first unique key, whose values are all given by entity,
+    used to re-select generated fields after create when there is neither RETURNING nor auto increment id
+    */
+    fn get_reselect_key_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn gen_reselect_arguments_sqlite(&self) -> Result<SqliteArguments<'_>, BoxDynError> {
+        Err(NotImplementError("gen_reselect_arguments_sqlite".to_string()).into())
+    }
+    fn gen_reselect_arguments_mysql(&self) -> Result<MySqlArguments, BoxDynError> {
+        Err(NotImplementError("gen_reselect_arguments_mysql".to_string()).into())
+    }
+    fn gen_reselect_arguments_postgres(&self) -> Result<PgArguments, BoxDynError> {
+        Err(NotImplementError("gen_reselect_arguments_postgres".to_string()).into())
+    }
+
+    // auto increment field and #[generated] fields in the row, decoded as their own types
+    fn set_generated_fields_sqlite(&mut self, row: &SqliteRow) -> Result<(), sqlx::Error> {
+        Ok(())
+    }
+    fn set_generated_fields_mysql(&mut self, row: &MySqlRow) -> Result<(), sqlx::Error> {
+        Ok(())
+    }
+    fn set_generated_fields_postgres(&mut self, row: &PgRow) -> Result<(), sqlx::Error> {
+        Ok(())
+    }
+
     fn gen_insert_arguments_sqlite(&self) -> Result<SqliteArguments<'_>, BoxDynError> {
         Err(NotImplementError("gen_insert_arguments_sqlite".to_string()).into())
     }
+    // append insert arguments of this row to an existing buffer, used by multi-row insert
+    fn add_insert_arguments_sqlite<'a>(&'a self, args: &mut SqliteArguments<'a>) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_insert_arguments_sqlite".to_string()).into())
+    }
     fn gen_upsert_arguments_sqlite(&self) -> Result<SqliteArguments<'_>, BoxDynError> {
         Err(NotImplementError("gen_upsert_arguments_sqlite".to_string()).into())
     }
     fn gen_insert_arguments_mysql(&self) -> Result<MySqlArguments, BoxDynError> {
         Err(NotImplementError("gen_insert_arguments_mysql".to_string()).into())
     }
+    fn add_insert_arguments_mysql(&self, args: &mut MySqlArguments) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_insert_arguments_mysql".to_string()).into())
+    }
     fn gen_upsert_arguments_mysql(&self) -> Result<MySqlArguments, BoxDynError> {
         Err(NotImplementError("gen_upsert_arguments_mysql".to_string()).into())
     }
@@ -33,6 +103,9 @@ pub trait Entity: Sync + Debug {
     fn gen_insert_arguments_postgres(&self) -> Result<PgArguments, BoxDynError> {
         Err(NotImplementError("gen_insert_arguments_postgres".to_string()).into())
     }
+    fn add_insert_arguments_postgres(&self, args: &mut PgArguments) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_insert_arguments_postgres".to_string()).into())
+    }
     fn gen_upsert_arguments_postgres(&self) -> Result<PgArguments, BoxDynError> {
         Err(NotImplementError("gen_upsert_arguments_postgres".to_string()).into())
     }
@@ -15,3 +15,30 @@ impl std::fmt::Display for NotValidOrderByError {
         write!(f, "order by fields: {} is not valid", self.0)
     }
 }
+
+#[derive(Debug)]
+pub struct NotValidCursorError(pub String);
+impl std::error::Error for NotValidCursorError {}
+impl std::fmt::Display for NotValidCursorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "cursor: {} is not valid", self.0)
+    }
+}
+
+#[derive(Debug)]
+pub struct NotValidCmpError(pub String);
+impl std::error::Error for NotValidCmpError {}
+impl std::fmt::Display for NotValidCmpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "location cmp: {} is not valid", self.0)
+    }
+}
+
+#[derive(Debug)]
+pub struct NotValidTemplateError(pub String);
+impl std::error::Error for NotValidTemplateError {}
+impl std::fmt::Display for NotValidTemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "template sql: {} is not valid", self.0)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Entity;
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+
+// errors of hooks are not required to be Send, hooks run on the task of the hooked operation
+pub type HookFuture<'a, T = ()> = Pin<Box<dyn Future<Output = Result<T, Box<dyn Error>>> + 'a>>;
+
+/**
+writes available inside hooks, they run on the connection or transaction of the hooked operation,
+so rows written by hooks are committed or rolled back together with it in a transaction
+*/
+pub trait HookExecutor {
+    fn execute_plain<'a>(&'a mut self, stmt: &'a str) -> HookFuture<'a, u64>;
+
+    fn insert<'a>(&'a mut self, entity: &'a dyn Entity) -> HookFuture<'a, bool>;
+
+    fn upsert<'a>(&'a mut self, entity: &'a dyn Entity) -> HookFuture<'a, bool>;
+}
+
+/**
+lifecycle hooks of writes, enabled by #[hooks(entity, mutation, unique, location)] on the schema
+1. entity: before/after_insert around insert, create, upsert, insert_all and upsert_all
+2. mutation: before/after_update around update and change
+3. unique: before/after_delete around delete and hard_delete
+4. location: before/after_delete around purify and hard_purify
+
+before hooks may change self, the changed value is written. create changes the given entity in place,
+other writes only borrow the value, so they write a clone changed by the hook and the caller's value is kept
+
+an error returned by a before hook aborts the operation before anything is written.
+after hooks run only when rows are affected, and the statement is already executed when they run:
+an error returned by an after hook is returned to the caller, but it undoes the write only in a transaction,
+on a database without transaction the written rows are kept, run the write in a transaction when they must be undone
+
+insert_all/upsert_all run after_insert for every entity of a batch once the batch statement affects rows,
+rows skipped by the database inside a batch can not be told apart
+*/
+pub trait WriteHooks: Sync {
+    fn before_insert<'a>(&'a mut self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn after_insert<'a>(&'a self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn before_update<'a>(&'a mut self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn after_update<'a>(&'a self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn before_delete<'a>(&'a mut self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn after_delete<'a>(&'a self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+}
@@ -1,3 +1,15 @@
 pub trait FromClause {
-    fn get_from_clause(&self) -> String;
+    fn get_from_clause(&self) -> String {
+        self.get_wrapped_from_clause(None)
+    }
+
+    // table and field names are wrapped by wrap_char of database when it is given
+    fn get_wrapped_from_clause(&self, wrap_char: Option<char>) -> String;
+}
+
+pub(crate) fn wrap_name(name: &str, wrap_char: Option<char>) -> String {
+    match wrap_char {
+        Some(wrap_char) => format!("{}{}{}", wrap_char, name, wrap_char),
+        None => name.to_string(),
+    }
 }
@@ -1,3 +1,4 @@
+use crate::join::from_clause::wrap_name;
 use crate::join::{FromClause, JoinedFields, JoinedMode};
 use serde::{Deserialize, Serialize};
 
@@ -12,25 +13,47 @@ pub struct JoinedCondition {
     pub(crate) joined_fields: Vec<JoinedFields>,
 }
 
-pub fn get_on_clause(joined_fields: &Vec<JoinedFields>) -> String {
+impl JoinedCondition {
+    pub fn new(
+        mode: JoinedMode,
+        left_table: impl Into<String>,
+        right_table: impl Into<String>,
+        joined_fields: Vec<JoinedFields>,
+    ) -> Self {
+        Self {
+            mode,
+            left_table: left_table.into(),
+            right_table: right_table.into(),
+            joined_fields,
+        }
+    }
+}
+
+pub fn get_on_clause(joined_fields: &Vec<JoinedFields>, wrap_char: Option<char>) -> String {
     let mut on_clause_vec: Vec<String> = Vec::new();
     for field in joined_fields {
         let on_seg = format!(
             "{}.{} = {}.{}",
-            field.0.table_name, field.0.field_name, field.1.table_name, field.1.field_name
+            wrap_name(&field.0.table_name, wrap_char),
+            wrap_name(&field.0.field_name, wrap_char),
+            wrap_name(&field.1.table_name, wrap_char),
+            wrap_name(&field.1.field_name, wrap_char)
         );
         on_clause_vec.push(on_seg);
     }
-    on_clause_vec.join(",")
+    on_clause_vec.join(" AND ")
 }
 
 impl FromClause for JoinedCondition {
-    fn get_from_clause(&self) -> String {
-        let on_clause = get_on_clause(&self.joined_fields);
+    fn get_wrapped_from_clause(&self, wrap_char: Option<char>) -> String {
+        let on_clause = get_on_clause(&self.joined_fields, wrap_char);
         let join_operator = self.mode.get_join_operator();
         format!(
             "{} {} {} ON {}",
-            self.left_table, join_operator, self.right_table, on_clause
+            wrap_name(&self.left_table, wrap_char),
+            join_operator,
+            wrap_name(&self.right_table, wrap_char),
+            on_clause
         )
     }
 }
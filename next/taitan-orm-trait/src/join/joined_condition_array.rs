@@ -12,11 +12,17 @@ pub struct JoinedConditionArray<const N: usize> {
 }
 
 impl<const N: usize> JoinedConditionArray<N> {
-    pub fn get_from_clause(&self) -> String {
-        let root_join = self.root.get_from_clause();
+    pub fn new(root: JoinedCondition, next: [JoinedConditionPart; N]) -> Self {
+        Self { root, next }
+    }
+}
+
+impl<const N: usize> FromClause for JoinedConditionArray<N> {
+    fn get_wrapped_from_clause(&self, wrap_char: Option<char>) -> String {
+        let root_join = self.root.get_wrapped_from_clause(wrap_char);
         let mut part_clauses: Vec<String> = Vec::new();
         for part in &self.next {
-            let part_clause = part.get_from_clause();
+            let part_clause = part.get_wrapped_from_clause(wrap_char);
             part_clauses.push(part_clause);
         }
         let part_clause = part_clauses.join(" ");
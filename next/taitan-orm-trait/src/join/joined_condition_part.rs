@@ -1,3 +1,4 @@
+use crate::join::from_clause::wrap_name;
 use crate::join::{get_on_clause, FromClause, JoinedFields, JoinedMode};
 use serde::{Deserialize, Serialize};
 
@@ -9,10 +10,20 @@ pub struct JoinedConditionPart {
     joined_fields: Vec<JoinedFields>,
 }
 
+impl JoinedConditionPart {
+    pub fn new(mode: JoinedMode, table: impl Into<String>, joined_fields: Vec<JoinedFields>) -> Self {
+        Self {
+            mode,
+            table: table.into(),
+            joined_fields,
+        }
+    }
+}
+
 impl FromClause for JoinedConditionPart {
-    fn get_from_clause(&self) -> String {
-        let on_clause = get_on_clause(&self.joined_fields);
+    fn get_wrapped_from_clause(&self, wrap_char: Option<char>) -> String {
+        let on_clause = get_on_clause(&self.joined_fields, wrap_char);
         let join_operator = self.mode.get_join_operator();
-        format!("{} {} ON {}", join_operator, self.table, on_clause)
+        format!("{} {} ON {}", join_operator, wrap_name(&self.table, wrap_char), on_clause)
     }
 }
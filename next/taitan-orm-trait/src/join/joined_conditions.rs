@@ -19,19 +19,19 @@ pub enum JoinedConditions {
 }
 
 impl FromClause for JoinedConditions {
-    fn get_from_clause(&self) -> String {
+    fn get_wrapped_from_clause(&self, wrap_char: Option<char>) -> String {
         match &self {
-            JoinedConditions::Two(e) => e.get_from_clause(),
-            JoinedConditions::Three(e) => e.get_from_clause(),
-            JoinedConditions::Four(e) => e.get_from_clause(),
-            JoinedConditions::Five(e) => e.get_from_clause(),
-            JoinedConditions::Six(e) => e.get_from_clause(),
-            JoinedConditions::Seven(e) => e.get_from_clause(),
-            JoinedConditions::Eight(e) => e.get_from_clause(),
-            JoinedConditions::Nine(e) => e.get_from_clause(),
-            JoinedConditions::Ten(e) => e.get_from_clause(),
-            JoinedConditions::Eleven(e) => e.get_from_clause(),
-            JoinedConditions::Twelve(e) => e.get_from_clause(),
+            JoinedConditions::Two(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Three(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Four(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Five(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Six(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Seven(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Eight(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Nine(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Ten(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Eleven(e) => e.get_wrapped_from_clause(wrap_char),
+            JoinedConditions::Twelve(e) => e.get_wrapped_from_clause(wrap_char),
         }
     }
 }
@@ -71,6 +71,8 @@ mod test {
         }
 
         let from_clause = joined_conds.get_from_clause();
-        assert_eq!(from_clause, "user INNER JOIN class ON user.id = class.id OUTER JOIN school ON school.id = user.id,user.name = school.name OUTER JOIN country ON country.id = school.id,coutry.name = user.name");
+        assert_eq!(from_clause, "user INNER JOIN class ON user.id = class.id OUTER JOIN school ON school.id = user.id AND user.name = school.name OUTER JOIN country ON country.id = school.id AND coutry.name = user.name");
+        let wrapped_clause = joined_conds.get_wrapped_from_clause(Some('`'));
+        assert!(wrapped_clause.starts_with("`user` INNER JOIN `class` ON `user`.`id` = `class`.`id` OUTER JOIN `school`"));
     }
 }
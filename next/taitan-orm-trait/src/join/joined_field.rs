@@ -8,6 +8,15 @@ pub struct JoinedField {
     pub(crate) field_name: String,
 }
 
+impl JoinedField {
+    pub fn new(table_name: impl Into<String>, field_name: impl Into<String>) -> Self {
+        Self {
+            table_name: table_name.into(),
+            field_name: field_name.into(),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for JoinedField {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -0,0 +1,38 @@
+use crate::{SelectedEntity, Selection};
+use sqlx::Database;
+use std::fmt::Debug;
+
+/**
+tuple of selected entities decoded from one joined row, such as (UserSelectedEntity, ClassSelectedEntity)
+the tuple itself is the selection, same as SelectedEntity::select_from_row
+columns of each entity follow the previous one in the order of tuple
+*/
+pub trait JoinedSelected<DB: Database>: Debug + Sized {
+    fn get_selections(&self) -> Vec<&dyn Selection>;
+
+    fn select_from_row(selection: &Self, row: DB::Row) -> Result<Self, sqlx::Error>;
+}
+
+macro_rules! joined_selected_impl {
+    ($($se:ident: $index:tt),+) => {
+        impl<DB: Database, $($se),+> JoinedSelected<DB> for ($($se,)+)
+        where
+            $($se: SelectedEntity<DB> + Selection),+
+        {
+            fn get_selections(&self) -> Vec<&dyn Selection> {
+                vec![$(&self.$index),+]
+            }
+
+            fn select_from_row(selection: &Self, row: DB::Row) -> Result<Self, sqlx::Error> {
+                let mut offset = 0;
+                Ok(($($se::select_from_row_at(&selection.$index, &row, &mut offset)?,)+))
+            }
+        }
+    };
+}
+
+joined_selected_impl!(SE0: 0, SE1: 1);
+joined_selected_impl!(SE0: 0, SE1: 1, SE2: 2);
+joined_selected_impl!(SE0: 0, SE1: 1, SE2: 2, SE3: 3);
+joined_selected_impl!(SE0: 0, SE1: 1, SE2: 2, SE3: 3, SE4: 4);
+joined_selected_impl!(SE0: 0, SE1: 1, SE2: 2, SE3: 3, SE4: 4, SE5: 5);
@@ -6,6 +6,7 @@ mod joined_conditions;
 mod joined_field;
 mod joined_mode;
 mod joined_query;
+mod joined_selected;
 
 pub use from_clause::FromClause;
 pub use joined_field::JoinedField;
@@ -14,5 +15,8 @@ pub use joined_mode::JoinedMode;
 
 pub use joined_condition::get_on_clause;
 pub use joined_condition::JoinedCondition;
+pub use joined_condition_array::JoinedConditionArray;
+pub use joined_condition_part::JoinedConditionPart;
 pub use joined_conditions::JoinedConditions;
 pub use joined_query::JoinedQuery;
+pub use joined_selected::JoinedSelected;
@@ -15,13 +15,23 @@ mod update_command;
 mod write_command;
 mod template;
 mod optional;
+mod assign;
 mod field;
+mod relation;
+mod aggregate;
+mod soft_delete;
+mod timestamp;
+mod hooks;
 
 pub use schema::Schema;
 pub use optional::Optional;
+pub use assign::{Assign, CopySource};
 
 pub use error::NotImplementError;
 pub use error::NotValidOrderByError;
+pub use error::NotValidCursorError;
+pub use error::NotValidCmpError;
+pub use error::NotValidTemplateError;
 
 pub use entity::Entity;
 
@@ -35,24 +45,45 @@ pub use selected::SelectedEntityNew;
 
 pub use join::FromClause;
 pub use location::CmpOperator;
+pub use location::Column;
+pub use location::TableColumn;
+pub use location::Having;
 pub use location::Location;
 pub use location::LocationExpr;
+pub use location::LocationValue;
 pub use location::LocationTrait;
+pub use location::LocationTree;
 pub use order_by::validate_order_by;
 pub use order_by::OrderBy;
+pub use order_by::{parse_order_by, FieldOrder, NullsOrder, OrderDirection};
 
 pub use join::JoinedCondition;
 pub use join::JoinedConditions;
+pub use join::{JoinedConditionArray, JoinedConditionPart, JoinedField, JoinedMode, JoinedSelected};
 
 pub use page::paged_info;
 pub use page::paged_list;
 pub use page::pagination;
+pub use page::cursor::{get_seek_indexes, is_seek_uniform, Cursor, CursorCodec, CursorDirection, CursorValue};
+pub use page::cursor_pagination::CursorPagination;
+pub use page::cursor_paged_list::CursorPagedList;
+pub use page::cursor_paged_list::build_cursor_paged_list;
 pub use page::count_sql::CountSql;
 
-pub use template_record::TemplateRecord;
-pub use template::ParsedTemplateSql;
+pub use template_record::{OutputTemplateRecord, TemplateRecord};
+pub use template::{list_place_holders, ParsedTemplateSql};
 pub use template::TemplateValue;
+pub use template::TemplateArgument;
+pub use template::resolve_template_includes;
+pub use template::{keyword_raw_ident, quote_raw_ident};
+pub use template::derive_count_sql;
+pub use template::{replace_place_holders, StringEscape};
 
 pub use write_command::WriteCommand;
 pub use page::paged_list::build_paged_list;
-pub use field::FieldName;
\ No newline at end of file
+pub use field::FieldName;
+pub use soft_delete::{DeletedScope, SoftDelete};
+pub use timestamp::AutoTimestamp;
+pub use hooks::{HookExecutor, HookFuture, WriteHooks};
+pub use aggregate::{AggregateExpr, AggregateFunction, Aggregated, NumericKind};
+pub use relation::{collect_relation_keys, group_many, group_one, RelationKey};
\ No newline at end of file
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 pub enum CmpOperator {
     #[serde(alias = "=")]
     Eq,
+    #[serde(alias = "!=", alias = "<>")]
+    NotEq,
     #[serde(alias = "<")]
     LessThan,
     #[serde(alias = "<=")]
@@ -14,17 +16,46 @@ pub enum CmpOperator {
     GreaterOrEq,
     #[serde(alias = "like")]
     Like,
+    #[serde(alias = "not like", alias = "not_like")]
+    NotLike,
+    #[serde(alias = "in")]
+    In,
+    #[serde(alias = "not in", alias = "not_in")]
+    NotIn,
+    #[serde(alias = "is null", alias = "is_null")]
+    IsNull,
+    #[serde(alias = "is not null", alias = "is_not_null")]
+    IsNotNull,
+    #[serde(alias = "between")]
+    Between,
 }
 
 impl CmpOperator {
     pub fn get_sql(&self) -> &'static str {
         match self {
             CmpOperator::Eq => "=",
+            CmpOperator::NotEq => "<>",
             CmpOperator::LessThan => "<",
             CmpOperator::LessOrEq => "<=",
             CmpOperator::GreaterThan => ">",
             CmpOperator::GreaterOrEq => ">=",
-            CmpOperator::Like => "LIKE",
+            CmpOperator::Like => " LIKE ",
+            CmpOperator::NotLike => " NOT LIKE ",
+            CmpOperator::In => " IN ",
+            CmpOperator::NotIn => " NOT IN ",
+            CmpOperator::IsNull => " IS NULL",
+            CmpOperator::IsNotNull => " IS NOT NULL",
+            CmpOperator::Between => " BETWEEN ",
+        }
+    }
+
+    // count of values bound to placeholders, None means a list of any length
+    pub fn get_values_count(&self) -> Option<usize> {
+        match self {
+            CmpOperator::IsNull | CmpOperator::IsNotNull => Some(0),
+            CmpOperator::Between => Some(2),
+            CmpOperator::In | CmpOperator::NotIn => None,
+            _ => Some(1),
         }
     }
 }
@@ -0,0 +1,134 @@
+use crate::{AggregateExpr, AggregateFunction, Aggregated, CmpOperator, Location, LocationExpr};
+use std::fmt::{Debug, Formatter};
+
+/**
+typed column handle generated by #[derive(Schema)] in {Table}Columns, such as UserColumns::age()
+every condition builds the location of the table, value type is the rust type of the field
+1. UserColumns::age().gt(18) -> UserLocation { age: Some(age > 18), .. }
+2. UserColumns::age().gt(18).and(UserColumns::name().like("a%")) -> LocationTree
+*/
+pub struct Column<L, T> {
+    name: &'static str,
+    locate: fn(LocationExpr<T>) -> L,
+}
+
+impl<L, T> Clone for Column<L, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L, T> Copy for Column<L, T> {}
+
+impl<L, T> Debug for Column<L, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Column").field("name", &self.name).finish()
+    }
+}
+
+impl<L: Location, T> Column<L, T> {
+    pub const fn new(name: &'static str, locate: fn(LocationExpr<T>) -> L) -> Self {
+        Self { name, locate }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn locate(&self, expr: LocationExpr<T>) -> L {
+        (self.locate)(expr)
+    }
+
+    pub fn eq(&self, value: T) -> L {
+        self.locate(LocationExpr::new(CmpOperator::Eq, value))
+    }
+
+    pub fn ne(&self, value: T) -> L {
+        self.locate(LocationExpr::new(CmpOperator::NotEq, value))
+    }
+
+    pub fn lt(&self, value: T) -> L {
+        self.locate(LocationExpr::new(CmpOperator::LessThan, value))
+    }
+
+    pub fn le(&self, value: T) -> L {
+        self.locate(LocationExpr::new(CmpOperator::LessOrEq, value))
+    }
+
+    pub fn gt(&self, value: T) -> L {
+        self.locate(LocationExpr::new(CmpOperator::GreaterThan, value))
+    }
+
+    pub fn ge(&self, value: T) -> L {
+        self.locate(LocationExpr::new(CmpOperator::GreaterOrEq, value))
+    }
+
+    // like pattern is usually a literal, so &str is accepted for String field
+    pub fn like(&self, pattern: impl Into<T>) -> L {
+        self.locate(LocationExpr::new(CmpOperator::Like, pattern.into()))
+    }
+
+    pub fn not_like(&self, pattern: impl Into<T>) -> L {
+        self.locate(LocationExpr::new(CmpOperator::NotLike, pattern.into()))
+    }
+
+    pub fn is_in(&self, values: Vec<T>) -> L {
+        self.locate(LocationExpr::is_in(values))
+    }
+
+    pub fn not_in(&self, values: Vec<T>) -> L {
+        self.locate(LocationExpr::not_in(values))
+    }
+
+    pub fn between(&self, start: T, end: T) -> L {
+        self.locate(LocationExpr::between(start, end))
+    }
+
+    pub fn is_null(&self) -> L {
+        self.locate(LocationExpr::is_null())
+    }
+
+    pub fn is_not_null(&self) -> L {
+        self.locate(LocationExpr::is_not_null())
+    }
+
+    pub fn count(&self) -> AggregateExpr {
+        AggregateExpr::new(AggregateFunction::Count, self.name, None)
+    }
+
+    pub fn count_distinct(&self) -> AggregateExpr {
+        AggregateExpr::new(AggregateFunction::CountDistinct, self.name, None)
+    }
+
+    pub fn min(&self) -> AggregateExpr {
+        AggregateExpr::new(AggregateFunction::Min, self.name, None)
+    }
+
+    pub fn max(&self) -> AggregateExpr {
+        AggregateExpr::new(AggregateFunction::Max, self.name, None)
+    }
+}
+
+/**
+column of table L with the value type erased, so columns of different types can be listed together,
+such as GROUP BY columns: [&UserColumns::name(), &UserColumns::age()]
+*/
+pub trait TableColumn<L>: Debug {
+    fn get_name(&self) -> &'static str;
+}
+
+impl<L: Location, T> TableColumn<L> for Column<L, T> {
+    fn get_name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<L: Location, T: Aggregated> Column<L, T> {
+    pub fn sum(&self) -> AggregateExpr {
+        AggregateExpr::new(AggregateFunction::Sum, self.name, Some(T::SUM_KIND))
+    }
+
+    pub fn avg(&self) -> AggregateExpr {
+        AggregateExpr::new(AggregateFunction::Avg, self.name, Some(T::AVG_KIND))
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{AggregateExpr, FieldName, Location, LocationExpr};
+use sqlx::error::BoxDynError;
+use sqlx::mysql::MySqlArguments;
+use sqlx::postgres::PgArguments;
+use sqlx::sqlite::SqliteArguments;
+use sqlx::{Arguments, Encode, MySql, Postgres, Sqlite, Type};
+use std::fmt::Debug;
+
+/**
+condition on an aggregate, used as the HAVING location of grouped search
+OrderColumns::amount().sum().gt(100) -> SUM(`amount`)>?
+it is not bound to a table, and can be composed with other having conditions by LocationTree
+*/
+#[derive(Clone, Debug)]
+pub struct Having<T> {
+    aggregate: AggregateExpr,
+    expr: LocationExpr<T>,
+}
+
+impl<T> Having<T> {
+    pub fn new(aggregate: AggregateExpr, expr: LocationExpr<T>) -> Self {
+        Self { aggregate, expr }
+    }
+}
+
+impl<T> Location for Having<T>
+where
+    T: Sync + Debug,
+    T: for<'q> Encode<'q, Sqlite> + Type<Sqlite>,
+    T: for<'q> Encode<'q, MySql> + Type<MySql>,
+    T: for<'q> Encode<'q, Postgres> + Type<Postgres>,
+{
+    fn get_table_name(&self) -> &'static str {
+        ""
+    }
+
+    fn get_location_fields_name(&self) -> Vec<FieldName> {
+        Vec::new()
+    }
+
+    // aggregate is not casted in HAVING, the comparison is done by the database
+    fn get_where_clause(&self, wrap_char: char, place_holder: char) -> String {
+        let aggregate = self.aggregate.get_expr(wrap_char, None);
+        self.expr.get_wrapped_where_sql(&aggregate, place_holder)
+    }
+
+    fn gen_location_arguments_sqlite(&self) -> Result<SqliteArguments<'_>, BoxDynError> {
+        let mut args = SqliteArguments::default();
+        self.add_location_arguments_sqlite(&mut args)?;
+        Ok(args)
+    }
+
+    fn gen_location_arguments_mysql(&self) -> Result<MySqlArguments, BoxDynError> {
+        let mut args = MySqlArguments::default();
+        self.add_location_arguments_mysql(&mut args)?;
+        Ok(args)
+    }
+
+    fn gen_location_arguments_postgres(&self) -> Result<PgArguments, BoxDynError> {
+        let mut args = PgArguments::default();
+        self.add_location_arguments_postgres(&mut args)?;
+        Ok(args)
+    }
+
+    fn add_location_arguments_sqlite<'a>(&'a self, args: &mut SqliteArguments<'a>) -> Result<(), BoxDynError> {
+        for value in self.expr.get_values()? {
+            args.add(value)?;
+        }
+        Ok(())
+    }
+
+    fn add_location_arguments_mysql(&self, args: &mut MySqlArguments) -> Result<(), BoxDynError> {
+        for value in self.expr.get_values()? {
+            args.add(value)?;
+        }
+        Ok(())
+    }
+
+    fn add_location_arguments_postgres(&self, args: &mut PgArguments) -> Result<(), BoxDynError> {
+        for value in self.expr.get_values()? {
+            args.add(value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AggregateExpr, AggregateFunction, Location, NumericKind};
+    use sqlx::Arguments;
+
+    #[test]
+    pub fn test_having() {
+        let having = AggregateExpr::count_all().gt(1i64);
+        assert_eq!(having.get_where_clause('`', '?'), "COUNT(*)>?");
+        assert_eq!(having.gen_location_arguments_sqlite().unwrap().len(), 1);
+
+        let sum = AggregateExpr::new(AggregateFunction::Sum, "qty", Some(NumericKind::Integer));
+        let having = sum.between(2i64, 9i64);
+        assert_eq!(having.get_where_clause('"', '?'), "SUM(\"qty\") BETWEEN ? AND ?");
+        assert_eq!(having.gen_location_arguments_postgres().unwrap().len(), 2);
+    }
+}
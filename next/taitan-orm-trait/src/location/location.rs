@@ -1,4 +1,4 @@
-use crate::{FieldName, NotImplementError};
+use crate::{FieldName, NotImplementError, SoftDelete, WriteHooks};
 use sqlx::error::BoxDynError;
 use sqlx::mysql::MySqlArguments;
 use sqlx::postgres::PgArguments;
@@ -12,6 +12,29 @@ pub trait Location: Sync + Debug {
 
     fn get_where_clause(&self, wrap_char: char, place_holder: char) -> String;
 
+    fn get_soft_delete(&self) -> Option<SoftDelete> {
+        None
+    }
+
+    // Some(self) with #[hooks(location)], purify/hard_purify run its before/after_delete
+    fn get_hooks(&self) -> Option<&dyn WriteHooks> {
+        None
+    }
+
+    fn get_hooks_mut(&mut self) -> Option<&mut dyn WriteHooks> {
+        None
+    }
+
+    // purify/hard_purify borrow the value, before hooks change this clone which is written instead
+    fn clone_for_hooks(&self) -> Option<Box<dyn Location + '_>> {
+        None
+    }
+
+    // where clause with fields qualified by table name, used by joined search
+    fn get_qualified_where_clause(&self, wrap_char: char, place_holder: char) -> String {
+        self.get_where_clause(wrap_char, place_holder)
+    }
+
     // fn check_valid_order_by(&self, fields: &[&str]) -> bool;
 
     fn gen_location_arguments_sqlite(&self) -> Result<SqliteArguments<'_>, BoxDynError> {
@@ -23,4 +46,15 @@ pub trait Location: Sync + Debug {
     fn gen_location_arguments_postgres(&self) -> Result<PgArguments, BoxDynError> {
         Err(NotImplementError("gen_primary_arguments_postgres".to_string()).into())
     }
+
+    // append location arguments to an existing buffer, used by composed locations
+    fn add_location_arguments_sqlite<'a>(&'a self, args: &mut SqliteArguments<'a>) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_location_arguments_sqlite".to_string()).into())
+    }
+    fn add_location_arguments_mysql(&self, args: &mut MySqlArguments) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_location_arguments_mysql".to_string()).into())
+    }
+    fn add_location_arguments_postgres(&self, args: &mut PgArguments) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_location_arguments_postgres".to_string()).into())
+    }
 }
@@ -1,9 +1,39 @@
-use crate::CmpOperator;
+use crate::{CmpOperator, NotValidCmpError};
 use serde::{Deserialize, Serialize};
 
+/**
+values of location expr, deserialized from json without tag:
+null -> None, 1 -> One, [1, 2] -> Pair, [1, 2, 3] -> List
+*/
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum LocationValue<T> {
+    #[default]
+    None,
+    One(T),
+    Pair(T, T),
+    List(Vec<T>),
+}
+
+impl<T> LocationValue<T> {
+    pub fn as_vec(&self) -> Vec<&T> {
+        match self {
+            LocationValue::None => Vec::new(),
+            LocationValue::One(value) => vec![value],
+            LocationValue::Pair(first, second) => vec![first, second],
+            LocationValue::List(values) => values.iter().collect(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct LocationExpr<T> {
-    pub val: T,
+    /**
+    breaking since 0.2.0: val was a single T, now it holds the values of IN/BETWEEN/IS NULL as well.
+    LocationExpr::new(cmp, v) builds LocationValue::One(v), read values to bind by get_values()
+    */
+    #[serde(default)]
+    pub val: LocationValue<T>,
     pub cmp: CmpOperator,
 }
 
@@ -18,6 +48,139 @@ impl<T> LocationTrait for LocationExpr<T> {
 
 impl<T> LocationExpr<T> {
     pub fn new(cmp: CmpOperator, val: T) -> Self {
-        Self { cmp, val }
+        Self {
+            cmp,
+            val: LocationValue::One(val),
+        }
+    }
+
+    pub fn is_in(values: Vec<T>) -> Self {
+        Self {
+            cmp: CmpOperator::In,
+            val: LocationValue::List(values),
+        }
+    }
+
+    pub fn not_in(values: Vec<T>) -> Self {
+        Self {
+            cmp: CmpOperator::NotIn,
+            val: LocationValue::List(values),
+        }
+    }
+
+    pub fn between(low: T, high: T) -> Self {
+        Self {
+            cmp: CmpOperator::Between,
+            val: LocationValue::Pair(low, high),
+        }
+    }
+
+    pub fn is_null() -> Self {
+        Self {
+            cmp: CmpOperator::IsNull,
+            val: LocationValue::None,
+        }
+    }
+
+    pub fn is_not_null() -> Self {
+        Self {
+            cmp: CmpOperator::IsNotNull,
+            val: LocationValue::None,
+        }
+    }
+
+    // values bound to placeholders of where clause in order, count must match the operator
+    pub fn get_values(&self) -> Result<Vec<&T>, NotValidCmpError> {
+        let values = self.val.as_vec();
+        match self.cmp.get_values_count() {
+            Some(count) if count != values.len() => Err(NotValidCmpError(format!(
+                "{:?} expects {} values, but got {}",
+                self.cmp,
+                count,
+                values.len()
+            ))),
+            _ => Ok(values),
+        }
+    }
+
+    /**
+    where segment of this expr on field, empty IN list is always false and empty NOT IN list is always true
+    `age`>=? | `age` IN (?,?) | `age` BETWEEN ? AND ? | `age` IS NULL
+    */
+    pub fn get_where_sql(&self, field_name: &str, wrap_char: char, place_holder: char) -> String {
+        let field = format!("{}{}{}", wrap_char, field_name, wrap_char);
+        self.get_wrapped_where_sql(&field, place_holder)
+    }
+
+    // same as get_where_sql, but field is qualified by table name: `user`.`age`>=?
+    pub fn get_qualified_where_sql(
+        &self,
+        table_name: &str,
+        field_name: &str,
+        wrap_char: char,
+        place_holder: char,
+    ) -> String {
+        let field = format!(
+            "{}{}{}.{}{}{}",
+            wrap_char, table_name, wrap_char, wrap_char, field_name, wrap_char
+        );
+        self.get_wrapped_where_sql(&field, place_holder)
+    }
+
+    pub(crate) fn get_wrapped_where_sql(&self, field: &str, place_holder: char) -> String {
+        let count = self.val.as_vec().len();
+        match self.cmp {
+            CmpOperator::IsNull | CmpOperator::IsNotNull => format!("{}{}", field, self.cmp.get_sql()),
+            CmpOperator::In if count == 0 => "1 = 0".to_string(),
+            CmpOperator::NotIn if count == 0 => "1 = 1".to_string(),
+            CmpOperator::In | CmpOperator::NotIn => {
+                let place_holders = vec![place_holder.to_string(); count].join(",");
+                format!("{}{}({})", field, self.cmp.get_sql(), place_holders)
+            }
+            CmpOperator::Between => format!(
+                "{}{}{} AND {}",
+                field,
+                self.cmp.get_sql(),
+                place_holder,
+                place_holder
+            ),
+            _ => format!("{}{}{}", field, self.cmp.get_sql(), place_holder),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_location_expr() -> Result<(), NotValidCmpError> {
+        let expr: LocationExpr<i32> = serde_json::from_str(r#"{"cmp": "in", "val": [1, 2, 3]}"#).unwrap();
+        assert_eq!(expr.get_where_sql("age", '`', '?'), "`age` IN (?,?,?)");
+        assert_eq!(expr.get_values()?, vec![&1, &2, &3]);
+
+        let expr: LocationExpr<i32> = serde_json::from_str(r#"{"cmp": "between", "val": [1, 9]}"#).unwrap();
+        assert_eq!(expr.get_where_sql("age", '`', '?'), "`age` BETWEEN ? AND ?");
+        assert_eq!(expr.get_values()?, vec![&1, &9]);
+
+        let expr: LocationExpr<i32> = serde_json::from_str(r#"{"cmp": "!=", "val": 5}"#).unwrap();
+        assert_eq!(expr, LocationExpr::new(CmpOperator::NotEq, 5));
+        assert_eq!(expr.get_where_sql("age", '`', '?'), "`age`<>?");
+
+        let expr: LocationExpr<i32> = serde_json::from_str(r#"{"cmp": "is null"}"#).unwrap();
+        assert_eq!(expr, LocationExpr::is_null());
+        assert_eq!(expr.get_where_sql("age", '`', '?'), "`age` IS NULL");
+        assert!(expr.get_values()?.is_empty());
+
+        let expr: LocationExpr<String> = LocationExpr::new(CmpOperator::Like, "a%".to_string());
+        assert_eq!(expr.get_where_sql("name", '"', '?'), "\"name\" LIKE ?");
+        let expr: LocationExpr<String> = LocationExpr::new(CmpOperator::NotLike, "a%".to_string());
+        assert_eq!(expr.get_where_sql("name", '"', '?'), "\"name\" NOT LIKE ?");
+
+        assert_eq!(LocationExpr::<i32>::is_in(Vec::new()).get_where_sql("age", '`', '?'), "1 = 0");
+        assert_eq!(LocationExpr::<i32>::not_in(Vec::new()).get_where_sql("age", '`', '?'), "1 = 1");
+        assert!(LocationExpr::new(CmpOperator::Between, 1).get_values().is_err());
+        assert!(LocationExpr::<i32>::is_in(vec![1, 2]).get_values().is_ok());
+        Ok(())
     }
 }
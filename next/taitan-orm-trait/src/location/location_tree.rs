@@ -0,0 +1,299 @@
+use crate::{FieldName, Location, SoftDelete};
+use sqlx::error::BoxDynError;
+use sqlx::mysql::MySqlArguments;
+use sqlx::postgres::PgArguments;
+use sqlx::sqlite::SqliteArguments;
+use std::any::Any;
+use std::fmt::Debug;
+use std::ops::Not;
+
+/**
+boolean composition of locations, every child is wrapped in parentheses when rendered
+1. Leaf: where clause of a generated location, its fields are joined by AND
+2. And/Or: children joined by AND/OR, an empty And is always true and an empty Or is always false
+3. Not: negation of the child
+
+a LocationTree given to leaf/and/or is kept as a tree instead of being wrapped as a leaf.
+all leaves should locate the same table, the table name is taken from the first leaf,
+soft delete is taken from the first leaf which has it.
+arguments are gathered in the same depth-first order as the where clause is rendered.
+*/
+#[derive(Debug)]
+pub enum LocationTree {
+    Leaf(Box<dyn Location>),
+    And(Vec<LocationTree>),
+    Or(Vec<LocationTree>),
+    Not(Box<LocationTree>),
+}
+
+impl Not for LocationTree {
+    type Output = LocationTree;
+
+    fn not(self) -> Self::Output {
+        LocationTree::Not(Box::new(self))
+    }
+}
+
+impl LocationTree {
+    pub fn leaf<L: Location + 'static>(location: L) -> Self {
+        let location: Box<dyn Any> = Box::new(location);
+        match location.downcast::<LocationTree>() {
+            Ok(tree) => *tree,
+            Err(location) => LocationTree::Leaf(location.downcast::<L>().unwrap()),
+        }
+    }
+
+    pub fn all<I: IntoIterator<Item = LocationTree>>(children: I) -> Self {
+        LocationTree::And(children.into_iter().collect())
+    }
+
+    pub fn any<I: IntoIterator<Item = LocationTree>>(children: I) -> Self {
+        LocationTree::Or(children.into_iter().collect())
+    }
+
+    // a chain of and() is flattened into one group, so is an And tree given as other
+    pub fn and<L: Location + 'static>(self, other: L) -> Self {
+        let mut children = match self {
+            LocationTree::And(children) => children,
+            _ => vec![self],
+        };
+        match Self::leaf(other) {
+            LocationTree::And(others) => children.extend(others),
+            other => children.push(other),
+        }
+        LocationTree::And(children)
+    }
+
+    // a chain of or() is flattened into one group, so is an Or tree given as other
+    pub fn or<L: Location + 'static>(self, other: L) -> Self {
+        let mut children = match self {
+            LocationTree::Or(children) => children,
+            _ => vec![self],
+        };
+        match Self::leaf(other) {
+            LocationTree::Or(others) => children.extend(others),
+            other => children.push(other),
+        }
+        LocationTree::Or(children)
+    }
+
+    // leaves are visited depth-first, in the order of the where clause
+    fn find_leaf<T, F: Fn(&dyn Location) -> Option<T> + Copy>(&self, f: F) -> Option<T> {
+        match self {
+            LocationTree::Leaf(location) => f(location.as_ref()),
+            LocationTree::And(children) | LocationTree::Or(children) => {
+                children.iter().find_map(|child| child.find_leaf(f))
+            }
+            LocationTree::Not(child) => child.find_leaf(f),
+        }
+    }
+
+    fn render_where_clause(&self, wrap_char: char, place_holder: char, qualified: bool) -> String {
+        match self {
+            LocationTree::Leaf(location) => {
+                let where_clause = if qualified {
+                    location.get_qualified_where_clause(wrap_char, place_holder)
+                } else {
+                    location.get_where_clause(wrap_char, place_holder)
+                };
+                if where_clause.is_empty() {
+                    "1 = 1".to_string()
+                } else {
+                    where_clause
+                }
+            }
+            LocationTree::And(children) if children.is_empty() => "1 = 1".to_string(),
+            LocationTree::Or(children) if children.is_empty() => "1 = 0".to_string(),
+            LocationTree::And(children) => {
+                Self::join_where_clause(children, " AND ", wrap_char, place_holder, qualified)
+            }
+            LocationTree::Or(children) => {
+                Self::join_where_clause(children, " OR ", wrap_char, place_holder, qualified)
+            }
+            LocationTree::Not(child) => {
+                format!("NOT ({})", child.render_where_clause(wrap_char, place_holder, qualified))
+            }
+        }
+    }
+
+    fn join_where_clause(
+        children: &[LocationTree],
+        separator: &str,
+        wrap_char: char,
+        place_holder: char,
+        qualified: bool,
+    ) -> String {
+        children
+            .iter()
+            .map(|child| format!("({})", child.render_where_clause(wrap_char, place_holder, qualified)))
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+}
+
+impl Location for LocationTree {
+    fn get_table_name(&self) -> &'static str {
+        self.find_leaf(|leaf| Some(leaf.get_table_name())).unwrap_or_default()
+    }
+
+    fn get_location_fields_name(&self) -> Vec<FieldName> {
+        match self {
+            LocationTree::Leaf(location) => location.get_location_fields_name(),
+            LocationTree::And(children) | LocationTree::Or(children) => children
+                .iter()
+                .flat_map(|child| child.get_location_fields_name())
+                .collect(),
+            LocationTree::Not(child) => child.get_location_fields_name(),
+        }
+    }
+
+    fn get_where_clause(&self, wrap_char: char, place_holder: char) -> String {
+        self.render_where_clause(wrap_char, place_holder, false)
+    }
+
+    fn get_soft_delete(&self) -> Option<SoftDelete> {
+        self.find_leaf(|leaf| leaf.get_soft_delete())
+    }
+
+    fn get_qualified_where_clause(&self, wrap_char: char, place_holder: char) -> String {
+        self.render_where_clause(wrap_char, place_holder, true)
+    }
+
+    fn gen_location_arguments_sqlite(&self) -> Result<SqliteArguments<'_>, BoxDynError> {
+        let mut args = SqliteArguments::default();
+        self.add_location_arguments_sqlite(&mut args)?;
+        Ok(args)
+    }
+
+    fn gen_location_arguments_mysql(&self) -> Result<MySqlArguments, BoxDynError> {
+        let mut args = MySqlArguments::default();
+        self.add_location_arguments_mysql(&mut args)?;
+        Ok(args)
+    }
+
+    fn gen_location_arguments_postgres(&self) -> Result<PgArguments, BoxDynError> {
+        let mut args = PgArguments::default();
+        self.add_location_arguments_postgres(&mut args)?;
+        Ok(args)
+    }
+
+    fn add_location_arguments_sqlite<'a>(&'a self, args: &mut SqliteArguments<'a>) -> Result<(), BoxDynError> {
+        match self {
+            LocationTree::Leaf(location) => location.add_location_arguments_sqlite(args),
+            LocationTree::And(children) | LocationTree::Or(children) => {
+                for child in children {
+                    child.add_location_arguments_sqlite(args)?;
+                }
+                Ok(())
+            }
+            LocationTree::Not(child) => child.add_location_arguments_sqlite(args),
+        }
+    }
+
+    fn add_location_arguments_mysql(&self, args: &mut MySqlArguments) -> Result<(), BoxDynError> {
+        match self {
+            LocationTree::Leaf(location) => location.add_location_arguments_mysql(args),
+            LocationTree::And(children) | LocationTree::Or(children) => {
+                for child in children {
+                    child.add_location_arguments_mysql(args)?;
+                }
+                Ok(())
+            }
+            LocationTree::Not(child) => child.add_location_arguments_mysql(args),
+        }
+    }
+
+    fn add_location_arguments_postgres(&self, args: &mut PgArguments) -> Result<(), BoxDynError> {
+        match self {
+            LocationTree::Leaf(location) => location.add_location_arguments_postgres(args),
+            LocationTree::And(children) | LocationTree::Or(children) => {
+                for child in children {
+                    child.add_location_arguments_postgres(args)?;
+                }
+                Ok(())
+            }
+            LocationTree::Not(child) => child.add_location_arguments_postgres(args),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    struct AgeLocation(&'static str);
+
+    impl Location for AgeLocation {
+        fn get_table_name(&self) -> &'static str {
+            "user"
+        }
+
+        fn get_location_fields_name(&self) -> Vec<FieldName> {
+            Vec::new()
+        }
+
+        fn get_where_clause(&self, wrap_char: char, place_holder: char) -> String {
+            if self.0.is_empty() {
+                return String::new();
+            }
+            format!("{}{}{}{}", wrap_char, self.0, wrap_char, place_holder)
+        }
+    }
+
+    #[derive(Debug)]
+    struct MemberLocation;
+
+    impl Location for MemberLocation {
+        fn get_table_name(&self) -> &'static str {
+            "member"
+        }
+
+        fn get_location_fields_name(&self) -> Vec<FieldName> {
+            Vec::new()
+        }
+
+        fn get_where_clause(&self, _wrap_char: char, _place_holder: char) -> String {
+            String::new()
+        }
+
+        fn get_soft_delete(&self) -> Option<SoftDelete> {
+            Some(SoftDelete::Flag("deleted"))
+        }
+    }
+
+    #[test]
+    pub fn test_location_tree() {
+        let tree = LocationTree::leaf(AgeLocation("age>"))
+            .or(AgeLocation("vip="))
+            .and(!LocationTree::leaf(AgeLocation("name LIKE ")));
+        assert_eq!(tree.get_table_name(), "user");
+        assert_eq!(
+            tree.get_where_clause('`', '?'),
+            "((`age>`?) OR (`vip=`?)) AND (NOT (`name LIKE `?))"
+        );
+
+        let tree = LocationTree::any([LocationTree::leaf(AgeLocation("")), LocationTree::all([])]);
+        assert_eq!(tree.get_where_clause('`', '?'), "(1 = 1) OR (1 = 1)");
+        assert_eq!(LocationTree::any([]).get_where_clause('`', '?'), "1 = 0");
+    }
+
+    #[test]
+    pub fn test_nested_location_tree() {
+        // trees given to and/or are kept as trees, groups of the same kind are flattened
+        let tree = LocationTree::leaf(AgeLocation("age>"))
+            .and(LocationTree::leaf(AgeLocation("vip=")).and(AgeLocation("id=")))
+            .and(LocationTree::leaf(AgeLocation("a=")).or(AgeLocation("b=")));
+        assert_eq!(
+            tree.get_where_clause('`', '?'),
+            "(`age>`?) AND (`vip=`?) AND (`id=`?) AND ((`a=`?) OR (`b=`?))"
+        );
+        let tree = LocationTree::leaf(LocationTree::leaf(AgeLocation("age>")));
+        assert!(matches!(tree, LocationTree::Leaf(_)));
+
+        // soft delete of any leaf is found, not only of the first one
+        let tree = LocationTree::leaf(AgeLocation("age>")).or(LocationTree::leaf(AgeLocation("vip=")).and(MemberLocation));
+        assert_eq!(tree.get_soft_delete(), Some(SoftDelete::Flag("deleted")));
+        assert_eq!(LocationTree::leaf(AgeLocation("age>")).get_soft_delete(), None);
+    }
+}
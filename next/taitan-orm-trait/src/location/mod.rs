@@ -1,10 +1,18 @@
 mod cmp_operator;
+mod column;
+mod having;
 mod located_query;
 mod location;
 mod location_expr;
+mod location_tree;
 
 pub use cmp_operator::CmpOperator;
+pub use column::Column;
+pub use column::TableColumn;
+pub use having::Having;
 pub use located_query::LocatedQuery;
 pub use location::Location;
 pub use location_expr::LocationExpr;
+pub use location_expr::LocationValue;
 pub use location_expr::LocationTrait;
+pub use location_tree::LocationTree;
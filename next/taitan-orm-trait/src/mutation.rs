@@ -1,4 +1,4 @@
-use crate::{FieldName, Location, NotImplementError, Unique};
+use crate::{FieldName, Location, NotImplementError, Unique, WriteHooks};
 use sqlx::error::BoxDynError;
 use sqlx::mysql::MySqlArguments;
 use sqlx::postgres::PgArguments;
@@ -11,6 +11,62 @@ pub trait Mutation: Sync + Debug {
 
     fn get_mutation_fields_name(&self) -> Vec<FieldName>;
 
+    /**
+    SET clause of update/change, `age` = ?,`views` = `views` + ?,`memo` = NULL
+    generated Mutation renders each Assign field, fields with is_null are set to NULL by default
+    */
+    fn get_set_clause(&self, wrap_char: char, place_holder: char) -> String {
+        self.get_mutation_fields_name()
+            .iter()
+            .map(|field| {
+                if field.is_null {
+                    format!("{}{}{} = NULL", wrap_char, field.database_field_name(), wrap_char)
+                } else {
+                    format!("{}{}{} = {}", wrap_char, field.database_field_name(), wrap_char, place_holder)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    /**
+    #[version] field of the table, update/change always SET `version` = `version` + 1,
+    and check `version` = ? in where clause only when the expected version is Some,
+    the expected version is Option<T> in generated mutation, as it is compared instead of assigned
+    */
+    fn get_version_field_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn is_version_checked(&self) -> bool {
+        false
+    }
+
+    // expected version is bound after where clause arguments
+    fn add_version_arguments_sqlite<'a>(&'a self, args: &mut SqliteArguments<'a>) -> Result<(), BoxDynError> {
+        Ok(())
+    }
+    fn add_version_arguments_mysql(&self, args: &mut MySqlArguments) -> Result<(), BoxDynError> {
+        Ok(())
+    }
+    fn add_version_arguments_postgres(&self, args: &mut PgArguments) -> Result<(), BoxDynError> {
+        Ok(())
+    }
+
+    // Some(self) with #[hooks(mutation)], update/change run its before/after_update
+    fn get_hooks(&self) -> Option<&dyn WriteHooks> {
+        None
+    }
+
+    fn get_hooks_mut(&mut self) -> Option<&mut dyn WriteHooks> {
+        None
+    }
+
+    // update/change borrow the value, before hooks change this clone which is written instead
+    fn clone_for_hooks(&self) -> Option<Self> where Self: Sized {
+        None
+    }
+
     // fn gen_update_arguments_sqlite<'a>(
     //     &'a self,
     //     primary: &'a Self::Primary,
@@ -48,4 +104,15 @@ pub trait Mutation: Sync + Debug {
     ) -> Result<PgArguments, BoxDynError> {
         Err(NotImplementError("gen_change_arguments_postgres".to_string()).into())
     }
+
+    // append mutation arguments to an existing buffer, where clause arguments are appended after them
+    fn add_mutation_arguments_sqlite<'a>(&'a self, args: &mut SqliteArguments<'a>) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_mutation_arguments_sqlite".to_string()).into())
+    }
+    fn add_mutation_arguments_mysql(&self, args: &mut MySqlArguments) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_mutation_arguments_mysql".to_string()).into())
+    }
+    fn add_mutation_arguments_postgres(&self, args: &mut PgArguments) -> Result<(), BoxDynError> {
+        Err(NotImplementError("add_mutation_arguments_postgres".to_string()).into())
+    }
 }
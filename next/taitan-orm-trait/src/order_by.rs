@@ -34,15 +34,120 @@ where
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OrderDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NullsOrder {
+    #[default]
+    Default, // decided by database
+    First,
+    Last,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FieldOrder {
+    pub direction: OrderDirection,
+    pub nulls: NullsOrder,
+}
+
+impl FieldOrder {
+    pub fn asc() -> Self {
+        Self::default()
+    }
+
+    pub fn desc() -> Self {
+        Self {
+            direction: OrderDirection::Desc,
+            nulls: NullsOrder::Default,
+        }
+    }
+
+    pub fn nulls_first(self) -> Self {
+        Self { nulls: NullsOrder::First, ..self }
+    }
+
+    pub fn nulls_last(self) -> Self {
+        Self { nulls: NullsOrder::Last, ..self }
+    }
+
+    // order used to walk backward, both direction and nulls are flipped
+    pub fn reverse(self) -> Self {
+        let direction = match self.direction {
+            OrderDirection::Asc => OrderDirection::Desc,
+            OrderDirection::Desc => OrderDirection::Asc,
+        };
+        let nulls = match self.nulls {
+            NullsOrder::Default => NullsOrder::Default,
+            NullsOrder::First => NullsOrder::Last,
+            NullsOrder::Last => NullsOrder::First,
+        };
+        Self { direction, nulls }
+    }
+}
+
+/**
+parse the query string syntax of order by, like "-created_at:nulls_last,name"
+1. fields are separated by comma
+2. "-" prefix means DESC, "+" prefix or no prefix means ASC
+3. ":nulls_first" or ":nulls_last" suffix sets the null ordering
+*/
+pub fn parse_order_by(order_by: &str) -> Result<Vec<(String, FieldOrder)>, NotValidOrderByError> {
+    let mut fields = Vec::new();
+    for segment in order_by.split(',') {
+        let segment = segment.trim();
+        let (segment, nulls) = match segment.split_once(':') {
+            None => (segment, NullsOrder::Default),
+            Some((field, "nulls_first")) => (field, NullsOrder::First),
+            Some((field, "nulls_last")) => (field, NullsOrder::Last),
+            Some(_) => return Err(NotValidOrderByError(segment.to_string())),
+        };
+        let (field, direction) = if let Some(field) = segment.strip_prefix('-') {
+            (field, OrderDirection::Desc)
+        } else if let Some(field) = segment.strip_prefix('+') {
+            (field, OrderDirection::Asc)
+        } else {
+            (segment, OrderDirection::Asc)
+        };
+        if field.is_empty() {
+            return Err(NotValidOrderByError(order_by.to_string()));
+        }
+        fields.push((field.to_string(), FieldOrder { direction, nulls }));
+    }
+    Ok(fields)
+}
+
 pub trait OrderBy: Sync + Debug {
+    // table of order by fields, used to qualify fields in joined search
+    fn get_table_name(&self) -> &'static str {
+        ""
+    }
+
     fn unique_fields(&self) -> &[&[&str]];
 
     fn all_fields(&self) -> &[&str];
 
     fn get_fields(&self) -> &[Cow<'_, str>];
+
+    // order of each field in get_fields, missing ones are ascending
+    fn get_orders(&self) -> &[FieldOrder] {
+        &[]
+    }
+
+    fn get_field_order(&self, index: usize) -> FieldOrder {
+        self.get_orders().get(index).copied().unwrap_or_default()
+    }
 }
 
 impl<T: OrderBy + Debug> OrderBy for &T {
+    fn get_table_name(&self) -> &'static str {
+        (*self).get_table_name()
+    }
+
     fn unique_fields(&self) -> &[&[&str]] {
         (*self).unique_fields()
     }
@@ -52,6 +157,10 @@ impl<T: OrderBy + Debug> OrderBy for &T {
     fn get_fields(&self) -> &[Cow<'_, str>] {
         (*self).get_fields()
     }
+
+    fn get_orders(&self) -> &[FieldOrder] {
+        (*self).get_orders()
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +216,27 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_parse_order_by() -> Result<(), Box<dyn Error + 'static>> {
+        let fields = parse_order_by("-x, +y,z:nulls_last,-id:nulls_first")?;
+        assert_eq!(
+            fields,
+            vec![
+                ("x".to_string(), FieldOrder::desc()),
+                ("y".to_string(), FieldOrder::asc()),
+                ("z".to_string(), FieldOrder::asc().nulls_last()),
+                ("id".to_string(), FieldOrder::desc().nulls_first()),
+            ]
+        );
+        let names = fields.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+        let order_by = TestOrderBy::build(names)?;
+        assert_eq!(order_by.get_field_order(0), FieldOrder::asc());
+
+        assert!(parse_order_by("-").is_err());
+        assert!(parse_order_by("x,,y").is_err());
+        assert!(parse_order_by("x:nulls").is_err());
+        assert_eq!(FieldOrder::desc().nulls_first().reverse(), FieldOrder::asc().nulls_last());
+        Ok(())
+    }
 }
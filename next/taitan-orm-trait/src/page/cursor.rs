@@ -0,0 +1,334 @@
+use crate::{NotValidCursorError, OrderBy};
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use sqlx::types::{BigDecimal, Uuid};
+use std::str::FromStr;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/**
+value of one order by field in cursor, typed value is restored by the selected entity when binding
+date time is stored as unix nanos in utc, so the cursor does not depend on formatting features,
+the nanos are kept in Text as they are i128, i64 nanos only cover the years 1677 to 2262
+*/
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CursorValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+pub trait CursorCodec: Sized {
+    fn to_cursor_value(&self) -> CursorValue;
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CursorDirection {
+    Next, // records after the cursor
+    Prev, // records before the cursor
+}
+
+/**
+opaque cursor of keyset pagination, encoded as hex of json to keep it url safe
+*/
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+    pub direction: CursorDirection,
+    pub values: Vec<CursorValue>,
+}
+
+impl Cursor {
+    pub fn new(direction: CursorDirection, values: Vec<CursorValue>) -> Self {
+        Self { direction, values }
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        json.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, NotValidCursorError> {
+        let invalid = || NotValidCursorError(encoded.to_string());
+        if encoded.len() % 2 != 0 || !encoded.is_ascii() {
+            return Err(invalid());
+        }
+        let bytes = (0..encoded.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&encoded[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| invalid())?;
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    }
+}
+
+/**
+indexes of order by fields bound by the seek predicate of keyset pagination
+1. all fields share one direction: (a,b) > (?,?), each field is bound once
+2. mixed directions: (a > ?) OR (a = ? AND b < ?), fields before the compared one repeat
+*/
+pub fn get_seek_indexes(order_by: &dyn OrderBy) -> Vec<usize> {
+    let len = order_by.get_fields().len();
+    if is_seek_uniform(order_by) {
+        return (0..len).collect();
+    }
+    (0..len).flat_map(|k| 0..=k).collect()
+}
+
+pub fn is_seek_uniform(order_by: &dyn OrderBy) -> bool {
+    let len = order_by.get_fields().len();
+    let first = order_by.get_field_order(0).direction;
+    (0..len).all(|i| order_by.get_field_order(i).direction == first)
+}
+
+impl Cursor {
+    // fields and values in the binding order of seek predicate
+    pub fn get_seek_arguments<'a>(
+        &self,
+        order_by: &'a dyn OrderBy,
+    ) -> Result<(Vec<Cow<'a, str>>, Vec<CursorValue>), NotValidCursorError> {
+        let fields = order_by.get_fields();
+        if fields.len() != self.values.len() {
+            return Err(NotValidCursorError("cursor does not match order by fields".to_string()));
+        }
+        let indexes = get_seek_indexes(order_by);
+        let seek_fields = indexes.iter().map(|i| fields[*i].clone()).collect();
+        let seek_values = indexes.iter().map(|i| self.values[*i].clone()).collect();
+        Ok((seek_fields, seek_values))
+    }
+}
+
+fn mismatch(value: &CursorValue, expect: &str) -> NotValidCursorError {
+    NotValidCursorError(format!("{:?} is not {}", value, expect))
+}
+
+macro_rules! int_cursor_codec {
+    ($($t:ty),*) => {
+        $(
+            impl CursorCodec for $t {
+                fn to_cursor_value(&self) -> CursorValue {
+                    CursorValue::Int(*self as i64)
+                }
+
+                fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+                    match value {
+                        CursorValue::Int(v) => <$t>::try_from(*v).map_err(|_| mismatch(value, stringify!($t))),
+                        _ => Err(mismatch(value, stringify!($t))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+int_cursor_codec!(i8, i16, i32, i64, u8, u16, u32);
+
+// u64 above i64::MAX is kept in Text
+impl CursorCodec for u64 {
+    fn to_cursor_value(&self) -> CursorValue {
+        match i64::try_from(*self) {
+            Ok(v) => CursorValue::Int(v),
+            Err(_) => CursorValue::Text(self.to_string()),
+        }
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        match value {
+            CursorValue::Int(v) => u64::try_from(*v).map_err(|_| mismatch(value, "u64")),
+            CursorValue::Text(v) => u64::from_str(v).map_err(|_| mismatch(value, "u64")),
+            _ => Err(mismatch(value, "u64")),
+        }
+    }
+}
+
+impl CursorCodec for f32 {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Float(*self as f64)
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        match value {
+            CursorValue::Float(v) => Ok(*v as f32),
+            _ => Err(mismatch(value, "f32")),
+        }
+    }
+}
+
+impl CursorCodec for f64 {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Float(*self)
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        match value {
+            CursorValue::Float(v) => Ok(*v),
+            _ => Err(mismatch(value, "f64")),
+        }
+    }
+}
+
+impl CursorCodec for bool {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Bool(*self)
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        match value {
+            CursorValue::Bool(v) => Ok(*v),
+            _ => Err(mismatch(value, "bool")),
+        }
+    }
+}
+
+impl CursorCodec for String {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Text(self.clone())
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        match value {
+            CursorValue::Text(v) => Ok(v.clone()),
+            _ => Err(mismatch(value, "String")),
+        }
+    }
+}
+
+impl CursorCodec for Vec<u8> {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Bytes(self.clone())
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        match value {
+            CursorValue::Bytes(v) => Ok(v.clone()),
+            _ => Err(mismatch(value, "Vec<u8>")),
+        }
+    }
+}
+
+impl CursorCodec for Uuid {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Bytes(self.as_bytes().to_vec())
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        match value {
+            CursorValue::Bytes(v) => Uuid::from_slice(v).map_err(|_| mismatch(value, "Uuid")),
+            _ => Err(mismatch(value, "Uuid")),
+        }
+    }
+}
+
+impl CursorCodec for BigDecimal {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Text(self.to_string())
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        match value {
+            CursorValue::Text(v) => BigDecimal::from_str(v).map_err(|_| mismatch(value, "BigDecimal")),
+            _ => Err(mismatch(value, "BigDecimal")),
+        }
+    }
+}
+
+impl CursorCodec for OffsetDateTime {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Text(self.unix_timestamp_nanos().to_string())
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        let nanos = match value {
+            CursorValue::Text(v) => i128::from_str(v).map_err(|_| mismatch(value, "OffsetDateTime"))?,
+            _ => return Err(mismatch(value, "OffsetDateTime")),
+        };
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| mismatch(value, "OffsetDateTime"))
+    }
+}
+
+impl CursorCodec for PrimitiveDateTime {
+    fn to_cursor_value(&self) -> CursorValue {
+        self.assume_utc().to_cursor_value()
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        let date_time = OffsetDateTime::from_cursor_value(value)?.to_offset(UtcOffset::UTC);
+        Ok(PrimitiveDateTime::new(date_time.date(), date_time.time()))
+    }
+}
+
+impl CursorCodec for Date {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Int(self.to_julian_day() as i64)
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        let julian_day = i32::from_cursor_value(value)?;
+        Date::from_julian_day(julian_day).map_err(|_| mismatch(value, "Date"))
+    }
+}
+
+impl CursorCodec for Time {
+    fn to_cursor_value(&self) -> CursorValue {
+        CursorValue::Int((*self - Time::MIDNIGHT).whole_nanoseconds() as i64)
+    }
+
+    fn from_cursor_value(value: &CursorValue) -> Result<Self, NotValidCursorError> {
+        let nanos = i64::from_cursor_value(value)?;
+        Ok(Time::MIDNIGHT + Duration::nanoseconds(nanos))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    pub fn test_cursor_encode_decode() -> Result<(), NotValidCursorError> {
+        let date = Date::from_calendar_date(2019, Month::January, 1).unwrap();
+        let date_time = PrimitiveDateTime::new(date, Time::from_hms(12, 30, 0).unwrap());
+        let cursor = Cursor::new(
+            CursorDirection::Next,
+            vec![
+                date_time.to_cursor_value(),
+                "Allen".to_string().to_cursor_value(),
+                Uuid::nil().to_cursor_value(),
+                23i32.to_cursor_value(),
+            ],
+        );
+        let encoded = cursor.encode();
+        assert!(encoded.chars().all(|c| c.is_ascii_hexdigit()));
+        let decoded = Cursor::decode(&encoded)?;
+        assert_eq!(decoded, cursor);
+        assert_eq!(
+            PrimitiveDateTime::from_cursor_value(&decoded.values[0])?,
+            date_time
+        );
+        assert_eq!(Uuid::from_cursor_value(&decoded.values[2])?, Uuid::nil());
+        assert_eq!(i32::from_cursor_value(&decoded.values[3])?, 23);
+
+        // out of the range of i64 nanos
+        let far_date_time = PrimitiveDateTime::new(
+            Date::from_calendar_date(9999, Month::December, 31).unwrap(),
+            Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(),
+        );
+        let encoded = Cursor::new(CursorDirection::Prev, vec![far_date_time.to_cursor_value()]).encode();
+        let decoded = Cursor::decode(&encoded)?;
+        assert_eq!(PrimitiveDateTime::from_cursor_value(&decoded.values[0])?, far_date_time);
+        let early_date_time = far_date_time.replace_year(1000).unwrap().assume_utc();
+        assert_eq!(OffsetDateTime::from_cursor_value(&early_date_time.to_cursor_value())?, early_date_time);
+
+        // u64 beyond i64 is kept in Text
+        assert_eq!(u64::from_cursor_value(&7u64.to_cursor_value())?, 7);
+        assert_eq!(u64::MAX.to_cursor_value(), CursorValue::Text(u64::MAX.to_string()));
+        assert_eq!(u64::from_cursor_value(&u64::MAX.to_cursor_value())?, u64::MAX);
+        assert!(u64::from_cursor_value(&CursorValue::Int(-1)).is_err());
+
+        assert!(Cursor::decode("not a cursor").is_err());
+        assert!(i32::from_cursor_value(&CursorValue::Text("a".to_string())).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::page::cursor::{Cursor, CursorDirection};
+use crate::{NotValidCursorError, OrderBy, SelectedEntity};
+use sqlx::Database;
+use std::marker::PhantomData;
+
+/**
+page of keyset pagination, no total count is queried
+next_cursor is None when there is no more record after this page,
+prev_cursor is None when this page is the first one
+*/
+#[derive(Clone, Debug)]
+pub struct CursorPagedList<DB, T>
+where
+    DB: Database,
+    T: SelectedEntity<DB>,
+{
+    pub data: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+    pub _phantom: PhantomData<DB>,
+}
+
+impl<DB, T> CursorPagedList<DB, T>
+where
+    DB: Database,
+    T: SelectedEntity<DB>,
+{
+    pub fn empty() -> Self {
+        Self {
+            data: Vec::new(),
+            next_cursor: None,
+            prev_cursor: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/**
+data is fetched with limit page_size + 1 in the order of direction,
+the extra record means there are more records in that direction
+*/
+pub fn build_cursor_paged_list<DB: Database, SE>(
+    mut data: Vec<SE>,
+    order_by: &dyn OrderBy,
+    page_size: u64,
+    direction: Option<CursorDirection>,
+) -> Result<CursorPagedList<DB, SE>, NotValidCursorError>
+where
+    SE: SelectedEntity<DB> + Send + Unpin,
+{
+    let has_more = data.len() as u64 > page_size;
+    data.truncate(page_size as usize);
+    if direction == Some(CursorDirection::Prev) {
+        data.reverse();
+    }
+
+    let fields = order_by.get_fields();
+    let build_cursor = |entity: Option<&SE>, direction: CursorDirection| match entity {
+        None => Ok(None),
+        Some(entity) => {
+            let values = entity.get_cursor_values(fields)?;
+            Ok::<_, NotValidCursorError>(Some(Cursor::new(direction, values).encode()))
+        }
+    };
+    let (has_next, has_prev) = match direction {
+        None => (has_more, false),
+        Some(CursorDirection::Next) => (has_more, true),
+        Some(CursorDirection::Prev) => (true, has_more),
+    };
+    let next_cursor = if has_next {
+        build_cursor(data.last(), CursorDirection::Next)?
+    } else {
+        None
+    };
+    let prev_cursor = if has_prev {
+        build_cursor(data.first(), CursorDirection::Prev)?
+    } else {
+        None
+    };
+
+    Ok(CursorPagedList {
+        data,
+        next_cursor,
+        prev_cursor,
+        _phantom: PhantomData,
+    })
+}
@@ -0,0 +1,35 @@
+use crate::page::cursor::Cursor;
+use crate::NotValidCursorError;
+
+/**
+keyset pagination, seek by the order by fields of the last(or first) record instead of offset
+cursor is None for the first page, otherwise the next_cursor or prev_cursor returned by previous page
+*/
+#[derive(Clone, Debug, Default)]
+pub struct CursorPagination {
+    pub page_size: u64,
+    pub cursor: Option<String>,
+}
+
+impl CursorPagination {
+    pub fn first(page_size: u64) -> Self {
+        Self {
+            page_size,
+            cursor: None,
+        }
+    }
+
+    pub fn new(page_size: u64, cursor: impl Into<String>) -> Self {
+        Self {
+            page_size,
+            cursor: Some(cursor.into()),
+        }
+    }
+
+    pub fn decode_cursor(&self) -> Result<Option<Cursor>, NotValidCursorError> {
+        match &self.cursor {
+            None => Ok(None),
+            Some(cursor) => Ok(Some(Cursor::decode(cursor)?)),
+        }
+    }
+}
@@ -3,3 +3,6 @@ pub mod paged_list;
 
 pub mod count_sql;
 pub mod pagination;
+pub mod cursor;
+pub mod cursor_pagination;
+pub mod cursor_paged_list;
@@ -1,14 +1,13 @@
 use crate::paged_info::PagedInfo;
-use crate::SelectedEntity;
 use sqlx::Database;
 use std::marker::PhantomData;
 use crate::pagination::Pagination;
 
+// T is usually a SelectedEntity, or a tuple of them in joined search
 #[derive(Clone, Debug)]
 pub struct PagedList<DB, T>
 where
     DB: Database,
-    T: SelectedEntity<DB>,
 {
     pub data: Vec<T>,
     pub page: PagedInfo,
@@ -18,7 +17,6 @@ where
 impl<DB, T> PagedList<DB, T>
 where
     DB: Database,
-    T: SelectedEntity<DB>,
 {
     pub fn empty(page_size: u64, page_num: u64) -> Self {
         Self {
@@ -35,7 +33,7 @@ pub fn build_paged_list<DB: Database, SE>(
     page: &Pagination,
 ) -> PagedList<DB, SE>
 where
-    SE: SelectedEntity<DB> + Send + Unpin,
+    SE: Send + Unpin,
 {
     let page_info = PagedInfo {
         page_size: page.page_size,
@@ -0,0 +1,101 @@
+use crate::Optional;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/**
+key of a relationship side, generated loaders read keys of parents and children through it.
+a key which is not set (or not selected) relates to nothing
+*/
+pub trait RelationKey<K> {
+    fn relation_key(&self) -> Option<&K>;
+}
+
+impl<K> RelationKey<K> for Optional<K> {
+    fn relation_key(&self) -> Option<&K> {
+        match self {
+            Optional::Some(key) => Some(key),
+            _ => None,
+        }
+    }
+}
+
+impl<K> RelationKey<K> for Option<K> {
+    fn relation_key(&self) -> Option<&K> {
+        self.as_ref()
+    }
+}
+
+// distinct keys of parents, in the order of parents, used as the IN list of the batched query
+pub fn collect_relation_keys<K: Eq + Hash + Clone>(parent_keys: &[Option<&K>]) -> Vec<K> {
+    let mut seen: HashSet<&K> = HashSet::new();
+    parent_keys
+        .iter()
+        .flatten()
+        .filter(|key| seen.insert(**key))
+        .map(|key| (*key).clone())
+        .collect()
+}
+
+// children of every parent, in the order of parents
+pub fn group_many<K, C, F>(parent_keys: &[Option<&K>], children: &[C], child_key: F) -> Vec<Vec<C>>
+where
+    K: Eq + Hash,
+    C: Clone,
+    F: Fn(&C) -> Option<&K>,
+{
+    let mut children_of: HashMap<&K, Vec<&C>> = HashMap::new();
+    for child in children {
+        if let Some(key) = child_key(child) {
+            children_of.entry(key).or_default().push(child);
+        }
+    }
+    parent_keys
+        .iter()
+        .map(|parent_key| match parent_key.and_then(|parent_key| children_of.get(parent_key)) {
+            Some(matched) => matched.iter().map(|child| (*child).clone()).collect(),
+            None => Vec::new(),
+        })
+        .collect()
+}
+
+// the first matched child of every parent, in the order of parents
+pub fn group_one<K, C, F>(parent_keys: &[Option<&K>], children: &[C], child_key: F) -> Vec<Option<C>>
+where
+    K: Eq + Hash,
+    C: Clone,
+    F: Fn(&C) -> Option<&K>,
+{
+    let mut child_of: HashMap<&K, &C> = HashMap::new();
+    for child in children {
+        if let Some(key) = child_key(child) {
+            child_of.entry(key).or_insert(child);
+        }
+    }
+    parent_keys
+        .iter()
+        .map(|parent_key| {
+            parent_key
+                .and_then(|parent_key| child_of.get(parent_key))
+                .map(|child| (*child).clone())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_group_relation() {
+        let parents = [Optional::Some(1), Optional::None, Optional::Some(2), Optional::Some(1)];
+        let parent_keys: Vec<Option<&i64>> = parents.iter().map(|p| p.relation_key()).collect();
+        assert_eq!(collect_relation_keys(&parent_keys), vec![1, 2]);
+
+        let children = [(1, "a"), (3, "b"), (1, "c")];
+        let grouped = group_many(&parent_keys, &children, |child| Some(&child.0));
+        assert_eq!(grouped, vec![vec![(1, "a"), (1, "c")], vec![], vec![], vec![(1, "a"), (1, "c")]]);
+
+        let grouped = group_one(&parent_keys, &children, |child| Some(&child.0));
+        assert_eq!(grouped, vec![Some((1, "a")), None, None, Some((1, "a"))]);
+    }
+}
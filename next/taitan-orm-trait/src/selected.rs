@@ -1,5 +1,7 @@
 use crate::selection::Selection;
-use crate::NotImplementError;
+use crate::{CursorValue, NotImplementError, NotValidCursorError};
+use sqlx::error::BoxDynError;
+use std::borrow::Cow;
 use sqlx::types::Uuid;
 use sqlx::{ColumnIndex, Database, Decode, Type};
 use std::fmt::Debug;
@@ -33,6 +35,33 @@ pub trait SelectedEntity<DB: Database>: Debug + Default {
             NotImplementError("".to_string()).into(),
         ))
     }
+
+    // same as select_from_row, but columns start at offset, offset is moved after the selected columns
+    fn select_from_row_at(selection: &Self, row: &DB::Row, offset: &mut usize) -> Result<Self, sqlx::Error>
+    where
+        Self: Sized,
+    {
+        Err(sqlx::Error::Decode(
+            NotImplementError("select_from_row_at".to_string()).into(),
+        ))
+    }
+
+    // values of order by fields, used to build the cursor of keyset pagination
+    fn get_cursor_values(&self, fields: &[Cow<'_, str>]) -> Result<Vec<CursorValue>, NotValidCursorError> {
+        Err(NotValidCursorError("get_cursor_values is not implemented".to_string()))
+    }
+
+    // bind cursor values as the typed value of order by fields
+    fn add_cursor_arguments<'a>(
+        fields: &[Cow<'_, str>],
+        values: &[CursorValue],
+        args: &mut DB::Arguments<'a>,
+    ) -> Result<(), BoxDynError>
+    where
+        Self: Sized,
+    {
+        Err(NotImplementError("add_cursor_arguments".to_string()).into())
+    }
 }
 
 pub trait SelectedEntityNew: Debug + Default {
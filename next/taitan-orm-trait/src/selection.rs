@@ -1,3 +1,4 @@
+use crate::SoftDelete;
 use std::fmt::Debug;
 
 pub trait Selection: Sync + Debug {
@@ -5,6 +6,10 @@ pub trait Selection: Sync + Debug {
 
     fn get_selected_fields(&self) -> Vec<String>;
 
+    fn get_soft_delete(&self) -> Option<SoftDelete> {
+        None
+    }
+
     fn get_selected_bits(&self) -> bit_vec::BitVec {
         todo!()
     }
@@ -0,0 +1,90 @@
+/**
+column annotated by #[soft_delete], deleted rows are kept in the table and filtered out by reads
+1. Flag: bool column, deleted rows are marked as TRUE, NULL is treated as not deleted
+2. Timestamp: nullable time column, deleted rows are marked with CURRENT_TIMESTAMP
+*/
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SoftDelete {
+    Flag(&'static str),
+    Timestamp(&'static str),
+}
+
+/**
+which rows are visible to a read on a table with #[soft_delete]
+Alive is the default of select/search/devour/count/exists, WithDeleted/OnlyDeleted are explicit variants
+*/
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DeletedScope {
+    #[default]
+    Alive,
+    WithDeleted,
+    OnlyDeleted,
+}
+
+impl SoftDelete {
+    pub fn get_field_name(&self) -> &'static str {
+        match self {
+            SoftDelete::Flag(field_name) | SoftDelete::Timestamp(field_name) => field_name,
+        }
+    }
+
+    // `deleted` = TRUE, `deleted_at` = CURRENT_TIMESTAMP
+    pub fn get_delete_set_sql(&self, wrap_char: char) -> String {
+        let field = format!("{}{}{}", wrap_char, self.get_field_name(), wrap_char);
+        match self {
+            SoftDelete::Flag(_) => format!("{} = TRUE", field),
+            SoftDelete::Timestamp(_) => format!("{} = CURRENT_TIMESTAMP", field),
+        }
+    }
+
+    // condition of rows visible in scope, None if all rows are visible
+    pub fn get_scope_sql(&self, scope: DeletedScope, wrap_char: char) -> Option<String> {
+        let field = format!("{}{}{}", wrap_char, self.get_field_name(), wrap_char);
+        self.render_scope_sql(field, scope)
+    }
+
+    // `table`.`deleted` IS NOT TRUE, condition of the table in joined reads
+    pub fn get_qualified_scope_sql(&self, table_name: &str, scope: DeletedScope, wrap_char: char) -> Option<String> {
+        let field = format!(
+            "{}{}{}.{}{}{}",
+            wrap_char, table_name, wrap_char, wrap_char, self.get_field_name(), wrap_char
+        );
+        self.render_scope_sql(field, scope)
+    }
+
+    fn render_scope_sql(&self, field: String, scope: DeletedScope) -> Option<String> {
+        match (self, scope) {
+            (_, DeletedScope::WithDeleted) => None,
+            (SoftDelete::Flag(_), DeletedScope::Alive) => Some(format!("{} IS NOT TRUE", field)),
+            (SoftDelete::Flag(_), DeletedScope::OnlyDeleted) => Some(format!("{} IS TRUE", field)),
+            (SoftDelete::Timestamp(_), DeletedScope::Alive) => Some(format!("{} IS NULL", field)),
+            (SoftDelete::Timestamp(_), DeletedScope::OnlyDeleted) => Some(format!("{} IS NOT NULL", field)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DeletedScope, SoftDelete};
+
+    #[test]
+    pub fn test_soft_delete() {
+        let flag = SoftDelete::Flag("deleted");
+        assert_eq!(flag.get_delete_set_sql('`'), "`deleted` = TRUE");
+        assert_eq!(flag.get_scope_sql(DeletedScope::Alive, '`'), Some("`deleted` IS NOT TRUE".to_string()));
+        assert_eq!(flag.get_scope_sql(DeletedScope::OnlyDeleted, '`'), Some("`deleted` IS TRUE".to_string()));
+        assert_eq!(flag.get_scope_sql(DeletedScope::WithDeleted, '`'), None);
+        assert_eq!(
+            flag.get_qualified_scope_sql("member", DeletedScope::Alive, '`'),
+            Some("`member`.`deleted` IS NOT TRUE".to_string())
+        );
+
+        let timestamp = SoftDelete::Timestamp("deleted_at");
+        assert_eq!(timestamp.get_delete_set_sql('"'), "\"deleted_at\" = CURRENT_TIMESTAMP");
+        assert_eq!(timestamp.get_scope_sql(DeletedScope::Alive, '"'), Some("\"deleted_at\" IS NULL".to_string()));
+        assert_eq!(
+            timestamp.get_scope_sql(DeletedScope::OnlyDeleted, '"'),
+            Some("\"deleted_at\" IS NOT NULL".to_string())
+        );
+    }
+}
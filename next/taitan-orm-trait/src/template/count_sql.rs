@@ -0,0 +1,131 @@
+use crate::template::parser::{ends_with_line_comment, parse_template_sql, StringEscape};
+use crate::template::template_argument::BlockTag;
+use crate::template::template_value::TemplateValue;
+use crate::NotValidTemplateError;
+
+/**
+derive count sql of a select template, when #[count_sql] is absent:
+SELECT COUNT(*) AS count FROM (template sql without ORDER BY/LIMIT/OFFSET tail) AS taitan_count
+
+1. the tail starts from the first ORDER BY, LIMIT or OFFSET outside of parentheses and control blocks,
+   ORDER BY inside control blocks is kept, which is still valid in a sub query
+2. strings, comments and variables are kept as they are, so keywords inside them are not the tail
+3. template not starting with SELECT or WITH has no count sql
+*/
+pub fn derive_count_sql(template_sql: &str, escape: StringEscape) -> Result<Option<String>, NotValidTemplateError> {
+    let values = parse_template_sql(template_sql.trim(), escape)?;
+    if !is_select(&values) {
+        return Ok(None);
+    }
+    let mut paren_depth: i32 = 0;
+    let mut block_depth: i32 = 0;
+    let mut kept = String::new();
+    for value in values.iter() {
+        match value {
+            TemplateValue::Block(block) => {
+                match BlockTag::parse(block).map_err(NotValidTemplateError)? {
+                    BlockTag::If(_) | BlockTag::For(_, _) => block_depth += 1,
+                    BlockTag::EndIf | BlockTag::EndFor => block_depth -= 1,
+                    _ => {}
+                }
+                kept.push_str(block);
+            }
+            TemplateValue::Segment(segment) => {
+                let tail = find_tail(segment, &mut paren_depth);
+                match tail {
+                    Some(pos) if block_depth == 0 => {
+                        kept.push_str(&segment[..pos]);
+                        break;
+                    }
+                    _ => kept.push_str(segment),
+                }
+            }
+            _ => kept.push_str(&value.to_template_string()),
+        }
+    }
+    let mut kept = kept.trim_end().to_string();
+    if ends_with_line_comment(&kept, escape)? {
+        kept.push('\n');
+    }
+    Ok(Some(format!("SELECT COUNT(*) AS count FROM ({}) AS taitan_count", kept)))
+}
+
+fn is_select(values: &[TemplateValue]) -> bool {
+    let first_segment = values.iter().find_map(|value| match value {
+        TemplateValue::Comment(_) => None,
+        TemplateValue::Segment(segment) if segment.trim().is_empty() => None,
+        TemplateValue::Segment(segment) => Some(segment.as_str()),
+        _ => Some(""),
+    });
+    let first_word = first_segment
+        .unwrap_or_default()
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .find(|word| !word.is_empty())
+        .unwrap_or_default();
+    first_word.eq_ignore_ascii_case("SELECT") || first_word.eq_ignore_ascii_case("WITH")
+}
+
+// byte position of the first ORDER BY/LIMIT/OFFSET keyword at parentheses depth 0, depth is carried across segments
+fn find_tail(segment: &str, paren_depth: &mut i32) -> Option<usize> {
+    let mut tail: Option<usize> = None;
+    let mut word_start: Option<usize> = None;
+    let mut words: Vec<(usize, &str)> = Vec::new();
+    for (i, c) in segment.char_indices().chain(std::iter::once((segment.len(), ' '))) {
+        if c.is_alphanumeric() || c == '_' {
+            word_start.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = word_start.take() {
+            if *paren_depth == 0 {
+                words.push((start, &segment[start..i]));
+            }
+        }
+        match c {
+            '(' => *paren_depth += 1,
+            ')' => *paren_depth -= 1,
+            _ => {}
+        }
+        if tail.is_none() {
+            tail = match words.as_slice() {
+                [.., (start, order), (_, by)] if order.eq_ignore_ascii_case("ORDER") && by.eq_ignore_ascii_case("BY") => {
+                    Some(*start)
+                }
+                [.., (start, word)] if word.eq_ignore_ascii_case("LIMIT") || word.eq_ignore_ascii_case("OFFSET") => {
+                    Some(*start)
+                }
+                _ => None,
+            };
+        }
+    }
+    tail
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_derive_count_sql() {
+        let sql = "SELECT `id`, (SELECT MAX(`age`) FROM `pet` ORDER BY `age` LIMIT 1) AS m FROM `pet` \
+            WHERE `name` != 'order by' AND `age` > #{age} -- limit\n ORDER BY `id` LIMIT #{page.offset}, #{page.count}";
+        assert_eq!(
+            derive_count_sql(sql, StringEscape::Standard).unwrap().unwrap(),
+            "SELECT COUNT(*) AS count FROM (SELECT `id`, (SELECT MAX(`age`) FROM `pet` ORDER BY `age` LIMIT 1) AS m FROM `pet` \
+            WHERE `name` != 'order by' AND `age` > #{age} -- limit\n) AS taitan_count"
+        );
+
+        let sql = "select * from `pet` where 1 = 1 {% if a %} and `a` = #{a} order by `a` {% else %} order by `id` {% endif %} limit 10";
+        assert_eq!(
+            derive_count_sql(sql, StringEscape::Standard).unwrap().unwrap(),
+            "SELECT COUNT(*) AS count FROM (select * from `pet` where 1 = 1 {% if a %} and `a` = #{a} order by `a` {% else %} order by `id` {% endif %}) AS taitan_count"
+        );
+
+        let sql = "-- report\nWITH t AS (SELECT * FROM `pet`) SELECT * FROM t";
+        assert_eq!(
+            derive_count_sql(sql, StringEscape::Standard).unwrap().unwrap(),
+            "SELECT COUNT(*) AS count FROM (-- report\nWITH t AS (SELECT * FROM `pet`) SELECT * FROM t) AS taitan_count"
+        );
+
+        assert_eq!(derive_count_sql("UPDATE `pet` SET `age` = #{age}", StringEscape::Standard).unwrap(), None);
+    }
+}
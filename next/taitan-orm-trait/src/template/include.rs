@@ -0,0 +1,120 @@
+use crate::template::parser::{ends_with_line_comment, parse_template_sql, StringEscape};
+use crate::template::template_value::TemplateValue;
+use crate::NotValidTemplateError;
+
+/**
+replace {% include "path/to/fragment.sql" %} with the content of the fragment, recursively.
+the fragment is loaded by the caller, so the macro decides how the path is resolved,
+fragment including itself directly or indirectly is an error.
+
+a fragment ending with a -- line comment keeps its line break, so the comment does not swallow the sql after it
+*/
+pub fn resolve_template_includes<F>(
+    template_sql: &str,
+    escape: StringEscape,
+    load: &mut F,
+) -> Result<String, NotValidTemplateError>
+where
+    F: FnMut(&str) -> Result<String, NotValidTemplateError>,
+{
+    let mut including: Vec<String> = Vec::new();
+    resolve_includes(template_sql, escape, load, &mut including)
+}
+
+fn resolve_includes<F>(
+    template_sql: &str,
+    escape: StringEscape,
+    load: &mut F,
+    including: &mut Vec<String>,
+) -> Result<String, NotValidTemplateError>
+where
+    F: FnMut(&str) -> Result<String, NotValidTemplateError>,
+{
+    let values = parse_template_sql(template_sql, escape)?;
+    let mut resolved = String::new();
+    for value in values {
+        let path = match &value {
+            TemplateValue::Block(block) => parse_include_path(block)?,
+            _ => None,
+        };
+        let Some(path) = path else {
+            resolved.push_str(&value.to_template_string());
+            continue;
+        };
+        if including.contains(&path) {
+            return Err(NotValidTemplateError(format!(
+                "{} includes itself: {} -> {}",
+                path,
+                including.join(" -> "),
+                path
+            )));
+        }
+        including.push(path.clone());
+        let fragment = load(&path)?;
+        let fragment = resolve_includes(fragment.trim(), escape, load, including)?;
+        including.pop();
+        resolved.push_str(&fragment);
+        if ends_with_line_comment(&fragment, escape)? {
+            resolved.push('\n');
+        }
+    }
+    Ok(resolved)
+}
+
+// {% include "path" %}, whitespace control chars -, + and ~ are ignored, the text around is kept as it is
+fn parse_include_path(block: &str) -> Result<Option<String>, NotValidTemplateError> {
+    let content = block
+        .trim_start_matches("{%")
+        .trim_end_matches("%}")
+        .trim_matches(|c: char| c == '-' || c == '+' || c == '~')
+        .trim();
+    let Some(path) = content.strip_prefix("include") else {
+        return Ok(None);
+    };
+    if !path.starts_with(char::is_whitespace) {
+        return Ok(None);
+    }
+    let path = path.trim();
+    let unquoted = path
+        .strip_prefix('"')
+        .and_then(|path| path.strip_suffix('"'))
+        .filter(|path| !path.is_empty() && !path.contains('"'));
+    match unquoted {
+        Some(path) => Ok(Some(path.to_string())),
+        None => Err(NotValidTemplateError(format!(
+            "{}, the included path should be quoted, for example: {{% include \"where_active.sql\" %}}",
+            block
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn load_fragment(path: &str) -> Result<String, NotValidTemplateError> {
+        match path {
+            "active.sql" => Ok("`deleted` = 0 AND `age` >= #{min_age} -- active only\n".to_string()),
+            "named.sql" => Ok("`name` = #{name} AND {% include \"active.sql\" %}".to_string()),
+            "loop.sql" => Ok("{% include \"loop.sql\" %}".to_string()),
+            _ => Err(NotValidTemplateError(format!("{} not found", path))),
+        }
+    }
+
+    #[test]
+    pub fn test_resolve_includes() {
+        let sql = "SELECT * FROM `user` WHERE {%- include \"named.sql\" -%} ORDER BY `id` -- '{% include \"no.sql\" %}'";
+        let resolved = resolve_template_includes(sql, StringEscape::Standard, &mut load_fragment).unwrap();
+        assert_eq!(
+            resolved,
+            "SELECT * FROM `user` WHERE `name` = #{name} AND `deleted` = 0 AND `age` >= #{min_age} -- active only\n ORDER BY `id` -- '{% include \"no.sql\" %}'"
+        );
+
+        let sql = "SELECT * FROM `user` WHERE {% include \"loop.sql\" %}";
+        assert!(resolve_template_includes(sql, StringEscape::Standard, &mut load_fragment).is_err());
+        let sql = "SELECT * FROM `user` WHERE {% include \"missing.sql\" %}";
+        assert!(resolve_template_includes(sql, StringEscape::Standard, &mut load_fragment).is_err());
+        let sql = "SELECT * FROM `user` WHERE {% include active.sql %}";
+        assert!(resolve_template_includes(sql, StringEscape::Standard, &mut load_fragment).is_err());
+    }
+}
@@ -1,6 +1,15 @@
 mod parser;
 mod template_value;
 mod parsed_template_sql;
+mod template_argument;
+mod include;
+mod raw_ident;
+mod count_sql;
 
-pub use parsed_template_sql::ParsedTemplateSql;
-pub use template_value::TemplateValue;
\ No newline at end of file
+pub use parsed_template_sql::{list_place_holders, ParsedTemplateSql};
+pub use template_value::TemplateValue;
+pub use template_argument::TemplateArgument;
+pub use include::resolve_template_includes;
+pub use raw_ident::{keyword_raw_ident, quote_raw_ident};
+pub use count_sql::derive_count_sql;
+pub use parser::{replace_place_holders, StringEscape};
@@ -1,33 +1,73 @@
-use nom::error::ErrorKind::NonEmpty;
-use crate::template::parser::parse_template_sql;
+use crate::template::parser::{parse_template_sql, StringEscape};
+use crate::template::template_argument::{BlockTag, TemplateArgument};
 use crate::template::template_value::{InnerString, TemplateValue};
+use crate::NotValidTemplateError;
 
 #[derive(Debug, Clone)]
 pub struct ParsedTemplateSql {
     pub sql: String,
     pub variables: Vec<String>,
     pub dollar_signs: Vec<String>,
+    pub arguments: Vec<TemplateArgument>,
+    pub has_block: bool,
+    pub has_list: bool,
+}
+
+/**
+placeholders of #{field} bound to a list, one per element, the list must not be empty
+*/
+pub fn list_place_holders(name: &str, len: usize) -> Result<String, String> {
+    if len == 0 {
+        return Err(format!("#{{{}}} bound to an empty list", name));
+    }
+    Ok(vec!["?"; len].join(","))
 }
 
 impl ParsedTemplateSql {
-    pub fn build(template_sql: &str) -> Result<Self, nom::Err<nom::error::Error<&str>>> {
+    /**
+    ${field} is not rendered from the field directly, the value is validated and quoted before rendering,
+    and put into this field of the template struct, in the order of dollar_signs
+    */
+    pub const RAW_IDENTS: &'static str = "taitan_raw_idents";
+
+    pub fn build(template_sql: &str) -> Result<Self, NotValidTemplateError> {
+        Self::build_with_list_fields(template_sql, &[], StringEscape::Standard)
+    }
+
+    /**
+    #{field} of list fields, such as ids: Vec<i64>, is expanded to one placeholder per element when rendering,
+    an empty list can not be rendered, `IN ()` is not valid sql and NULL would make `NOT IN (NULL)` locate nothing,
+    so rendering fails with NotValidTemplateError, guard the list by {% if !ids.is_empty() %} when it may be empty.
+    a loop variable of the same name shadows the list field, and is bound as a single value
+    */
+    pub fn build_with_list_fields(
+        template_sql: &str,
+        list_fields: &[String],
+        escape: StringEscape,
+    ) -> Result<Self, NotValidTemplateError> {
         let trimmed_template_sql = template_sql.trim();
         if trimmed_template_sql.is_empty() {
-            return Err(nom::Err::Error(nom::error::Error::new(template_sql, NonEmpty)));
+            return Err(NotValidTemplateError("template sql is empty".to_string()));
+        }
+        let parsed = parse_template_sql(trimmed_template_sql, escape)?;
+        if parsed.iter().any(Self::has_question_mark) {
+            return Err(NotValidTemplateError(format!("{}, use #{{}} instead of ?", trimmed_template_sql)));
         }
-        let (_, parsed) = parse_template_sql(trimmed_template_sql)?;
-        let parsed_template = ParsedTemplateSql::new(parsed);
+        TemplateArgument::build(&parsed).map_err(NotValidTemplateError)?;
+        let parsed_template = ParsedTemplateSql::new_with_list_fields(parsed, list_fields);
         Ok(parsed_template)
     }
 
     pub fn need_render(&self) -> bool {
-        !self.dollar_signs.is_empty()
+        !self.dollar_signs.is_empty() || self.has_block || self.has_list
     }
 
     pub fn new(values: Vec<TemplateValue>) -> Self {
-        let has_question_mark: bool = values
-            .iter()
-            .any(|e| &TemplateValue::Segment("?".to_string()) == e);
+        Self::new_with_list_fields(values, &[])
+    }
+
+    pub fn new_with_list_fields(values: Vec<TemplateValue>, list_fields: &[String]) -> Self {
+        let has_question_mark: bool = values.iter().any(Self::has_question_mark);
         if has_question_mark {
             panic!("sql template should not contains ?");
         }
@@ -38,19 +78,51 @@ impl ParsedTemplateSql {
             .map(|e| e.inner_string())
             .collect();
 
-        let dollar_signs: Vec<String> = values
-            .iter()
-            .filter(|e| matches!(e, TemplateValue::DollarVariable(_)))
-            .map(|e| e.inner_string())
-            .collect();
+        let mut dollar_signs: Vec<String> = Vec::new();
+        for value in values.iter() {
+            if let TemplateValue::DollarVariable(variable) = value {
+                if !dollar_signs.contains(variable) {
+                    dollar_signs.push(variable.clone());
+                }
+            }
+        }
 
+        let has_block = values.iter().any(|e| matches!(e, TemplateValue::Block(_)));
+        let has_list = values.iter().any(|e| match e {
+            TemplateValue::HashVariable(variable) => list_fields.contains(variable),
+            _ => false,
+        });
+        let need_render = !dollar_signs.is_empty() || has_block || has_list;
+        let arguments = TemplateArgument::build(&values)
+            .unwrap_or_else(|err| panic!("invalid control block in sql template: {}", err));
 
+        let mut locals: Vec<Vec<String>> = Vec::new();
         let result: Vec<TemplateValue> = values
             .into_iter()
             .map(|e| {
                 let t: TemplateValue = match e {
+                    TemplateValue::Block(block) => {
+                        Self::track_locals(&mut locals, &block);
+                        TemplateValue::Block(block)
+                    }
+                    TemplateValue::HashVariable(variable)
+                        if list_fields.contains(&variable) && !locals.iter().flatten().any(|l| l.eq(&variable)) =>
+                    {
+                        TemplateValue::Segment(format!(
+                            "{{{{ taitan_orm::traits::list_place_holders(\"{0}\", {0}.len())? }}}}",
+                            variable
+                        ))
+                    }
                     TemplateValue::HashVariable(_) => TemplateValue::Segment("?".to_string()),
-                    TemplateValue::DollarVariable(e) => TemplateValue::Segment(format!("{{{{ {} }}}}", e)),
+                    TemplateValue::DollarVariable(e) => {
+                        let index = dollar_signs.iter().position(|d| d.eq(&e)).unwrap();
+                        TemplateValue::Segment(format!("{{{{ {}[{}] }}}}", Self::RAW_IDENTS, index))
+                    }
+                    // text which looks like rinja syntax is kept as it is, {% raw %} can not be used,
+                    // the text itself may contain {% endraw %}
+                    _ if need_render && Self::has_rinja_delimiter(&e.to_string()) => {
+                        TemplateValue::Segment(Self::escape_rinja_delimiters(&e.to_string()))
+                    }
                     _ => e
                 };
                 t
@@ -58,16 +130,66 @@ impl ParsedTemplateSql {
             .collect();
 
 
-        let marked_sql = result
-            .iter()
-            .map(|e| e.to_string())
-            .collect::<Vec<String>>()
-            .join(" ");
+        let marked_sql = result.iter().map(|e| e.to_string()).collect::<String>();
 
         Self {
             sql: marked_sql,
             variables,
             dollar_signs,
+            arguments,
+            has_block,
+            has_list,
+        }
+    }
+
+    // ? is only allowed in strings and comments
+    fn has_question_mark(value: &TemplateValue) -> bool {
+        matches!(value, TemplateValue::Segment(segment) if segment.contains('?'))
+    }
+
+    // a trailing { would make a delimiter with the { of the next value
+    fn has_rinja_delimiter(text: &str) -> bool {
+        text.contains("{{") || text.contains("{%") || text.contains("{#") || text.ends_with('{')
+    }
+
+    // every delimiter is rendered by a string expression, such as {{ "{%" }}
+    fn escape_rinja_delimiters(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let delimiter = ["{{", "{%", "{#"].into_iter().find(|d| rest.starts_with(d));
+            match delimiter {
+                Some(delimiter) => {
+                    escaped.push_str(&format!("{{{{ \"{}\" }}}}", delimiter));
+                    rest = &rest[delimiter.len()..];
+                }
+                None if rest == "{" => {
+                    escaped.push_str("{{ \"{\" }}");
+                    rest = "";
+                }
+                None => {
+                    escaped.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        escaped
+    }
+
+    // locals declared by for/if let are visible until the block ends
+    fn track_locals(locals: &mut Vec<Vec<String>>, block: &str) {
+        let Ok(tag) = BlockTag::parse(block) else {
+            return;
+        };
+        match tag {
+            BlockTag::If(_) | BlockTag::For(_, _) => locals.push(tag.declared_locals()),
+            BlockTag::ElseIf(_) | BlockTag::Else => {
+                locals.pop();
+                locals.push(tag.declared_locals());
+            }
+            BlockTag::EndIf | BlockTag::EndFor => {
+                locals.pop();
+            }
         }
     }
 }
@@ -86,8 +208,65 @@ mod tests {
     #[test]
     fn test_template_sql2() {
         let parsed_template = ParsedTemplateSql::build("SELECT * `test` user ${v1. v2. v3} where id = 23").unwrap();
-        assert_eq!(parsed_template.sql, "SELECT * `test` user {{ v1.v2.v3 }} where id = 23");
+        assert_eq!(parsed_template.sql, "SELECT * `test` user {{ taitan_raw_idents[0] }} where id = 23");
         assert_eq!(parsed_template.dollar_signs, vec!["v1.v2.v3"]);
+
+        let parsed_template = ParsedTemplateSql::build("SELECT * FROM ${a} JOIN ${b} JOIN ${a}").unwrap();
+        assert_eq!(
+            parsed_template.sql,
+            "SELECT * FROM {{ taitan_raw_idents[0] }} JOIN {{ taitan_raw_idents[1] }} JOIN {{ taitan_raw_idents[0] }}"
+        );
+        assert_eq!(parsed_template.dollar_signs, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_template_sql_block() {
+        let parsed_template = ParsedTemplateSql::build("select * from user where 1 = 1 {% if age.is_some() %} AND age = #{age} {% endif %}").unwrap();
+        assert_eq!(parsed_template.sql, "select * from user where 1 = 1 {% if age.is_some() %} AND age = ? {% endif %}");
+        assert_eq!(parsed_template.variables, vec!["age"]);
+        assert!(parsed_template.need_render());
+    }
+
+    #[test]
+    fn test_template_sql_list() {
+        let list_fields = vec!["ids".to_string()];
+        let parsed_template = ParsedTemplateSql::build_with_list_fields(
+            "select * from user where id IN (#{ids}) {% for ids in groups %} OR id = #{ids} {% endfor %}",
+            &list_fields,
+            StringEscape::Standard,
+        )
+        .unwrap();
+        assert_eq!(
+            parsed_template.sql,
+            "select * from user where id IN ({{ taitan_orm::traits::list_place_holders(\"ids\", ids.len())? }}) {% for ids in groups %} OR id = ? {% endfor %}"
+        );
+        assert!(parsed_template.need_render());
+
+        assert_eq!(list_place_holders("ids", 3).unwrap(), "?,?,?");
+        assert!(list_place_holders("ids", 0).is_err());
+    }
+
+    #[test]
+    fn test_template_sql_verbatim() {
+        let template_sql = "select u.name, '{{ #{a} }}' -- ${b}\n from \"user\" u where u.id != #{id} and u.age::text || ${c} <> 'x'";
+        let parsed_template = ParsedTemplateSql::build(template_sql).unwrap();
+        assert_eq!(
+            parsed_template.sql,
+            "select u.name, '{{ \"{{\" }} #{a} }}' -- ${b}\n from \"user\" u where u.id != ? and u.age::text || {{ taitan_raw_idents[0] }} <> 'x'"
+        );
+        assert_eq!(parsed_template.variables, vec!["id"]);
+        assert_eq!(parsed_template.dollar_signs, vec!["c"]);
+
+        let parsed_template = ParsedTemplateSql::build("select '{% endraw %}{#' -- {\n from ${t}").unwrap();
+        assert_eq!(
+            parsed_template.sql,
+            "select '{{ \"{%\" }} endraw %}{{ \"{#\" }}' -- {{ \"{\" }}\n from {{ taitan_raw_idents[0] }}"
+        );
+
+        assert!(ParsedTemplateSql::build("select * from user where id = ?").is_err());
+        assert!(ParsedTemplateSql::build("select * from user where name = '?'").is_ok());
+        assert!(ParsedTemplateSql::build("select * {% if a %}").is_err());
+        assert!(ParsedTemplateSql::build("  ").is_err());
     }
 
     #[test]
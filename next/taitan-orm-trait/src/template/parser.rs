@@ -1,236 +1,343 @@
-use nom::branch::alt;
-use nom::bytes::complete::escaped;
-use nom::bytes::complete::{tag, take_till, take_until, take_while};
-use nom::character::complete::{
-    alpha0, alphanumeric0, alphanumeric1, char, multispace0, multispace1, one_of,
-};
-use nom::character::is_alphanumeric;
-use nom::combinator::{cut, iterator, map, opt, value};
-use nom::error::{context, ContextError, ParseError, VerboseError};
-use nom::multi::separated_list1;
-use nom::sequence::Tuple;
-use nom::sequence::{delimited, terminated};
-use nom::sequence::{preceded, tuple};
-use nom::Compare;
-use nom::InputLength;
-use nom::InputTake;
-
 use crate::template::template_value::TemplateValue;
-use nom::IResult;
+use crate::NotValidTemplateError;
 
-pub fn parse_back_quote_string<'a, E: ParseError<&'a str>>(
-    input: &'a str,
-) -> IResult<&'a str, TemplateValue, E> {
-    let (remaining, parsed) = preceded(
-        preceded(multispace0, char('`')),
-        cut(terminated(take_until("`"), char('`'))),
-    )(input)?;
-    let value = TemplateValue::BackQuoteString(format!("`{}`", parsed));
-    return Ok((remaining, value));
-}
+/**
+sql tokenizer of template, the original text is kept verbatim, concatenating all values gives the input back,
+except that #{..} and ${..} are normalized by removing whitespaces.
 
-pub fn parse_single_quote_string<'a, E: ParseError<&'a str>>(
-    input: &'a str,
-) -> IResult<&'a str, TemplateValue, E> {
-    let (remaining, parsed) = preceded(
-        preceded(multispace0, char('\'')),
-        cut(terminated(take_until("'"), char('\''))),
-    )(input)?;
-    let value = TemplateValue::SingleQuoteString(format!("'{}'", parsed));
-    return Ok((remaining, value));
+1. 'single quote string', "double quote string/identifier", `back quote identifier`, quote is escaped by doubling it,
+   a backslash escapes the next char only in E'' strings of postgres, and in single and double quote strings of mysql
+2. -- line comment and /* block comment */
+3. $$dollar quoted string$$ and $tag$dollar quoted string$tag$ of postgres
+4. #{a.b} bound variable and ${a.b} raw variable, only outside of strings and comments
+5. {% .. %} control block
+6. anything else is kept in Segment as it is, including whitespaces
+*/
+pub fn parse_template_sql(input: &str, escape: StringEscape) -> Result<Vec<TemplateValue>, NotValidTemplateError> {
+    let mut lexer = TemplateLexer {
+        input,
+        escape,
+        template: true,
+        pos: 0,
+        values: Vec::new(),
+        segment_start: 0,
+    };
+    lexer.run()?;
+    Ok(lexer.values)
 }
 
-pub fn parse_double_quote_string<'a, E: ParseError<&'a str>>(
-    input: &'a str,
-) -> IResult<&'a str, TemplateValue, E> {
-    let (remaining, parsed) = preceded(
-        preceded(multispace0, char('"')),
-        cut(terminated(take_until("\""), char('"'))),
-    )(input)?;
-    let value = TemplateValue::DoubleQuoteString(format!("\"{}\"", parsed));
-    return Ok((remaining, value));
+/**
+replace every place holder outside of strings, quoted identifiers and comments, ? in them is kept as it is.
+the sql is rendered sql instead of template, #{}, ${} and {% %} are not recognized,
+sql which can not be tokenized, such as an unclosed string, has every place holder replaced
+*/
+pub fn replace_place_holders<F: FnMut() -> String>(sql: &str, place_holder: char, mut replace: F) -> String {
+    let mut lexer = TemplateLexer {
+        input: sql,
+        escape: StringEscape::Standard,
+        template: false,
+        pos: 0,
+        values: Vec::new(),
+        segment_start: 0,
+    };
+    let values = match lexer.run() {
+        Ok(_) => lexer.values,
+        Err(_) => vec![TemplateValue::Segment(sql.to_string())],
+    };
+    let mut replaced = String::with_capacity(sql.len());
+    for value in values {
+        match value {
+            TemplateValue::Segment(segment) => {
+                for c in segment.chars() {
+                    if c == place_holder {
+                        replaced.push_str(&replace());
+                    } else {
+                        replaced.push(c);
+                    }
+                }
+            }
+            value => replaced.push_str(&value.to_string()),
+        }
+    }
+    replaced
 }
 
-fn parse_string<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
-    input: &'a str,
-) -> IResult<&'a str, TemplateValue, E> {
-    context(
-        "string",
-        alt((
-            parse_single_quote_string,
-            parse_double_quote_string,
-            parse_back_quote_string,
-        )),
-    )(input)
+/**
+escape of '' and "" strings, decided by the dialect the template is written in
+1. Standard: sqlite and postgres, a backslash is an ordinary char, except in E'' strings of postgres
+2. Backslash: mysql, a backslash escapes the next char, enabled by #[backslash_escape] on the template
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StringEscape {
+    #[default]
+    Standard,
+    Backslash,
 }
 
-//pub fn parse_string(input: &str) -> IResult<&str, &str> {
-//    alt((parse_single_quote_string, parse_double_quote_string))(input)
-//}
-
-pub fn parse_dot<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, &'a str, E> {
-    delimited(multispace0, tag("."), multispace0)(input)
+// sql ending with -- line comment needs a line break before anything is appended
+pub(crate) fn ends_with_line_comment(sql: &str, escape: StringEscape) -> Result<bool, NotValidTemplateError> {
+    let values = parse_template_sql(sql, escape)?;
+    Ok(matches!(values.last(), Some(TemplateValue::Comment(comment)) if comment.starts_with("--")))
 }
-pub fn parse_variable_seg<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+
+struct TemplateLexer<'a> {
     input: &'a str,
-) -> IResult<&'a str, &'a str, E> {
-    context("variable_seg", preceded(multispace0, alphanumeric1))(input)
+    escape: StringEscape,
+    // false for rendered sql, variables and control blocks are not recognized
+    template: bool,
+    pos: usize,
+    values: Vec<TemplateValue>,
+    segment_start: usize,
 }
 
-/*
-pub fn check_following_dot<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
-    input: &'a str,
-) -> IResult<&'a str, (), E> {
-    if input.starts_with(".") {
-        let error = VerboseError::from_char(input, '.');
-        return Err(nom::Err::Failure(error));
+impl<'a> TemplateLexer<'a> {
+    fn run(&mut self) -> Result<(), NotValidTemplateError> {
+        while self.pos < self.input.len() {
+            let rest = &self.input[self.pos..];
+            if rest.starts_with('\'') {
+                let backslash_escape = self.escape == StringEscape::Backslash || self.follows_escape_prefix();
+                let end = self.find_quote_end('\'', backslash_escape)?;
+                self.push_value(end, TemplateValue::SingleQuoteString);
+            } else if rest.starts_with('"') {
+                let end = self.find_quote_end('"', self.escape == StringEscape::Backslash)?;
+                self.push_value(end, TemplateValue::DoubleQuoteString);
+            } else if rest.starts_with('`') {
+                let end = self.find_quote_end('`', false)?;
+                self.push_value(end, TemplateValue::BackQuoteString);
+            } else if rest.starts_with("--") {
+                let end = rest.find('\n').map(|i| self.pos + i).unwrap_or(self.input.len());
+                self.push_value(end, TemplateValue::Comment);
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                let end = comment
+                    .find("*/")
+                    .map(|i| self.pos + 2 + i + 2)
+                    .ok_or_else(|| self.error("block comment is not closed"))?;
+                self.push_value(end, TemplateValue::Comment);
+            } else if rest.starts_with("#{") && self.template {
+                let (end, variable) = self.parse_variable()?;
+                self.push_value(end, |_| TemplateValue::HashVariable(variable));
+            } else if rest.starts_with("${") && self.template {
+                let (end, variable) = self.parse_variable()?;
+                self.push_value(end, |_| TemplateValue::DollarVariable(variable));
+            } else if rest.starts_with("{%") && self.template {
+                let end = rest
+                    .find("%}")
+                    .map(|i| self.pos + i + 2)
+                    .ok_or_else(|| self.error("control block is not closed"))?;
+                self.push_value(end, TemplateValue::Block);
+            } else if let Some(tag) = Self::dollar_quote_tag(rest) {
+                let end = rest[tag.len()..]
+                    .find(tag)
+                    .map(|i| self.pos + tag.len() + i + tag.len())
+                    .ok_or_else(|| self.error("dollar quoted string is not closed"))?;
+                self.push_value(end, TemplateValue::DollarQuoteString);
+            } else {
+                self.pos += rest.chars().next().unwrap().len_utf8();
+            }
+        }
+        self.flush_segment();
+        Ok(())
     }
-    return Ok((input, ()));
-}
-*/
 
-pub fn parse_hash_variable<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
-    input: &'a str,
-) -> IResult<&'a str, TemplateValue, E> {
-    //let (remaining, parsed) = parse_variable_seg(input)?;
-    //let first_seg: &str = parsed;
-
-    let segs_result = delimited(
-        preceded(multispace0, tag("#{")),
-        separated_list1(parse_dot, parse_variable_seg),
-        preceded(multispace0, tag("}")),
-    )(input)?;
-    let (remaining, parsed) = segs_result;
-    let following_segs: Vec<&str> = parsed;
-    let mut segs: Vec<&str> = Vec::new();
-    //segs.push(first_seg);
-    segs.extend(following_segs);
-    let variable = segs.join(".");
-    return Ok((remaining, TemplateValue::HashVariable(variable.to_string())));
-}
+    fn error(&self, message: &str) -> NotValidTemplateError {
+        NotValidTemplateError(format!("{} at: {}", message, &self.input[self.pos..]))
+    }
 
-pub fn parse_dollar_variable<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
-    input: &'a str,
-) -> IResult<&'a str, TemplateValue, E> {
-    //let (remaining, parsed) = parse_variable_seg(input)?;
-    //let first_seg: &str = parsed;
-
-    let segs_result = delimited(
-        preceded(multispace0, tag("${")),
-        separated_list1(parse_dot, parse_variable_seg),
-        preceded(multispace0, tag("}")),
-    )(input)?;
-    let (remaining, parsed) = segs_result;
-    let following_segs: Vec<&str> = parsed;
-    let mut segs: Vec<&str> = Vec::new();
-    //segs.push(first_seg);
-    segs.extend(following_segs);
-    let variable = segs.join(".");
-    return Ok((remaining, TemplateValue::DollarVariable(variable.to_string())));
-}
+    fn flush_segment(&mut self) {
+        if self.segment_start < self.pos {
+            let segment = &self.input[self.segment_start..self.pos];
+            self.values.push(TemplateValue::Segment(segment.to_string()));
+        }
+    }
 
-pub fn parse_segment<'a, E: ParseError<&'a str>>(
-    input: &'a str,
-) -> IResult<&'a str, TemplateValue, E> {
-    let (remaining, parsed) = alt((
-        preceded(multispace0, alphanumeric1),
-        preceded(multispace0, tag("*")),
-        preceded(multispace0, tag(",")),
-        preceded(multispace0, tag(";")),
-        preceded(multispace0, tag("=")),
-        preceded(multispace0, tag("?")),
-        preceded(multispace0, tag("<")),
-        preceded(multispace0, tag(">")),
-        preceded(multispace0, tag("%")),
-        preceded(multispace0, tag("(")),
-        preceded(multispace0, tag(")")),
-    ))(input)?;
-    return Ok((remaining, TemplateValue::Segment(parsed.to_string())));
-}
+    // text before current pos is a segment, text from pos to end is the value
+    fn push_value<F: FnOnce(String) -> TemplateValue>(&mut self, end: usize, build: F) {
+        self.flush_segment();
+        let text = &self.input[self.pos..end];
+        self.values.push(build(text.to_string()));
+        self.pos = end;
+        self.segment_start = end;
+    }
 
-/*
-pub fn parse_variable(input: &str) -> IResult<&str, &str> {
-    delimited(
-        tuple((tag("#{"), multispace0)),
-        parse_dot_variable,
-        tuple((multispace0, tag("}"))),
-    )(input)
-}
-*/
+    // E'' or e'' string of postgres, the E is not the end of an identifier such as name''
+    fn follows_escape_prefix(&self) -> bool {
+        let mut before = self.input[..self.pos].chars().rev();
+        matches!(before.next(), Some('E' | 'e'))
+            && !before.next().is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    }
 
-pub fn parse_template_value(input: &str) -> IResult<&str, TemplateValue> {
-    alt((parse_dollar_variable, parse_hash_variable, parse_string, parse_segment))(input)
-}
-pub fn parse_template_sql(input: &str) -> IResult<&str, Vec<TemplateValue>> {
-    let mut values: Vec<TemplateValue> = Vec::new();
-    let (mut remaining, mut parsed) = parse_template_value(input)?;
-    values.push(parsed);
-    while !remaining.is_empty() {
-        (remaining, parsed) = parse_template_value(remaining)?;
-        values.push(parsed);
-    }
-    Ok((remaining, values))
+    // position after the closing quote, doubled quote or backslash escaped quote is not the end
+    fn find_quote_end(&self, quote: char, backslash_escape: bool) -> Result<usize, NotValidTemplateError> {
+        let mut chars = self.input[self.pos + 1..].char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if c == '\\' && backslash_escape {
+                chars.next();
+                continue;
+            }
+            if c == quote {
+                if chars.peek().map(|(_, next)| *next == quote).unwrap_or(false) {
+                    chars.next();
+                    continue;
+                }
+                return Ok(self.pos + 1 + i + 1);
+            }
+        }
+        Err(self.error(&format!("{} quoted string is not closed", quote)))
+    }
+
+    // $$ or $tag$, where tag is an identifier, $1 is a positional parameter instead
+    fn dollar_quote_tag(rest: &str) -> Option<&str> {
+        let body = rest.strip_prefix('$')?;
+        let tag_len = body
+            .char_indices()
+            .take_while(|(i, c)| c.is_alphabetic() || *c == '_' || (*i > 0 && c.is_ascii_digit()))
+            .map(|(i, c)| i + c.len_utf8())
+            .last()
+            .unwrap_or(0);
+        if body[tag_len..].starts_with('$') {
+            Some(&rest[..tag_len + 2])
+        } else {
+            None
+        }
+    }
+
+    // #{ a . b } or ${ a . b }, segments are alphanumeric or underscore
+    fn parse_variable(&self) -> Result<(usize, String), NotValidTemplateError> {
+        let start = self.pos + 2;
+        let close = self.input[start..]
+            .find('}')
+            .map(|i| start + i)
+            .ok_or_else(|| self.error("variable is not closed"))?;
+        let segs: Vec<&str> = self.input[start..close].split('.').map(|seg| seg.trim()).collect();
+        let is_valid = segs
+            .iter()
+            .all(|seg| !seg.is_empty() && seg.chars().all(|c| c.is_alphanumeric() || c == '_'));
+        if !is_valid {
+            return Err(self.error("variable is not valid"));
+        }
+        Ok((close + 1, segs.join(".")))
+    }
 }
 
 #[cfg(test)]
 mod test {
-
-    use super::TemplateValue;
     use super::*;
-    use crate::template::parsed_template_sql::ParsedTemplateSql;
-    use nom::error::ErrorKind;
+
+    fn concat(values: &[TemplateValue]) -> String {
+        values.iter().map(|value| value.to_string()).collect()
+    }
 
     #[test]
     pub fn test_string() {
-        let (remaining, parsed) =
-            parse_string::<(&str, ErrorKind)>("'this is single string'").unwrap();
+        let parsed = parse_template_sql("'this is ''single'' string'", StringEscape::Standard).unwrap();
+        assert_eq!(
+            parsed,
+            vec![TemplateValue::SingleQuoteString("'this is ''single'' string'".to_string())]
+        );
+        let parsed = parse_template_sql("\"this is #{double} string\"", StringEscape::Standard).unwrap();
+        assert_eq!(
+            parsed,
+            vec![TemplateValue::DoubleQuoteString("\"this is #{double} string\"".to_string())]
+        );
+        assert!(parse_template_sql("select 'not closed", StringEscape::Standard).is_err());
+        // backslash is an ordinary char in standard strings, 'C:\' is closed
+        let parsed = parse_template_sql("path = 'C:\\' AND id = #{id}", StringEscape::Standard).unwrap();
         assert_eq!(
             parsed,
-            TemplateValue::SingleQuoteString("'this is single string'".to_string())
+            vec![
+                TemplateValue::Segment("path = ".to_string()),
+                TemplateValue::SingleQuoteString("'C:\\'".to_string()),
+                TemplateValue::Segment(" AND id = ".to_string()),
+                TemplateValue::HashVariable("id".to_string()),
+            ]
         );
-        let (remaining, parsed) =
-            parse_string::<(&str, ErrorKind)>("\"this is double string\"").unwrap();
+        // it escapes the next char in mysql strings and E'' strings of postgres
+        let parsed = parse_template_sql("'it\\'s #{not}' = #{a}", StringEscape::Backslash).unwrap();
         assert_eq!(
             parsed,
-            TemplateValue::DoubleQuoteString("\"this is double string\"".to_string())
+            vec![
+                TemplateValue::SingleQuoteString("'it\\'s #{not}'".to_string()),
+                TemplateValue::Segment(" = ".to_string()),
+                TemplateValue::HashVariable("a".to_string()),
+            ]
         );
+        let parsed = parse_template_sql("'c:\\\\' #{a}", StringEscape::Backslash).unwrap();
+        assert_eq!(parsed[0], TemplateValue::SingleQuoteString("'c:\\\\'".to_string()));
+        let parsed = parse_template_sql("E'it\\'s' = #{a} AND name'' = \"a\\\"", StringEscape::Standard).unwrap();
+        assert_eq!(parsed[1], TemplateValue::SingleQuoteString("'it\\'s'".to_string()));
+        assert_eq!(parsed[5], TemplateValue::SingleQuoteString("''".to_string()));
+        assert_eq!(parsed[7], TemplateValue::DoubleQuoteString("\"a\\\"".to_string()));
+    }
+
+    #[test]
+    pub fn test_replace_place_holders() {
+        let sql = "SELECT '?', \"a?\" FROM t -- ?\n WHERE /* ? */ b = $$?$$ AND c = ? AND d IN (?, ?) AND e = E'\\'?'";
+        let mut index = 0;
+        let replaced = replace_place_holders(sql, '?', || {
+            index += 1;
+            format!("${}", index)
+        });
+        assert_eq!(
+            replaced,
+            "SELECT '?', \"a?\" FROM t -- ?\n WHERE /* ? */ b = $$?$$ AND c = $1 AND d IN ($2, $3) AND e = E'\\'?'"
+        );
+        assert_eq!(replace_place_holders("a = ? AND b = '?", '?', || "$".to_string()), "a = $ AND b = '$");
+        assert_eq!(replace_place_holders("a = '#{x}' AND b = ?", '?', || "$1".to_string()), "a = '#{x}' AND b = $1");
     }
 
     #[test]
     pub fn test_hash_variable() {
-        let (remaining, parsed) =
-            parse_hash_variable::<(&str, ErrorKind)>("#{ var1 . var2 }").unwrap();
-        assert_eq!(parsed, TemplateValue::HashVariable("var1.var2".to_string()));
+        let parsed = parse_template_sql("#{ var1 . var_2 }", StringEscape::Standard).unwrap();
+        assert_eq!(parsed, vec![TemplateValue::HashVariable("var1.var_2".to_string())]);
+        assert!(parse_template_sql("#{ var1 var2 }", StringEscape::Standard).is_err());
+        assert!(parse_template_sql("#{ var1", StringEscape::Standard).is_err());
     }
 
     #[test]
     pub fn test_dollar_variable() {
-        let (remaining, parsed) =
-            parse_dollar_variable::<(&str, ErrorKind)>("${ var1 . var2 }").unwrap();
-        assert_eq!(parsed, TemplateValue::DollarVariable("var1.var2".to_string()));
+        let parsed = parse_template_sql("${ var1 . var2 }", StringEscape::Standard).unwrap();
+        assert_eq!(parsed, vec![TemplateValue::DollarVariable("var1.var2".to_string())]);
+    }
+
+    #[test]
+    pub fn test_comment_and_dollar_quote() {
+        let sql = "select $1 -- #{not_variable}\n /* ${not} */ $$ it's #{not} $$, $fn$ a $$ b $fn$ from t";
+        let parsed = parse_template_sql(sql, StringEscape::Standard).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                TemplateValue::Segment("select $1 ".to_string()),
+                TemplateValue::Comment("-- #{not_variable}".to_string()),
+                TemplateValue::Segment("\n ".to_string()),
+                TemplateValue::Comment("/* ${not} */".to_string()),
+                TemplateValue::Segment(" ".to_string()),
+                TemplateValue::DollarQuoteString("$$ it's #{not} $$".to_string()),
+                TemplateValue::Segment(", ".to_string()),
+                TemplateValue::DollarQuoteString("$fn$ a $$ b $fn$".to_string()),
+                TemplateValue::Segment(" from t".to_string()),
+            ]
+        );
+        assert_eq!(concat(&parsed), sql);
     }
 
     #[test]
     pub fn test_template() {
-        let (remaining, parsed) =
-            parse_template_sql("SELECT * `test` user #{v1. v2. v3} where id = 23").unwrap();
+        let sql = "SELECT u.*, `test` FROM user u WHERE u.id != #{v1. v2. v3} AND age::text || 'a' <> \"b\" {%- if a %}[x]{% endif %}";
+        let parsed = parse_template_sql(sql, StringEscape::Standard).unwrap();
         let result_vec = vec![
-            TemplateValue::Segment("SELECT".to_string()),
-            TemplateValue::Segment("*".to_string()),
+            TemplateValue::Segment("SELECT u.*, ".to_string()),
             TemplateValue::BackQuoteString("`test`".to_string()),
-            TemplateValue::Segment("user".to_string()),
+            TemplateValue::Segment(" FROM user u WHERE u.id != ".to_string()),
             TemplateValue::HashVariable("v1.v2.v3".to_string()),
-            TemplateValue::Segment("where".to_string()),
-            TemplateValue::Segment("id".to_string()),
-            TemplateValue::Segment("=".to_string()),
-            TemplateValue::Segment("23".to_string()),
+            TemplateValue::Segment(" AND age::text || ".to_string()),
+            TemplateValue::SingleQuoteString("'a'".to_string()),
+            TemplateValue::Segment(" <> ".to_string()),
+            TemplateValue::DoubleQuoteString("\"b\"".to_string()),
+            TemplateValue::Segment(" ".to_string()),
+            TemplateValue::Block("{%- if a %}".to_string()),
+            TemplateValue::Segment("[x]".to_string()),
+            TemplateValue::Block("{% endif %}".to_string()),
         ];
         assert_eq!(parsed, result_vec);
-
-        let parsed_sql = ParsedTemplateSql::new(parsed);
-
-        assert_eq!(parsed_sql.sql, "SELECT * `test` user ? where id = 23");
-        let variables = vec!["v1.v2.v3".to_string()];
-        assert_eq!(parsed_sql.variables, variables);
     }
 }
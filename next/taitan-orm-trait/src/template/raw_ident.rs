@@ -0,0 +1,88 @@
+use crate::NotValidTemplateError;
+
+/**
+${field} is rendered into sql as it is, so the value must be a plain identifier, quoted by wrap char of the dialect.
+1. value is split by '.', such as schema.table, every segment starts with letter or underscore, and contains only ascii letters, digits and underscores
+2. if one_of is given by #[raw(one_of = "user,admin")], value must also be one of them, it is quoted all the same
+*/
+pub fn quote_raw_ident(
+    name: &str,
+    value: &str,
+    one_of: Option<&[&str]>,
+    wrap_char: char,
+) -> Result<String, NotValidTemplateError> {
+    if let Some(allowed) = one_of {
+        if !allowed.contains(&value) {
+            return Err(NotValidTemplateError(format!(
+                "raw value of {}: {} is not one of [{}]",
+                name,
+                value,
+                allowed.join(",")
+            )));
+        }
+    }
+    let segs: Vec<&str> = value.split('.').collect();
+    let is_valid = segs.iter().all(|seg| {
+        seg.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && seg.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+    if !is_valid {
+        return Err(NotValidTemplateError(format!(
+            "raw value of {}: {} is not an identifier",
+            name, value
+        )));
+    }
+    Ok(segs
+        .iter()
+        .map(|seg| format!("{}{}{}", wrap_char, seg, wrap_char))
+        .collect::<Vec<String>>()
+        .join("."))
+}
+
+/**
+${field} declared by #[raw(keyword_of = "ASC,DESC")] is rendered verbatim without quoting,
+so value must be exactly one of the listed keywords, such as sort directions
+*/
+pub fn keyword_raw_ident(name: &str, value: &str, keyword_of: &[&str]) -> Result<String, NotValidTemplateError> {
+    if !keyword_of.contains(&value) {
+        return Err(NotValidTemplateError(format!(
+            "raw value of {}: {} is not one of keywords [{}]",
+            name,
+            value,
+            keyword_of.join(",")
+        )));
+    }
+    Ok(value.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_quote_raw_ident() {
+        assert_eq!(quote_raw_ident("table", "user", None, '`').unwrap(), "`user`");
+        assert_eq!(quote_raw_ident("table", "public.user_2", None, '"').unwrap(), "\"public\".\"user_2\"");
+        assert!(quote_raw_ident("table", "user; DROP TABLE user", None, '`').is_err());
+        assert!(quote_raw_ident("table", "user`", None, '`').is_err());
+        assert!(quote_raw_ident("table", "2user", None, '`').is_err());
+        assert!(quote_raw_ident("table", "user.", None, '`').is_err());
+        assert!(quote_raw_ident("table", "", None, '`').is_err());
+
+        let allowed = ["user", "admin"];
+        assert_eq!(quote_raw_ident("table", "admin", Some(&allowed), '`').unwrap(), "`admin`");
+        assert_eq!(quote_raw_ident("table", "admin", Some(&allowed), '"').unwrap(), "\"admin\"");
+        assert!(quote_raw_ident("table", "guest", Some(&allowed), '`').is_err());
+        let allowed = ["user; DROP TABLE user"];
+        assert!(quote_raw_ident("table", "user; DROP TABLE user", Some(&allowed), '`').is_err());
+    }
+
+    #[test]
+    pub fn test_keyword_raw_ident() {
+        let keywords = ["ASC", "DESC", "NULLS LAST"];
+        assert_eq!(keyword_raw_ident("direction", "ASC", &keywords).unwrap(), "ASC");
+        assert_eq!(keyword_raw_ident("direction", "NULLS LAST", &keywords).unwrap(), "NULLS LAST");
+        assert!(keyword_raw_ident("direction", "asc", &keywords).is_err());
+        assert!(keyword_raw_ident("direction", "DESC; DROP TABLE user", &keywords).is_err());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::template::template_value::TemplateValue;
+
+/**
+bound variables of template sql, nested in the same control blocks as they are rendered.
+the macro generates argument binding code with the same structure, so arguments are always
+in sync with the sql actually rendered
+
+1. Variable: #{a.b}
+2. If: {% if cond %} .. {% elif cond %} .. {% else %} .. {% endif %}, cond may be `let Some(x) = y`
+3. For: {% for x in xs %} .. {% endfor %}
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateArgument {
+    Variable(String),
+    If {
+        branches: Vec<(String, Vec<TemplateArgument>)>,
+        otherwise: Vec<TemplateArgument>,
+    },
+    For {
+        target: String,
+        iter: String,
+        body: Vec<TemplateArgument>,
+    },
+}
+
+impl TemplateArgument {
+    pub fn has_variable(&self) -> bool {
+        match self {
+            TemplateArgument::Variable(_) => true,
+            TemplateArgument::If { branches, otherwise } => {
+                branches.iter().any(|(_, body)| body.iter().any(|e| e.has_variable()))
+                    || otherwise.iter().any(|e| e.has_variable())
+            }
+            TemplateArgument::For { body, .. } => body.iter().any(|e| e.has_variable()),
+        }
+    }
+
+    // drop variables which are the field or sub fields of field, such as limit field in count sql
+    pub fn without_field(arguments: &[TemplateArgument], field: &str) -> Vec<TemplateArgument> {
+        let field_dot = format!("{}.", field);
+        arguments
+            .iter()
+            .filter(|argument| match argument {
+                TemplateArgument::Variable(variable) => variable.ne(field) && !variable.starts_with(&field_dot),
+                _ => true,
+            })
+            .map(|argument| match argument {
+                TemplateArgument::If { branches, otherwise } => TemplateArgument::If {
+                    branches: branches
+                        .iter()
+                        .map(|(cond, body)| (cond.clone(), Self::without_field(body, field)))
+                        .collect(),
+                    otherwise: Self::without_field(otherwise, field),
+                },
+                TemplateArgument::For { target, iter, body } => TemplateArgument::For {
+                    target: target.clone(),
+                    iter: iter.clone(),
+                    body: Self::without_field(body, field),
+                },
+                _ => argument.clone(),
+            })
+            .collect()
+    }
+
+    pub fn build(values: &[TemplateValue]) -> Result<Vec<TemplateArgument>, String> {
+        let mut index = 0;
+        let (arguments, end) = Self::build_until(values, &mut index)?;
+        match end {
+            None => Ok(arguments),
+            Some(tag) => Err(format!("unexpected {{% {} %}}", tag)),
+        }
+    }
+
+    // build arguments until an end-like tag (elif/else/endif/endfor) or the end of values
+    fn build_until(
+        values: &[TemplateValue],
+        index: &mut usize,
+    ) -> Result<(Vec<TemplateArgument>, Option<BlockTag>), String> {
+        let mut arguments: Vec<TemplateArgument> = Vec::new();
+        while *index < values.len() {
+            let value = &values[*index];
+            *index += 1;
+            match value {
+                TemplateValue::HashVariable(variable) => arguments.push(TemplateArgument::Variable(variable.clone())),
+                TemplateValue::Block(block) => match BlockTag::parse(block)? {
+                    BlockTag::If(cond) => arguments.push(Self::build_if(cond, values, index)?),
+                    BlockTag::For(target, iter) => {
+                        let (body, end) = Self::build_until(values, index)?;
+                        if end != Some(BlockTag::EndFor) {
+                            return Err(format!("{{% for {} in {} %}} is not closed by {{% endfor %}}", target, iter));
+                        }
+                        arguments.push(TemplateArgument::For { target, iter, body });
+                    }
+                    tag => return Ok((arguments, Some(tag))),
+                },
+                _ => {}
+            }
+        }
+        Ok((arguments, None))
+    }
+
+    fn build_if(cond: String, values: &[TemplateValue], index: &mut usize) -> Result<TemplateArgument, String> {
+        let mut branches: Vec<(String, Vec<TemplateArgument>)> = Vec::new();
+        let mut cond = cond;
+        loop {
+            let (body, end) = Self::build_until(values, index)?;
+            branches.push((cond, body));
+            match end {
+                Some(BlockTag::ElseIf(next_cond)) => cond = next_cond,
+                Some(BlockTag::Else) => {
+                    let (otherwise, end) = Self::build_until(values, index)?;
+                    if end != Some(BlockTag::EndIf) {
+                        return Err("{% else %} is not closed by {% endif %}".to_string());
+                    }
+                    return Ok(TemplateArgument::If { branches, otherwise });
+                }
+                Some(BlockTag::EndIf) => {
+                    return Ok(TemplateArgument::If {
+                        branches,
+                        otherwise: Vec::new(),
+                    })
+                }
+                _ => return Err("{% if %} is not closed by {% endif %}".to_string()),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum BlockTag {
+    If(String),
+    ElseIf(String),
+    Else,
+    EndIf,
+    For(String, String),
+    EndFor,
+}
+
+impl std::fmt::Display for BlockTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlockTag::If(cond) => write!(f, "if {}", cond),
+            BlockTag::ElseIf(cond) => write!(f, "elif {}", cond),
+            BlockTag::Else => write!(f, "else"),
+            BlockTag::EndIf => write!(f, "endif"),
+            BlockTag::For(target, iter) => write!(f, "for {} in {}", target, iter),
+            BlockTag::EndFor => write!(f, "endfor"),
+        }
+    }
+}
+
+impl BlockTag {
+    // names declared by the block, loop variables of for and bindings of if let
+    pub(crate) fn declared_locals(&self) -> Vec<String> {
+        let pattern = match self {
+            BlockTag::For(target, _) => target.as_str(),
+            BlockTag::If(cond) | BlockTag::ElseIf(cond) => match cond.strip_prefix("let ") {
+                Some(let_cond) => let_cond.split('=').next().unwrap_or_default(),
+                None => return Vec::new(),
+            },
+            _ => return Vec::new(),
+        };
+        pattern
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|name| name.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    // block is the raw {% .. %}, whitespace control chars -, + and ~ are ignored
+    pub(crate) fn parse(block: &str) -> Result<Self, String> {
+        let content = block
+            .trim_start_matches("{%")
+            .trim_end_matches("%}")
+            .trim_matches(|c: char| c == '-' || c == '+' || c == '~')
+            .trim();
+        let (keyword, rest) = content.split_once(char::is_whitespace).unwrap_or((content, ""));
+        let rest = rest.trim();
+        match keyword {
+            "if" if !rest.is_empty() => Ok(BlockTag::If(rest.to_string())),
+            "elif" if !rest.is_empty() => Ok(BlockTag::ElseIf(rest.to_string())),
+            "else" if rest.is_empty() => Ok(BlockTag::Else),
+            "else" => match rest.strip_prefix("if") {
+                Some(cond) if cond.starts_with(char::is_whitespace) => Ok(BlockTag::ElseIf(cond.trim().to_string())),
+                _ => Err(format!("unsupported template block: {}", block)),
+            },
+            "endif" => Ok(BlockTag::EndIf),
+            "for" => match rest.split_once(" in ") {
+                Some((target, iter)) => Ok(BlockTag::For(target.trim().to_string(), iter.trim().to_string())),
+                None => Err(format!("unsupported template block: {}", block)),
+            },
+            "endfor" => Ok(BlockTag::EndFor),
+            _ => Err(format!("unsupported template block: {}", block)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::template::parser::{parse_template_sql, StringEscape};
+
+    #[test]
+    pub fn test_template_argument() {
+        let values = parse_template_sql(
+            "select * from user where 1 = 1 {% if age.is_some() %} AND age = #{age} {% elif min_age > 0 %} AND age > #{min_age} {% else %} AND age IS NULL {% endif %} \
+            {%- for id in ids -%} OR id = #{id} {%- endfor %} LIMIT #{page.offset}",
+            StringEscape::Standard,
+        )
+        .unwrap();
+        let arguments = TemplateArgument::build(&values).unwrap();
+        let expected = vec![
+            TemplateArgument::If {
+                branches: vec![
+                    ("age.is_some()".to_string(), vec![TemplateArgument::Variable("age".to_string())]),
+                    ("min_age > 0".to_string(), vec![TemplateArgument::Variable("min_age".to_string())]),
+                ],
+                otherwise: vec![],
+            },
+            TemplateArgument::For {
+                target: "id".to_string(),
+                iter: "ids".to_string(),
+                body: vec![TemplateArgument::Variable("id".to_string())],
+            },
+            TemplateArgument::Variable("page.offset".to_string()),
+        ];
+        assert_eq!(arguments, expected);
+        assert_eq!(TemplateArgument::without_field(&arguments, "page"), expected[0..2].to_vec());
+
+        let values = parse_template_sql("select * {% if a %} #{a} {% for b in c %} #{b} {% endif %}", StringEscape::Standard).unwrap();
+        assert!(TemplateArgument::build(&values).is_err());
+        let values = parse_template_sql("select * {% if a %} #{a}", StringEscape::Standard).unwrap();
+        assert!(TemplateArgument::build(&values).is_err());
+        let values = parse_template_sql("select * {% endfor %}", StringEscape::Standard).unwrap();
+        assert!(TemplateArgument::build(&values).is_err());
+    }
+}
@@ -5,10 +5,16 @@ pub enum TemplateValue {
     SingleQuoteString(String),
     DoubleQuoteString(String),
     BackQuoteString(String),
+    // $$..$$ or $tag$..$tag$ of postgres
+    DollarQuoteString(String),
+    // -- line comment or /* block comment */
+    Comment(String),
     Star(String),
     Segment(String),
     HashVariable(String),
     DollarVariable(String),
+    // control block of rinja, such as {% if age.is_some() %}, kept as it is
+    Block(String),
 }
 
 pub trait InnerString {
@@ -20,8 +26,20 @@ impl InnerString for TemplateValue {
         match &self {
             Self::SingleQuoteString(s) | Self::DoubleQuoteString(s) => s.clone(),
             Self::BackQuoteString(s) | Self::Star(s) => s.clone(),
+            Self::DollarQuoteString(s) | Self::Comment(s) => s.clone(),
             Self::HashVariable(v) | Self::DollarVariable(v) => v.clone(),
-            Self::Segment(s) => s.clone(),
+            Self::Segment(s) | Self::Block(s) => s.clone(),
+        }
+    }
+}
+
+impl TemplateValue {
+    // text of the value in template sql syntax, variables are written back as #{..} and ${..}
+    pub fn to_template_string(&self) -> String {
+        match self {
+            TemplateValue::HashVariable(v) => format!("#{{{}}}", v),
+            TemplateValue::DollarVariable(v) => format!("${{{}}}", v),
+            _ => self.to_string(),
         }
     }
 }
@@ -32,8 +50,11 @@ impl Display for TemplateValue {
             TemplateValue::SingleQuoteString(v)
             | TemplateValue::DoubleQuoteString(v)
             | TemplateValue::BackQuoteString(v)
+            | TemplateValue::DollarQuoteString(v)
+            | TemplateValue::Comment(v)
             | TemplateValue::Star(v)
             | TemplateValue::Segment(v)
+            | TemplateValue::Block(v)
             | TemplateValue::HashVariable(v) => v.to_string(),
             | TemplateValue::DollarVariable(v) => v.to_string(),
         };
@@ -1,18 +1,28 @@
 use crate::CountSql;
 use crate::pagination::Pagination;
 use crate::NotImplementError;
+use crate::NotValidTemplateError;
 use sqlx::any::AnyArguments;
 use sqlx::error::BoxDynError;
-use sqlx::mysql::MySqlArguments;
-use sqlx::postgres::PgArguments;
-use sqlx::sqlite::SqliteArguments;
+use sqlx::mysql::{MySqlArguments, MySqlRow};
+use sqlx::postgres::{PgArguments, PgRow};
+use sqlx::sqlite::{SqliteArguments, SqliteRow};
 use std::fmt::Debug;
 
 pub trait TemplateRecord: Sync + Debug {
+    // errors of rendering are returned, an invalid ${} value never turns into an empty sql
+    fn get_sql(&self, page: Option<&Pagination>) -> Result<String, NotValidTemplateError>;
 
-    fn get_sql(&self, page: Option<&Pagination>) -> String;
+    fn get_count_sql(&self) -> Result<Option<String>, NotValidTemplateError>;
 
-    fn get_count_sql(&self) -> Option<String>;
+    // ${} values are validated and quoted by wrap char of the dialect, get_sql quotes them by `
+    fn render_sql(&self, page: Option<&Pagination>, _wrap_char: char) -> Result<String, NotValidTemplateError> {
+        self.get_sql(page)
+    }
+
+    fn render_count_sql(&self, _wrap_char: char) -> Result<Option<String>, NotValidTemplateError> {
+        self.get_count_sql()
+    }
 
     fn get_pagination(&self) -> Option<&Pagination> {
         None
@@ -40,3 +50,18 @@ pub trait TemplateRecord: Sync + Debug {
         Err(NotImplementError("gen_template_arguments_postgres".to_string()).into())
     }
 }
+
+/**
+implemented only by templates declared with #[output = "UserSelectedEntity"] or #[output = "i64"],
+rows of query/query_option/query_paged are decoded into Output by decode_output_xxx,
+template without #[output] is only executed, or fetched with an explicit SelectedEntity
+*/
+pub trait OutputTemplateRecord: TemplateRecord {
+    type Output;
+
+    fn decode_output_sqlite(&self, row: SqliteRow) -> Result<Self::Output, sqlx::Error>;
+
+    fn decode_output_mysql(&self, row: MySqlRow) -> Result<Self::Output, sqlx::Error>;
+
+    fn decode_output_postgres(&self, row: PgRow) -> Result<Self::Output, sqlx::Error>;
+}
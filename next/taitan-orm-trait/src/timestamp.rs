@@ -0,0 +1,27 @@
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+/**
+type of #[created_at]/#[updated_at] fields, the current time is bound when the row is written
+PrimitiveDateTime is the current time in UTC without offset
+*/
+pub trait AutoTimestamp: Sized {
+    // the same instant is converted for every field and row written by one statement
+    fn at(now: OffsetDateTime) -> Self;
+
+    fn now() -> Self {
+        Self::at(OffsetDateTime::now_utc())
+    }
+}
+
+impl AutoTimestamp for OffsetDateTime {
+    fn at(now: OffsetDateTime) -> Self {
+        now
+    }
+}
+
+impl AutoTimestamp for PrimitiveDateTime {
+    fn at(now: OffsetDateTime) -> Self {
+        let now = now.to_offset(time::UtcOffset::UTC);
+        PrimitiveDateTime::new(now.date(), now.time())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Mutation, NotImplementError};
+use crate::{Mutation, NotImplementError, SoftDelete, WriteHooks};
 use sqlx::error::BoxDynError;
 use sqlx::mysql::MySqlArguments;
 use sqlx::postgres::PgArguments;
@@ -11,6 +11,25 @@ pub trait Unique: Sync + Debug {
 
     fn get_unique_field_names(&self) -> &'static [&'static str];
 
+    // #[soft_delete] column of the table, delete becomes an update and reads skip deleted rows
+    fn get_soft_delete(&self) -> Option<SoftDelete> {
+        None
+    }
+
+    // Some(self) with #[hooks(unique)], delete/hard_delete run its before/after_delete
+    fn get_hooks(&self) -> Option<&dyn WriteHooks> {
+        None
+    }
+
+    fn get_hooks_mut(&mut self) -> Option<&mut dyn WriteHooks> {
+        None
+    }
+
+    // delete/hard_delete borrow the value, before hooks change this clone which is written instead
+    fn clone_for_hooks(&self) -> Option<Box<dyn Unique<Mutation = Self::Mutation> + '_>> {
+        None
+    }
+
 
     fn gen_update_arguments_sqlite<'a>(
         &'a self,
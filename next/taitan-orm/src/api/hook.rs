@@ -0,0 +1,21 @@
+use crate::api::writer::WriterApi;
+use taitan_orm_trait::{Entity, HookExecutor, HookFuture};
+
+// HookExecutor of hooks, borrows the executor running the hooked operation
+pub(crate) struct HookContext<'e, T: ?Sized> {
+    pub(crate) executor: &'e mut T,
+}
+
+impl<T: WriterApi + ?Sized> HookExecutor for HookContext<'_, T> {
+    fn execute_plain<'a>(&'a mut self, stmt: &'a str) -> HookFuture<'a, u64> {
+        Box::pin(async move { Ok(self.executor.execute_plain(stmt).await?) })
+    }
+
+    fn insert<'a>(&'a mut self, entity: &'a dyn Entity) -> HookFuture<'a, bool> {
+        Box::pin(async move { Ok(self.executor.insert(entity).await?) })
+    }
+
+    fn upsert<'a>(&'a mut self, entity: &'a dyn Entity) -> HookFuture<'a, bool> {
+        Box::pin(async move { Ok(self.executor.upsert(entity).await?) })
+    }
+}
@@ -1,3 +1,4 @@
 pub mod writer;
 pub mod reader;
-pub mod template;
\ No newline at end of file
+pub mod template;
+mod hook;
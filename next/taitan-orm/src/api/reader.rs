@@ -1,14 +1,33 @@
 use crate::extractor::Extractor;
 use crate::Result;
+use futures::stream::{LocalBoxStream, StreamExt};
 use crate::{CountResult, SqlExecutor, SqlGenerator, SqlGeneratorContainer};
-use taitan_orm_trait::{Location, Mutation, OrderBy, SelectedEntity, Selection, Unique};
+use sqlx::{ColumnIndex, Database, Decode, FromRow, Type};
+use taitan_orm_trait::{AggregateExpr, AggregateFunction, Aggregated, Column, DeletedScope, NumericKind, TableColumn};
+use taitan_orm_trait::{JoinedConditions, JoinedSelected, Location, Mutation, OrderBy, SelectedEntity, Selection, Unique};
 
 impl<T> ReaderApi for T where T: SqlExecutor + SqlGeneratorContainer + Extractor {}
 
 pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
+    /**
+    reads of a table with #[soft_delete] skip deleted rows,
+    the *_with_deleted variants read all rows and the *_only_deleted variants read deleted rows only
+    */
     async fn exists<M: Mutation>(&mut self, unique: &dyn Unique<Mutation = M>) -> Result<bool> {
-        tracing::debug!(target: "taitan_orm", command = "exists", unique = ?unique);
-        let sql = self.get_generator().get_exists_sql(unique);
+        self.exists_in_scope(unique, DeletedScope::Alive).await
+    }
+
+    async fn exists_with_deleted<M: Mutation>(&mut self, unique: &dyn Unique<Mutation = M>) -> Result<bool> {
+        self.exists_in_scope(unique, DeletedScope::WithDeleted).await
+    }
+
+    async fn exists_only_deleted<M: Mutation>(&mut self, unique: &dyn Unique<Mutation = M>) -> Result<bool> {
+        self.exists_in_scope(unique, DeletedScope::OnlyDeleted).await
+    }
+
+    async fn exists_in_scope<M: Mutation>(&mut self, unique: &dyn Unique<Mutation = M>, scope: DeletedScope) -> Result<bool> {
+        tracing::debug!(target: "taitan_orm", command = "exists", unique = ?unique, scope = ?scope);
+        let sql = self.get_generator().get_scoped_exists_sql(unique, scope);
         tracing::debug!(target: "taitan_orm", command = "exists", sql = sql);
         let args = Self::extract_unique_arguments(unique)?;
         let result: bool = self.fetch_exists(&sql, args).await?;
@@ -17,15 +36,28 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
     }
 
     async fn count(&mut self, location: &dyn Location) -> Result<u64> {
-        tracing::debug!(target: "taitan_orm", command = "count", location = ?location);
+        self.count_in_scope(location, DeletedScope::Alive).await
+    }
+
+    async fn count_with_deleted(&mut self, location: &dyn Location) -> Result<u64> {
+        self.count_in_scope(location, DeletedScope::WithDeleted).await
+    }
+
+    async fn count_only_deleted(&mut self, location: &dyn Location) -> Result<u64> {
+        self.count_in_scope(location, DeletedScope::OnlyDeleted).await
+    }
+
+    async fn count_in_scope(&mut self, location: &dyn Location, scope: DeletedScope) -> Result<u64> {
+        tracing::debug!(target: "taitan_orm", command = "count", location = ?location, scope = ?scope);
         let args = Self::extract_location_arguments(location)?;
-        let count_sql = self.get_generator().get_count_sql(location);
+        let count_sql = self.get_generator().get_scoped_count_sql(location, scope);
         tracing::debug!(target: "taitan_orm", command = "count", sql = count_sql);
         let record_count: u64 = self.fetch_count(&count_sql, args).await?;
         tracing::debug!(target: "taitan_orm", command = "count", result = ?record_count);
         Ok(record_count)
     }
 
+    // count all rows of the table by name, including rows deleted by #[soft_delete]
     async fn count_all(&mut self, table_name: &str) -> Result<u64> {
         tracing::debug!(target: "taitan_orm", command = "count", table_name = ?table_name);
         let count_sql = self.get_generator().get_count_table_sql(table_name);
@@ -35,6 +67,107 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         Ok(record_count)
     }
 
+    /**
+    aggregate of one column under location, NULL of empty set is None,
+    R must be decodable from the result of the function, the typed sum/avg/min/max cast it for you
+    */
+    async fn aggregate<R>(
+        &mut self,
+        function: AggregateFunction,
+        field_name: &str,
+        kind: Option<NumericKind>,
+        location: &dyn Location,
+    ) -> Result<Option<R>>
+    where
+        R: for<'r> Decode<'r, Self::DB> + Type<Self::DB> + std::fmt::Debug + Send + Unpin,
+        usize: ColumnIndex<<Self::DB as Database>::Row>,
+    {
+        tracing::debug!(target: "taitan_orm", command = "aggregate", function = ?function, field_name = field_name, location = ?location);
+        let sql = self.get_generator().get_aggregate_sql(function, field_name, kind, location);
+        tracing::debug!(target: "taitan_orm", command = "aggregate", sql = sql);
+        let args = Self::extract_location_arguments(location)?;
+        let result: Option<R> = self.fetch_scalar(&sql, args).await?;
+        tracing::debug!(target: "taitan_orm", command = "aggregate", result = ?result);
+        Ok(result)
+    }
+
+    // integer sum is i64, float sum is f64, decimal sum is BigDecimal
+    async fn sum<L, T>(&mut self, column: Column<L, T>, location: &L) -> Result<Option<T::Sum>>
+    where
+        L: Location,
+        T: Aggregated,
+        T::Sum: for<'r> Decode<'r, Self::DB> + Type<Self::DB> + std::fmt::Debug + Send + Unpin,
+        usize: ColumnIndex<<Self::DB as Database>::Row>,
+    {
+        self.aggregate(AggregateFunction::Sum, column.get_name(), Some(T::SUM_KIND), location).await
+    }
+
+    async fn avg<L, T>(&mut self, column: Column<L, T>, location: &L) -> Result<Option<T::Avg>>
+    where
+        L: Location,
+        T: Aggregated,
+        T::Avg: for<'r> Decode<'r, Self::DB> + Type<Self::DB> + std::fmt::Debug + Send + Unpin,
+        usize: ColumnIndex<<Self::DB as Database>::Row>,
+    {
+        self.aggregate(AggregateFunction::Avg, column.get_name(), Some(T::AVG_KIND), location).await
+    }
+
+    async fn min<L, T>(&mut self, column: Column<L, T>, location: &L) -> Result<Option<T>>
+    where
+        L: Location,
+        T: for<'r> Decode<'r, Self::DB> + Type<Self::DB> + std::fmt::Debug + Send + Unpin,
+        usize: ColumnIndex<<Self::DB as Database>::Row>,
+    {
+        self.aggregate(AggregateFunction::Min, column.get_name(), None, location).await
+    }
+
+    async fn max<L, T>(&mut self, column: Column<L, T>, location: &L) -> Result<Option<T>>
+    where
+        L: Location,
+        T: for<'r> Decode<'r, Self::DB> + Type<Self::DB> + std::fmt::Debug + Send + Unpin,
+        usize: ColumnIndex<<Self::DB as Database>::Row>,
+    {
+        self.aggregate(AggregateFunction::Max, column.get_name(), None, location).await
+    }
+
+    async fn count_distinct<L: Location, T>(&mut self, column: Column<L, T>, location: &L) -> Result<u64> {
+        tracing::debug!(target: "taitan_orm", command = "count_distinct", column = ?column, location = ?location);
+        let sql = self.get_generator().get_aggregate_sql(AggregateFunction::CountDistinct, column.get_name(), None, location);
+        tracing::debug!(target: "taitan_orm", command = "count_distinct", sql = sql);
+        let args = Self::extract_location_arguments(location)?;
+        let record_count: u64 = self.fetch_count(&sql, args).await?;
+        tracing::debug!(target: "taitan_orm", command = "count_distinct", result = ?record_count);
+        Ok(record_count)
+    }
+
+    /**
+    grouped search, each row has the group columns followed by the aggregates,
+    R can be a tuple such as (String, i64) or a struct derived by sqlx::FromRow, whose fields are named by column names and aggregate aliases,
+    group columns are column handles of the located table, such as &[&UserColumns::name()]
+    */
+    async fn search_grouped<L: Location, R>(
+        &mut self,
+        group_by: &[&dyn TableColumn<L>],
+        aggregates: &[AggregateExpr],
+        location: &L,
+        having: &Option<&dyn Location>,
+    ) -> Result<Vec<R>>
+    where
+        R: for<'r> FromRow<'r, <Self::DB as Database>::Row> + std::fmt::Debug + Send + Unpin,
+    {
+        tracing::debug!(target: "taitan_orm", command = "search_grouped", group_by = ?group_by, aggregates = ?aggregates, location = ?location, having = ?having);
+        let group_fields: Vec<&str> = group_by.iter().map(|column| column.get_name()).collect();
+        let sql = self.get_generator().get_grouped_sql(&group_fields, aggregates, location, having);
+        tracing::debug!(target: "taitan_orm", command = "search_grouped", sql = sql);
+        let args = match having {
+            Some(having) => Self::extract_joined_location_arguments(&[location, *having])?,
+            None => Self::extract_location_arguments(location)?,
+        };
+        let result: Vec<R> = self.fetch_tuples(&sql, args).await?;
+        tracing::debug!(target: "taitan_orm", command = "search_grouped", result = ?result);
+        Ok(result)
+    }
+
     async fn __select<SE, M>(
         &mut self,
         selection: &SE::Selection,
@@ -62,8 +195,45 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         M: Mutation,
         SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
     {
-        tracing::debug!(target: "taitan_orm", command = "select", primary = ?unique, selection = ?selection);
-        let sql = self.get_generator().get_select_sql(selection, unique);
+        self.select_in_scope(selection, unique, DeletedScope::Alive).await
+    }
+
+    async fn select_with_deleted<SE, M>(
+        &mut self,
+        selection: &SE,
+        unique: &dyn Unique<Mutation = M>,
+    ) -> Result<Option<SE>>
+    where
+        M: Mutation,
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        self.select_in_scope(selection, unique, DeletedScope::WithDeleted).await
+    }
+
+    async fn select_only_deleted<SE, M>(
+        &mut self,
+        selection: &SE,
+        unique: &dyn Unique<Mutation = M>,
+    ) -> Result<Option<SE>>
+    where
+        M: Mutation,
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        self.select_in_scope(selection, unique, DeletedScope::OnlyDeleted).await
+    }
+
+    async fn select_in_scope<SE, M>(
+        &mut self,
+        selection: &SE,
+        unique: &dyn Unique<Mutation = M>,
+        scope: DeletedScope,
+    ) -> Result<Option<SE>>
+    where
+        M: Mutation,
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        tracing::debug!(target: "taitan_orm", command = "select", primary = ?unique, selection = ?selection, scope = ?scope);
+        let sql = self.get_generator().get_scoped_select_sql(selection, unique, scope);
         tracing::debug!(target: "taitan_orm", command = "select", sql = sql);
         let args = Self::extract_unique_arguments(unique)?;
         let result: Option<SE> = self.fetch_option_(&sql, selection, args).await?;
@@ -102,10 +272,50 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
     where
         SE: SelectedEntity<Self::DB> + Send + Unpin  + Selection,
     {
-        tracing::debug!(target: "taitan_orm", command = "search", location = ?location, order_by = ?order_by, selection = ?selection);
+        self.search_in_scope(selection, location, order_by, page, DeletedScope::Alive).await
+    }
+
+    async fn search_with_deleted<SE>(
+        &mut self,
+        selection: &SE,
+        location: &dyn Location,
+        order_by: &Option<&dyn OrderBy>,
+        page: &Option<&crate::page::Pagination>,
+    ) -> Result<Vec<SE>>
+    where
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        self.search_in_scope(selection, location, order_by, page, DeletedScope::WithDeleted).await
+    }
+
+    async fn search_only_deleted<SE>(
+        &mut self,
+        selection: &SE,
+        location: &dyn Location,
+        order_by: &Option<&dyn OrderBy>,
+        page: &Option<&crate::page::Pagination>,
+    ) -> Result<Vec<SE>>
+    where
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        self.search_in_scope(selection, location, order_by, page, DeletedScope::OnlyDeleted).await
+    }
+
+    async fn search_in_scope<SE>(
+        &mut self,
+        selection: &SE,
+        location: &dyn Location,
+        order_by: &Option<&dyn OrderBy>,
+        page: &Option<&crate::page::Pagination>,
+        scope: DeletedScope,
+    ) -> Result<Vec<SE>>
+    where
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        tracing::debug!(target: "taitan_orm", command = "search", location = ?location, order_by = ?order_by, selection = ?selection, scope = ?scope);
         let sql =
             self.get_generator()
-                .get_search_paged_sql(selection, &Some(location), order_by, page);
+                .get_scoped_search_paged_sql(selection, &Some(location), order_by, page, scope);
         tracing::debug!(target: "taitan_orm", command = "search", sql = sql);
         let args = Self::extract_location_arguments(location)?;
         let result: Vec<SE> = self.fetch_all_(&sql, selection, args).await?;
@@ -113,6 +323,31 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         Ok(result)
     }
 
+    /**
+    stream version of search, rows are fetched and decoded lazily while polling,
+    the stream borrows self until it is dropped
+    */
+    fn search_stream<'a, SE>(
+        &'a mut self,
+        selection: &'a SE,
+        location: &'a dyn Location,
+        order_by: &Option<&dyn OrderBy>,
+    ) -> Result<LocalBoxStream<'a, Result<SE>>>
+    where
+        SE: SelectedEntity<Self::DB> + Selection + 'a,
+    {
+        tracing::debug!(target: "taitan_orm", command = "search_stream", location = ?location, order_by = ?order_by, selection = ?selection);
+        let sql = self
+            .get_generator()
+            .get_search_paged_sql(selection, &Some(location), order_by, &None);
+        tracing::debug!(target: "taitan_orm", command = "search_stream", sql = sql);
+        let args = Self::extract_location_arguments(location)?;
+        let stream = self.fetch_stream_(sql, selection, args).inspect(|result| {
+            tracing::debug!(target: "taitan_orm", command = "search_stream", result = ?result);
+        });
+        Ok(stream.boxed_local())
+    }
+
     async fn __search_paged<SE>(
         &mut self,
         selection: &SE::Selection,
@@ -125,7 +360,7 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
     {
         tracing::debug!(target: "taitan_orm", command = "search_paged", location = ?location, order_by = ?order_by, selection = ?selection, page = ?page);
         let record_count = self.count(location).await?;
-        if record_count <= 0 {
+        if record_count == 0 {
             return Ok(crate::page::PagedList::empty(page.page_size, page.page_num));
         }
 
@@ -155,7 +390,7 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
     {
         tracing::debug!(target: "taitan_orm", command = "search_paged", location = ?location, order_by = ?order_by, selection = ?selection, page = ?page);
         let record_count = self.count(location).await?;
-        if record_count <= 0 {
+        if record_count == 0 {
             return Ok(crate::page::PagedList::empty(page.page_size, page.page_num));
         }
 
@@ -173,6 +408,100 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         Ok(result)
     }
 
+    /**
+    search joined tables, selection is a tuple of selected entities, one for each table
+    locations are joined by AND, each one is qualified by its own table
+    rows are decoded into the tuple in the order of its selected entities
+    */
+    async fn search_joined<SE>(
+        &mut self,
+        joined_conds: &JoinedConditions,
+        locations: &[&dyn Location],
+        selection: &SE,
+        order_by: &Option<&dyn OrderBy>,
+    ) -> Result<Vec<SE>>
+    where
+        SE: JoinedSelected<Self::DB>,
+    {
+        tracing::debug!(target: "taitan_orm", command = "search_joined", joined_conds = ?joined_conds, locations = ?locations, order_by = ?order_by, selection = ?selection);
+        let sql = self.get_generator().get_page_joined_search_sql(
+            joined_conds,
+            locations,
+            order_by,
+            &selection.get_selections(),
+            &None,
+        );
+        tracing::debug!(target: "taitan_orm", command = "search_joined", sql = sql);
+        let args = Self::extract_joined_location_arguments(locations)?;
+        let rows = self.fetch_rows(&sql, args).await?;
+        let result = rows
+            .into_iter()
+            .map(|row| SE::select_from_row(selection, row))
+            .collect::<std::result::Result<Vec<SE>, sqlx::Error>>()?;
+        tracing::debug!(target: "taitan_orm", command = "search_joined", result = ?result);
+        Ok(result)
+    }
+
+    async fn search_joined_paged<SE>(
+        &mut self,
+        joined_conds: &JoinedConditions,
+        locations: &[&dyn Location],
+        selection: &SE,
+        order_by: &dyn OrderBy,
+        page: &crate::page::Pagination,
+    ) -> Result<crate::page::PagedList<Self::DB, SE>>
+    where
+        SE: JoinedSelected<Self::DB> + Send + Unpin,
+    {
+        tracing::debug!(target: "taitan_orm", command = "search_joined_paged", joined_conds = ?joined_conds, locations = ?locations, order_by = ?order_by, selection = ?selection, page = ?page);
+        let count_sql = self.get_generator().get_joined_count_sql(joined_conds, locations, &selection.get_selections());
+        tracing::debug!(target: "taitan_orm", command = "search_joined_paged", sql = count_sql);
+        let args = Self::extract_joined_location_arguments(locations)?;
+        let record_count = self.fetch_count(&count_sql, args).await?;
+        if record_count == 0 {
+            return Ok(crate::page::PagedList::empty(page.page_size, page.page_num));
+        }
+
+        let sql = self.get_generator().get_page_joined_search_sql(
+            joined_conds,
+            locations,
+            &Some(order_by),
+            &selection.get_selections(),
+            &Some(page),
+        );
+        tracing::debug!(target: "taitan_orm", command = "search_joined_paged", sql = sql);
+        let args = Self::extract_joined_location_arguments(locations)?;
+        let rows = self.fetch_rows(&sql, args).await?;
+        let entity_list = rows
+            .into_iter()
+            .map(|row| SE::select_from_row(selection, row))
+            .collect::<std::result::Result<Vec<SE>, sqlx::Error>>()?;
+        let result = taitan_orm_trait::build_paged_list(entity_list, record_count, page);
+        tracing::debug!(target: "taitan_orm", command = "search_joined_paged", result = ?result);
+        Ok(result)
+    }
+
+    fn devour_stream<'a, SE>(
+        &'a mut self,
+        selection: &'a SE,
+        order_by: &Option<&dyn OrderBy>,
+    ) -> Result<LocalBoxStream<'a, Result<SE>>>
+    where
+        SE: SelectedEntity<Self::DB> + Selection + 'a,
+    {
+        tracing::debug!(target: "taitan_orm", command = "devour_stream", selection = ?selection);
+        let sql = self
+            .get_generator()
+            .get_search_paged_sql(selection, &None, order_by, &None);
+        tracing::debug!(target: "taitan_orm", command = "devour_stream", sql = sql);
+        let stream = self
+            .fetch_stream_(sql, selection, Default::default())
+            .inspect(|result| {
+                tracing::debug!(target: "taitan_orm", command = "devour_stream", result = ?result);
+            });
+        Ok(stream.boxed_local())
+    }
+
     async fn __devour<SE>(
         &mut self,
         selection: &SE::Selection,
@@ -211,10 +540,47 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
     where
         SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
     {
-        tracing::debug!(target: "taitan_orm", command = "devour", selection = ?selection);
+        self.devour_in_scope(selection, order_by, page, DeletedScope::Alive).await
+    }
+
+    async fn devour_with_deleted<SE>(
+        &mut self,
+        selection: &SE,
+        order_by: &Option<&dyn OrderBy>,
+        page: &Option<&crate::page::Pagination>,
+    ) -> Result<Vec<SE>>
+    where
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        self.devour_in_scope(selection, order_by, page, DeletedScope::WithDeleted).await
+    }
+
+    async fn devour_only_deleted<SE>(
+        &mut self,
+        selection: &SE,
+        order_by: &Option<&dyn OrderBy>,
+        page: &Option<&crate::page::Pagination>,
+    ) -> Result<Vec<SE>>
+    where
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        self.devour_in_scope(selection, order_by, page, DeletedScope::OnlyDeleted).await
+    }
+
+    async fn devour_in_scope<SE>(
+        &mut self,
+        selection: &SE,
+        order_by: &Option<&dyn OrderBy>,
+        page: &Option<&crate::page::Pagination>,
+        scope: DeletedScope,
+    ) -> Result<Vec<SE>>
+    where
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        tracing::debug!(target: "taitan_orm", command = "devour", selection = ?selection, scope = ?scope);
         let sql = self
             .get_generator()
-            .get_search_paged_sql(selection, &None, order_by, page);
+            .get_scoped_search_paged_sql(selection, &None, order_by, page, scope);
         tracing::debug!(target: "taitan_orm", command = "devour", sql = sql);
         match page {
             None => {
@@ -241,8 +607,9 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         SE: SelectedEntity<Self::DB> + Send + Unpin,
     {
         tracing::debug!(target: "taitan_orm", command = "devour_paged", order_by = ?order_by, selection = ?selection, page = ?page);
-        let record_count = self.count_all(selection.get_table_name()).await?;
-        if record_count <= 0 {
+        let count_sql = self.get_generator().get_scoped_count_table_sql(selection, DeletedScope::Alive);
+        let record_count = self.fetch_count_plain(&count_sql).await?;
+        if record_count == 0 {
             return Ok(crate::page::PagedList::empty(page.page_size, page.page_num));
         }
 
@@ -271,8 +638,9 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
     {
         tracing::debug!(target: "taitan_orm", command = "devour_paged", order_by = ?order_by, selection = ?selection, page = ?page);
-        let record_count = self.count_all(selection.get_table_name()).await?;
-        if record_count <= 0 {
+        let count_sql = self.get_generator().get_scoped_count_table_sql(selection, DeletedScope::Alive);
+        let record_count = self.fetch_count_plain(&count_sql).await?;
+        if record_count == 0 {
             return Ok(crate::page::PagedList::empty(page.page_size, page.page_num));
         }
 
@@ -290,4 +658,71 @@ pub trait ReaderApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         tracing::debug!(target: "taitan_orm", command = "devour_paged", result = ?result);
         Ok(result)
     }
+
+    /**
+    keyset pagination, seek by order by fields instead of offset, no count query is executed
+    order_by must contain an unique key, and its fields must be selected to build next/prev cursor
+    */
+    async fn search_cursor_paged<SE>(
+        &mut self,
+        selection: &SE,
+        location: &dyn Location,
+        order_by: &dyn OrderBy,
+        page: &crate::page::CursorPagination,
+    ) -> Result<crate::page::CursorPagedList<Self::DB, SE>>
+    where
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        tracing::debug!(target: "taitan_orm", command = "search_cursor_paged", location = ?location, order_by = ?order_by, selection = ?selection, page = ?page);
+        let cursor = page.decode_cursor()?;
+        let direction = cursor.as_ref().map(|cursor| cursor.direction);
+        let sql = self.get_generator().get_search_cursor_sql(
+            selection,
+            &Some(location),
+            order_by,
+            &direction,
+            page.page_size + 1,
+        );
+        tracing::debug!(target: "taitan_orm", command = "search_cursor_paged", sql = sql);
+        let mut args = Self::extract_location_arguments(location)?;
+        if let Some(cursor) = &cursor {
+            let (seek_fields, seek_values) = cursor.get_seek_arguments(order_by)?;
+            SE::add_cursor_arguments(&seek_fields, &seek_values, &mut args)?;
+        }
+        let entity_list: Vec<SE> = self.fetch_all_(&sql, selection, args).await?;
+        let result = taitan_orm_trait::build_cursor_paged_list(entity_list, order_by, page.page_size, direction)?;
+        tracing::debug!(target: "taitan_orm", command = "search_cursor_paged", result = ?result);
+        Ok(result)
+    }
+
+    async fn devour_cursor_paged<SE>(
+        &mut self,
+        selection: &SE,
+        order_by: &dyn OrderBy,
+        page: &crate::page::CursorPagination,
+    ) -> Result<crate::page::CursorPagedList<Self::DB, SE>>
+    where
+        SE: SelectedEntity<Self::DB> + Send + Unpin + Selection,
+    {
+        tracing::debug!(target: "taitan_orm", command = "devour_cursor_paged", order_by = ?order_by, selection = ?selection, page = ?page);
+        let cursor = page.decode_cursor()?;
+        let direction = cursor.as_ref().map(|cursor| cursor.direction);
+        let sql = self.get_generator().get_search_cursor_sql(
+            selection,
+            &None,
+            order_by,
+            &direction,
+            page.page_size + 1,
+        );
+        tracing::debug!(target: "taitan_orm", command = "devour_cursor_paged", sql = sql);
+        let mut args = <Self::DB as sqlx::Database>::Arguments::default();
+        if let Some(cursor) = &cursor {
+            let (seek_fields, seek_values) = cursor.get_seek_arguments(order_by)?;
+            SE::add_cursor_arguments(&seek_fields, &seek_values, &mut args)?;
+        }
+        let entity_list: Vec<SE> = self.fetch_all_(&sql, selection, args).await?;
+        let result = taitan_orm_trait::build_cursor_paged_list(entity_list, order_by, page.page_size, direction)?;
+        tracing::debug!(target: "taitan_orm", command = "devour_cursor_paged", result = ?result);
+        Ok(result)
+    }
 }
@@ -2,6 +2,7 @@ use crate::extractor::Extractor;
 use crate::{Result, SqlExecutor, SqlGenerator, SqlGeneratorContainer};
 use taitan_orm_trait::{Entity, Location, Mutation, Unique};
 use tracing::debug;
+use futures::stream::{LocalBoxStream, StreamExt};
 use crate::api::writer::WriterApi;
 
 impl<T> TemplateApi for T where T: SqlExecutor + SqlGeneratorContainer + Extractor {}
@@ -12,7 +13,7 @@ pub trait TemplateApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         template: &dyn crate::traits::TemplateRecord,
     ) -> crate::Result<u64> {
         debug!(target: "taitan_orm", command = "execute_by_template", template = ?template);
-        let sql = template.get_sql(None);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
         let sql = self.get_generator().post_process(sql);
         debug!(target: "taitan_orm", command = "execute_by_template", sql = sql);
         let args = Self::extract_template_arguments(template)?;
@@ -29,7 +30,7 @@ pub trait TemplateApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         SE: crate::traits::SelectedEntity<Self::DB> + Send + Unpin,
     {
         debug!(target: "taitan_orm", command = "procedure_by_template", template = ?template);
-        let sql = template.get_sql(None);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
         let sql = self.get_generator().post_process(sql);
         debug!(target: "taitan_orm", command = "procedure_by_template", sql = sql);
         let args = Self::extract_template_arguments(template)?;
@@ -46,7 +47,7 @@ pub trait TemplateApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         SE: crate::traits::SelectedEntity<Self::DB> + Send + Unpin,
     {
         debug!(target: "taitan_orm", command = "select_by_template", template = ?template);
-        let sql = template.get_sql(None);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
         let sql = self.get_generator().post_process(sql);
         debug!(target: "taitan_orm", command = "select_by_template", sql = sql);
         let args = Self::extract_template_arguments(template)?;
@@ -63,7 +64,7 @@ pub trait TemplateApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         SE: crate::traits::SelectedEntity<Self::DB> + Send + Unpin,
     {
         debug!(target: "taitan_orm", command = "search_by_template", template = ?template);
-        let sql = template.get_sql(None);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
         let sql = self.get_generator().post_process(sql);
         debug!(target: "taitan_orm", command = "search_by_template", sql = sql);
         let args = Self::extract_template_arguments(template)?;
@@ -72,6 +73,24 @@ pub trait TemplateApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         Ok(result)
     }
 
+    fn fetch_stream_by_template<'a, SE>(
+        &'a mut self,
+        template: &'a dyn crate::traits::TemplateRecord,
+    ) -> crate::Result<LocalBoxStream<'a, crate::Result<SE>>>
+    where
+        SE: crate::traits::SelectedEntity<Self::DB> + 'a,
+    {
+        debug!(target: "taitan_orm", command = "stream_by_template", template = ?template);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
+        let sql = self.get_generator().post_process(sql);
+        debug!(target: "taitan_orm", command = "stream_by_template", sql = sql);
+        let args = Self::extract_template_arguments(template)?;
+        let stream = self.fetch_stream_full(sql, args).inspect(|result| {
+            debug!(target: "taitan_orm", command = "stream_by_template", result = ?result);
+        });
+        Ok(stream.boxed_local())
+    }
+
     async fn fetch_paged_by_template<SE>(
         &mut self,
         template: &dyn crate::traits::TemplateRecord,
@@ -81,7 +100,7 @@ pub trait TemplateApi: SqlExecutor + SqlGeneratorContainer + Extractor {
     {
         debug!(target: "taitan_orm", command = "search_paged_by_template", template = ?template);
         let count_sql = template
-            .get_count_sql()
+            .render_count_sql(self.get_generator().get_wrap_char())?
             .ok_or(crate::TaitanOrmError::TemplatePagedNotHasCountSql)?;
         let count_sql = self.get_generator().post_process(count_sql);
         debug!(target: "taitan_orm", command = "search_paged_by_template", count_sql = count_sql);
@@ -91,11 +110,11 @@ pub trait TemplateApi: SqlExecutor + SqlGeneratorContainer + Extractor {
 
         let count_args = Self::extract_template_count_arguments(template)?;
         let record_count: u64 = self.fetch_count(&count_sql, count_args).await?;
-        if record_count <= 0 {
+        if record_count == 0 {
             return Ok(crate::page::PagedList::empty(page.page_size, page.page_num));
         }
 
-        let sql = template.get_sql(Some(page));
+        let sql = template.render_sql(Some(page), self.get_generator().get_wrap_char())?;
         let sql = self.get_generator().post_process(sql);
         debug!(target: "taitan_orm", command = "search_paged_by_template", sql = sql);
         let args = Self::extract_template_arguments(template)?;
@@ -115,4 +134,157 @@ pub trait TemplateApi: SqlExecutor + SqlGeneratorContainer + Extractor {
         debug!(target: "taitan_orm", command = "search_paged_by_template", result = ?result);
         Ok(result)
     }
+
+    async fn fetch_scalar_by_template<T>(
+        &mut self,
+        template: &dyn crate::traits::TemplateRecord,
+    ) -> crate::Result<T>
+    where
+        T: for<'r> sqlx::Decode<'r, Self::DB> + sqlx::Type<Self::DB> + std::fmt::Debug + Send + Unpin,
+        usize: sqlx::ColumnIndex<<Self::DB as sqlx::Database>::Row>,
+    {
+        debug!(target: "taitan_orm", command = "fetch_scalar_by_template", template = ?template);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
+        let sql = self.get_generator().post_process(sql);
+        debug!(target: "taitan_orm", command = "fetch_scalar_by_template", sql = sql);
+        let args = Self::extract_template_arguments(template)?;
+        let result: T = self.fetch_scalar(&sql, args).await?;
+        debug!(target: "taitan_orm", command = "fetch_scalar_by_template", result = ?result);
+        Ok(result)
+    }
+
+    async fn fetch_scalars_by_template<T>(
+        &mut self,
+        template: &dyn crate::traits::TemplateRecord,
+    ) -> crate::Result<Vec<T>>
+    where
+        T: for<'r> sqlx::Decode<'r, Self::DB> + sqlx::Type<Self::DB> + std::fmt::Debug + Send + Unpin,
+        usize: sqlx::ColumnIndex<<Self::DB as sqlx::Database>::Row>,
+    {
+        debug!(target: "taitan_orm", command = "fetch_scalars_by_template", template = ?template);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
+        let sql = self.get_generator().post_process(sql);
+        debug!(target: "taitan_orm", command = "fetch_scalars_by_template", sql = sql);
+        let args = Self::extract_template_arguments(template)?;
+        let result: Vec<T> = self.fetch_scalars(&sql, args).await?;
+        debug!(target: "taitan_orm", command = "fetch_scalars_by_template", result = ?result);
+        Ok(result)
+    }
+
+    async fn fetch_tuple_by_template<T>(
+        &mut self,
+        template: &dyn crate::traits::TemplateRecord,
+    ) -> crate::Result<T>
+    where
+        T: for<'r> sqlx::FromRow<'r, <Self::DB as sqlx::Database>::Row> + std::fmt::Debug + Send + Unpin,
+    {
+        debug!(target: "taitan_orm", command = "fetch_tuple_by_template", template = ?template);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
+        let sql = self.get_generator().post_process(sql);
+        debug!(target: "taitan_orm", command = "fetch_tuple_by_template", sql = sql);
+        let args = Self::extract_template_arguments(template)?;
+        let result: T = self.fetch_tuple(&sql, args).await?;
+        debug!(target: "taitan_orm", command = "fetch_tuple_by_template", result = ?result);
+        Ok(result)
+    }
+
+    async fn fetch_tuples_by_template<T>(
+        &mut self,
+        template: &dyn crate::traits::TemplateRecord,
+    ) -> crate::Result<Vec<T>>
+    where
+        T: for<'r> sqlx::FromRow<'r, <Self::DB as sqlx::Database>::Row> + std::fmt::Debug + Send + Unpin,
+    {
+        debug!(target: "taitan_orm", command = "fetch_tuples_by_template", template = ?template);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
+        let sql = self.get_generator().post_process(sql);
+        debug!(target: "taitan_orm", command = "fetch_tuples_by_template", sql = sql);
+        let args = Self::extract_template_arguments(template)?;
+        let result: Vec<T> = self.fetch_tuples(&sql, args).await?;
+        debug!(target: "taitan_orm", command = "fetch_tuples_by_template", result = ?result);
+        Ok(result)
+    }
+
+    // rows are decoded into the #[output] type of the template, no SelectedEntity is needed at call site
+    async fn query<O>(&mut self, template: &dyn crate::traits::OutputTemplateRecord<Output = O>) -> crate::Result<Vec<O>>
+    where
+        O: std::fmt::Debug,
+    {
+        debug!(target: "taitan_orm", command = "query", template = ?template);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
+        let sql = self.get_generator().post_process(sql);
+        debug!(target: "taitan_orm", command = "query", sql = sql);
+        let args = Self::extract_template_arguments(template)?;
+        let rows = self.fetch_rows(&sql, args).await?;
+        let result = rows
+            .into_iter()
+            .map(|row| Self::extract_template_output(template, row))
+            .collect::<crate::Result<Vec<O>>>()?;
+        debug!(target: "taitan_orm", command = "query", result = ?result);
+        Ok(result)
+    }
+
+    async fn query_option<O>(&mut self, template: &dyn crate::traits::OutputTemplateRecord<Output = O>) -> crate::Result<Option<O>>
+    where
+        O: std::fmt::Debug,
+    {
+        debug!(target: "taitan_orm", command = "query_option", template = ?template);
+        let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
+        let sql = self.get_generator().post_process(sql);
+        debug!(target: "taitan_orm", command = "query_option", sql = sql);
+        let args = Self::extract_template_arguments(template)?;
+        let result = match self.fetch_row_option(&sql, args).await? {
+            Some(row) => Some(Self::extract_template_output(template, row)?),
+            None => None,
+        };
+        debug!(target: "taitan_orm", command = "query_option", result = ?result);
+        Ok(result)
+    }
+
+    async fn query_paged<O>(
+        &mut self,
+        template: &dyn crate::traits::OutputTemplateRecord<Output = O>,
+    ) -> crate::Result<crate::page::PagedList<Self::DB, O>>
+    where
+        O: std::fmt::Debug,
+    {
+        debug!(target: "taitan_orm", command = "query_paged", template = ?template);
+        let count_sql = template
+            .render_count_sql(self.get_generator().get_wrap_char())?
+            .ok_or(crate::TaitanOrmError::TemplatePagedNotHasCountSql)?;
+        let count_sql = self.get_generator().post_process(count_sql);
+        debug!(target: "taitan_orm", command = "query_paged", count_sql = count_sql);
+        let page = template
+            .get_pagination()
+            .ok_or(crate::TaitanOrmError::TemplatePageFieldNotFound)?;
+
+        let count_args = Self::extract_template_count_arguments(template)?;
+        let record_count: u64 = self.fetch_count(&count_sql, count_args).await?;
+        if record_count == 0 {
+            return Ok(crate::page::PagedList::empty(page.page_size, page.page_num));
+        }
+
+        let sql = template.render_sql(Some(page), self.get_generator().get_wrap_char())?;
+        let sql = self.get_generator().post_process(sql);
+        debug!(target: "taitan_orm", command = "query_paged", sql = sql);
+        let args = Self::extract_template_arguments(template)?;
+        let rows = self.fetch_rows(&sql, args).await?;
+        let data = rows
+            .into_iter()
+            .map(|row| Self::extract_template_output(template, row))
+            .collect::<crate::Result<Vec<O>>>()?;
+
+        let result = crate::page::PagedList {
+            data,
+            page: crate::page::PagedInfo {
+                page_size: page.page_size,
+                page_num: page.page_num,
+                page_total: record_count / page.page_size,
+                total: record_count,
+            },
+            _phantom: std::marker::PhantomData,
+        };
+        debug!(target: "taitan_orm", command = "query_paged", result = ?result);
+        Ok(result)
+    }
 }
@@ -1,68 +1,383 @@
 use crate::extractor::Extractor;
-use crate::{Result, SqlExecutor, SqlGenerator, SqlGeneratorContainer};
-use taitan_orm_trait::{Entity, Location, Mutation, Unique};
+use crate::{Result, SqlExecutor, TaitanOrmError, SqlGenerator, SqlGeneratorContainer};
+use taitan_orm_trait::{DeletedScope, Entity, Location, Mutation, Unique};
+use time::OffsetDateTime;
 use tracing::debug;
 use crate::api::reader::ReaderApi;
+use crate::api::hook::HookContext;
 
 impl<T> WriterApi for T where T: SqlExecutor + SqlGeneratorContainer + Extractor {}
 
 pub trait WriterApi: SqlExecutor + SqlGeneratorContainer + Extractor {
     async fn insert(&mut self, entity: &dyn Entity) -> Result<bool> {
         debug!(target: "taitan_orm", command = "insert", entity = ?entity);
+        let mut written = entity.clone_for_writes();
+        if let Some(written) = written.as_mut() {
+            if let Some(hooks) = written.get_hooks_mut() {
+                hooks.before_insert(&mut HookContext { executor: self }).await?;
+            }
+            written.fill_auto_timestamps(OffsetDateTime::now_utc());
+        }
+        let entity = written.as_deref().unwrap_or(entity);
         let sql = self.get_generator().get_insert_sql(entity);
         debug!(target: "taitan_orm", command = "insert", sql = sql);
         let args = Self::extract_insert_arguments(entity)?;
         let result = self.execute(&sql, args).await?;
         debug!(target: "taitan_orm", command = "insert", result = ?result);
+        if let (true, Some(hooks)) = (result > 0, entity.get_hooks()) {
+            hooks.after_insert(&mut HookContext { executor: self }).await?;
+        }
         Ok(result > 0)
     }
+
+    /**
+    insert entity and write auto increment field and #[generated] fields back
+    1. database support RETURNING: fetch the returning row in one statement
+    2. otherwise: use last insert id, and re-select generated fields by it
+    3. without auto increment id: re-select generated fields by primary key or unique key given by entity
+    */
+    async fn create(&mut self, entity: &mut dyn Entity) -> Result<bool> {
+        debug!(target: "taitan_orm", command = "create", entity = ?entity);
+        if let Some(hooks) = entity.get_hooks_mut() {
+            hooks.before_insert(&mut HookContext { executor: self }).await?;
+        }
+        // the entity holds the written timestamps
+        entity.fill_auto_timestamps(OffsetDateTime::now_utc());
+        let sql = self.get_generator().get_create_sql(entity);
+        debug!(target: "taitan_orm", command = "create", sql = sql);
+        let has_auto_field = entity.get_auto_increment_field().is_some();
+        let has_generated_fields = !entity.get_generated_fields().is_empty();
+        if !has_auto_field && !has_generated_fields {
+            let args = Self::extract_insert_arguments(entity)?;
+            let result = self.execute(&sql, args).await?;
+            debug!(target: "taitan_orm", command = "create", result = ?result);
+            if let (true, Some(hooks)) = (result > 0, entity.get_hooks()) {
+                hooks.after_insert(&mut HookContext { executor: self }).await?;
+            }
+            return Ok(result > 0);
+        }
+
+        if self.get_generator().is_returning_supported() {
+            let args = Self::extract_insert_arguments(entity)?;
+            let row_opt = self.fetch_row_option(&sql, args).await?;
+            let Some(row) = row_opt else {
+                debug!(target: "taitan_orm", command = "create", result = 0);
+                return Ok(false);
+            };
+            Self::extract_returning_fields(entity, &row)?;
+            debug!(target: "taitan_orm", command = "create", result = ?entity);
+            if let Some(hooks) = entity.get_hooks() {
+                hooks.after_insert(&mut HookContext { executor: self }).await?;
+            }
+            return Ok(true);
+        }
+
+        let args = Self::extract_insert_arguments(entity)?;
+        let (result, last_insert_id) = self.execute_with_id(&sql, args).await?;
+        debug!(target: "taitan_orm", command = "create", result = ?result, last_insert_id = ?last_insert_id);
+        if result == 0 {
+            return Ok(false);
+        }
+        let auto_id = if has_auto_field { last_insert_id } else { None };
+        if let Some(auto_id) = auto_id {
+            if !entity.set_auto_increment_field(Some(auto_id)) {
+                return Err(TaitanOrmError::AutoIncrementOutOfRange(auto_id));
+            }
+        }
+        if has_generated_fields {
+            let row_opt = match auto_id {
+                Some(auto_id) => match self.get_generator().get_generated_select_sql(entity, auto_id) {
+                    Some(select_sql) => {
+                        debug!(target: "taitan_orm", command = "create", sql = select_sql);
+                        self.fetch_row_option(&select_sql, Default::default()).await?
+                    }
+                    None => None,
+                },
+                None => match self.get_generator().get_generated_reselect_sql(entity) {
+                    Some(select_sql) => {
+                        debug!(target: "taitan_orm", command = "create", sql = select_sql);
+                        let args = Self::extract_reselect_arguments(entity)?;
+                        self.fetch_row_option(&select_sql, args).await?
+                    }
+                    None => None,
+                },
+            };
+            if let Some(row) = row_opt {
+                Self::extract_returning_fields(entity, &row)?;
+            }
+        }
+        debug!(target: "taitan_orm", command = "create", result = ?entity);
+        if let Some(hooks) = entity.get_hooks() {
+            hooks.after_insert(&mut HookContext { executor: self }).await?;
+        }
+        Ok(true)
+    }
+
+    /**
+    insert the entity, or update all its fields when the row exists
+    #[version] field is not checked by upsert, it is written as it is in entity (last writer wins),
+    use update with the expected version when concurrent modification must be detected
+    */
     async fn upsert(&mut self, entity: &dyn Entity) -> Result<bool> {
         debug!(target: "taitan_orm", command = "upsert", entity = ?entity);
+        let mut written = entity.clone_for_writes();
+        if let Some(written) = written.as_mut() {
+            if let Some(hooks) = written.get_hooks_mut() {
+                hooks.before_insert(&mut HookContext { executor: self }).await?;
+            }
+            written.fill_auto_timestamps(OffsetDateTime::now_utc());
+        }
+        let entity = written.as_deref().unwrap_or(entity);
         let sql = self.get_generator().get_upsert_sql(entity);
         debug!(target: "taitan_orm", command = "upsert", sql = sql);
         let args = Self::extract_upsert_arguments(entity)?;
         let result = self.execute(&sql, args).await?;
         debug!(target: "taitan_orm", command = "upsert", result = ?result);
+        if let (true, Some(hooks)) = (result > 0, entity.get_hooks()) {
+            hooks.after_insert(&mut HookContext { executor: self }).await?;
+        }
         Ok(result > 0)
     }
+    /**
+    multi-row insert, entities are split into several statements when
+    1. insert fields of entity differ from the previous one
+    2. bind parameters exceed the limit of database
+    statements are not wrapped in a transaction, use it in a transaction if atomicity is required
+
+    before_insert hooks of all entities run before the first statement,
+    after_insert hooks of entities in a batch run after the statement of the batch affects rows
+    */
+    async fn insert_all<E: Entity>(&mut self, entities: &[E]) -> Result<u64> {
+        debug!(target: "taitan_orm", command = "insert_all", size = entities.len());
+        let max_params = self.get_generator().get_max_bind_params();
+        let mut written: Vec<Box<dyn Entity + '_>> = entities.iter().filter_map(|entity| entity.clone_for_writes()).collect();
+        for entity in written.iter_mut() {
+            if let Some(hooks) = entity.get_hooks_mut() {
+                hooks.before_insert(&mut HookContext { executor: self }).await?;
+            }
+        }
+        // all rows are written with the same timestamps
+        let now = OffsetDateTime::now_utc();
+        for entity in written.iter_mut() {
+            entity.fill_auto_timestamps(now);
+        }
+        let rows: Vec<&dyn Entity> = if written.is_empty() {
+            entities.iter().map(|entity| entity as &dyn Entity).collect()
+        } else {
+            written.iter().map(|entity| entity.as_ref()).collect()
+        };
+        let mut result = 0;
+        for batch in split_insert_batches(&rows, max_params) {
+            let sql = self.get_generator().get_insert_all_sql(batch[0], batch.len());
+            debug!(target: "taitan_orm", command = "insert_all", sql = sql);
+            let args = Self::extract_insert_all_arguments(batch)?;
+            let batch_result = self.execute(&sql, args).await?;
+            result += batch_result;
+            for entity in batch.iter() {
+                if let (true, Some(hooks)) = (batch_result > 0, entity.get_hooks()) {
+                    hooks.after_insert(&mut HookContext { executor: self }).await?;
+                }
+            }
+        }
+        debug!(target: "taitan_orm", command = "insert_all", result = ?result);
+        Ok(result)
+    }
+
+    async fn upsert_all<E: Entity>(&mut self, entities: &[E]) -> Result<u64> {
+        debug!(target: "taitan_orm", command = "upsert_all", size = entities.len());
+        let max_params = self.get_generator().get_max_bind_params();
+        let mut written: Vec<Box<dyn Entity + '_>> = entities.iter().filter_map(|entity| entity.clone_for_writes()).collect();
+        for entity in written.iter_mut() {
+            if let Some(hooks) = entity.get_hooks_mut() {
+                hooks.before_insert(&mut HookContext { executor: self }).await?;
+            }
+        }
+        // all rows are written with the same timestamps
+        let now = OffsetDateTime::now_utc();
+        for entity in written.iter_mut() {
+            entity.fill_auto_timestamps(now);
+        }
+        let rows: Vec<&dyn Entity> = if written.is_empty() {
+            entities.iter().map(|entity| entity as &dyn Entity).collect()
+        } else {
+            written.iter().map(|entity| entity.as_ref()).collect()
+        };
+        let mut result = 0;
+        for batch in split_insert_batches(&rows, max_params) {
+            let sql = self.get_generator().get_upsert_all_sql(batch[0], batch.len());
+            debug!(target: "taitan_orm", command = "upsert_all", sql = sql);
+            let args = Self::extract_insert_all_arguments(batch)?;
+            let batch_result = self.execute(&sql, args).await?;
+            result += batch_result;
+            for entity in batch.iter() {
+                if let (true, Some(hooks)) = (batch_result > 0, entity.get_hooks()) {
+                    hooks.after_insert(&mut HookContext { executor: self }).await?;
+                }
+            }
+        }
+        debug!(target: "taitan_orm", command = "upsert_all", result = ?result);
+        Ok(result)
+    }
+
+    /**
+    update fields of mutation on the row located by unique
+    if the expected #[version] of mutation is Some, the row is updated only when its version equals to it,
+    and Err(TaitanOrmError::StaleVersion) is returned when the row exists with another version,
+    so the caller can reload the row and retry. #[version] field is increased by 1 in every update
+    */
     async fn update<M: Mutation>(
         &mut self,
         mutation: &M,
         unique: &dyn Unique<Mutation = M>,
     ) -> Result<bool> {
         debug!(target: "taitan_orm", command = "update", mutation = ?mutation, primary = ?unique);
+        let mut hooked = mutation.clone_for_hooks();
+        if let Some(hooks) = hooked.as_mut().and_then(|hooked| hooked.get_hooks_mut()) {
+            hooks.before_update(&mut HookContext { executor: self }).await?;
+        }
+        let mutation = hooked.as_ref().unwrap_or(mutation);
         let sql = self.get_generator().get_update_sql(mutation, unique);
         debug!(target: "taitan_orm", command = "update", sql = sql);
         let args = Self::extract_update_arguments(mutation, unique)?;
         let result = self.execute(&sql, args).await?;
         debug!(target: "taitan_orm", command = "update", result = ?result);
+        if let (true, Some(hooks)) = (result > 0, mutation.get_hooks()) {
+            hooks.after_update(&mut HookContext { executor: self }).await?;
+        }
+        if result == 0 && mutation.is_version_checked() {
+            let exists_sql = self.get_generator().get_scoped_exists_sql(unique, DeletedScope::WithDeleted);
+            let exists_args = Self::extract_unique_arguments(unique)?;
+            if self.fetch_exists(&exists_sql, exists_args).await? {
+                return Err(TaitanOrmError::StaleVersion);
+            }
+        }
         Ok(result > 0)
     }
-    async fn change<M: Mutation>(&mut self, mutation: &M, location: &M::Location) -> Result<u64> {
+    /**
+    update fields of mutation on rows matched by location
+    location can be the generated location of the mutation's table, a condition of its columns,
+    or a LocationTree composed of them, such as UserColumns::age().gt(18).or(UserColumns::name().like("a%"))
+    if the expected #[version] of mutation is Some, only rows with that version are updated,
+    rows with other versions are skipped silently, check the returned count to detect conflicts
+    */
+    async fn change<M: Mutation>(&mut self, mutation: &M, location: &dyn Location) -> Result<u64> {
         debug!(target: "taitan_orm", command = "change", mutation = ?mutation, location = ?location);
+        let mut hooked = mutation.clone_for_hooks();
+        if let Some(hooks) = hooked.as_mut().and_then(|hooked| hooked.get_hooks_mut()) {
+            hooks.before_update(&mut HookContext { executor: self }).await?;
+        }
+        let mutation = hooked.as_ref().unwrap_or(mutation);
         let sql = self.get_generator().get_change_sql(mutation, location);
         debug!(target: "taitan_orm", command = "change", sql = sql);
         let args = Self::extract_change_arguments(mutation, location)?;
         let result = self.execute(&sql, args).await?;
         debug!(target: "taitan_orm", command = "change", result = ?result);
+        if let (true, Some(hooks)) = (result > 0, mutation.get_hooks()) {
+            hooks.after_update(&mut HookContext { executor: self }).await?;
+        }
         Ok(result)
     }
+    /**
+    delete the row located by unique, on a table with #[soft_delete] the alive row is marked as deleted,
+    deleting a row which is already deleted returns false
+    */
     async fn delete<M: Mutation>(&mut self, unique: &dyn Unique<Mutation = M>) -> Result<bool> {
         debug!(target: "taitan_orm", command = "delete", primary = ?unique);
+        let mut hooked = unique.clone_for_hooks();
+        if let Some(hooks) = hooked.as_mut().and_then(|hooked| hooked.get_hooks_mut()) {
+            hooks.before_delete(&mut HookContext { executor: self }).await?;
+        }
+        let unique = hooked.as_deref().unwrap_or(unique);
         let sql = self.get_generator().get_delete_sql(unique);
         debug!(target: "taitan_orm", command = "delete", sql = sql);
         let args = Self::extract_delete_arguments(unique)?;
         let result = self.execute(&sql, args).await?;
         debug!(target: "taitan_orm", command = "delete", result = ?result);
+        if let (true, Some(hooks)) = (result > 0, unique.get_hooks()) {
+            hooks.after_delete(&mut HookContext { executor: self }).await?;
+        }
         Ok(result > 0)
     }
+    /**
+    delete rows matched by location, on a table with #[soft_delete] alive rows are marked as deleted
+    */
     async fn purify(&mut self, location: &dyn Location) -> Result<u64> {
         debug!(target: "taitan_orm", command = "purify", location = ?location);
+        let mut hooked = location.clone_for_hooks();
+        if let Some(hooks) = hooked.as_mut().and_then(|hooked| hooked.get_hooks_mut()) {
+            hooks.before_delete(&mut HookContext { executor: self }).await?;
+        }
+        let location = hooked.as_deref().unwrap_or(location);
         let sql = self.get_generator().get_purify_sql(location);
         debug!(target: "taitan_orm", command = "purify", sql = sql);
         let args = Self::extract_purify_arguments(location)?;
         let result = self.execute(&sql, args).await?;
         debug!(target: "taitan_orm", command = "purify", result = ?result);
+        if let (true, Some(hooks)) = (result > 0, location.get_hooks()) {
+            hooks.after_delete(&mut HookContext { executor: self }).await?;
+        }
         Ok(result)
     }
+
+    // DELETE FROM even if the table has a #[soft_delete] column, deleted rows can be removed too
+    async fn hard_delete<M: Mutation>(&mut self, unique: &dyn Unique<Mutation = M>) -> Result<bool> {
+        debug!(target: "taitan_orm", command = "hard_delete", primary = ?unique);
+        let mut hooked = unique.clone_for_hooks();
+        if let Some(hooks) = hooked.as_mut().and_then(|hooked| hooked.get_hooks_mut()) {
+            hooks.before_delete(&mut HookContext { executor: self }).await?;
+        }
+        let unique = hooked.as_deref().unwrap_or(unique);
+        let sql = self.get_generator().get_hard_delete_sql(unique);
+        debug!(target: "taitan_orm", command = "hard_delete", sql = sql);
+        let args = Self::extract_delete_arguments(unique)?;
+        let result = self.execute(&sql, args).await?;
+        debug!(target: "taitan_orm", command = "hard_delete", result = ?result);
+        if let (true, Some(hooks)) = (result > 0, unique.get_hooks()) {
+            hooks.after_delete(&mut HookContext { executor: self }).await?;
+        }
+        Ok(result > 0)
+    }
+
+    async fn hard_purify(&mut self, location: &dyn Location) -> Result<u64> {
+        debug!(target: "taitan_orm", command = "hard_purify", location = ?location);
+        let mut hooked = location.clone_for_hooks();
+        if let Some(hooks) = hooked.as_mut().and_then(|hooked| hooked.get_hooks_mut()) {
+            hooks.before_delete(&mut HookContext { executor: self }).await?;
+        }
+        let location = hooked.as_deref().unwrap_or(location);
+        let sql = self.get_generator().get_hard_purify_sql(location);
+        debug!(target: "taitan_orm", command = "hard_purify", sql = sql);
+        let args = Self::extract_purify_arguments(location)?;
+        let result = self.execute(&sql, args).await?;
+        debug!(target: "taitan_orm", command = "hard_purify", result = ?result);
+        if let (true, Some(hooks)) = (result > 0, location.get_hooks()) {
+            hooks.after_delete(&mut HookContext { executor: self }).await?;
+        }
+        Ok(result)
+    }
+}
+
+// split entities into batches sharing the same insert fields, each batch binds no more than max_params
+fn split_insert_batches<'a, 'e>(entities: &'a [&'e dyn Entity], max_params: usize) -> Vec<&'a [&'e dyn Entity]> {
+    let mut batches = Vec::new();
+    let mut start = 0;
+    let mut batch_fields = Vec::new();
+    let mut batch_params = 0;
+    for (index, entity) in entities.iter().enumerate() {
+        let fields = entity.get_insert_fields();
+        let params = fields.iter().filter(|field| !field.is_null).count();
+        if index > start && (fields != batch_fields || batch_params + params > max_params) {
+            batches.push(&entities[start..index]);
+            start = index;
+            batch_params = 0;
+        }
+        if index == start {
+            batch_fields = fields;
+        }
+        batch_params += params;
+    }
+    if start < entities.len() {
+        batches.push(&entities[start..]);
+    }
+    batches
 }
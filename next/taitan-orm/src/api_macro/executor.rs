@@ -24,6 +24,68 @@ macro_rules! executor_impl {
             Self::generic_execute_plain(&mut *ex, stmt, args).await
         }
 
+        fn fetch_stream_<'a, SE>(
+            &'a mut self,
+            stmt: String,
+            selection: &'a SE,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> futures::stream::LocalBoxStream<'a, crate::Result<SE>>
+        where
+            SE: taitan_orm_trait::SelectedEntity<Self::DB> + 'a,
+        {
+            Box::pin(async_stream::try_stream! {
+                let pool = self.get_pool()?;
+                let mut rows = sqlx::query_with(&stmt, args).fetch(pool);
+                while let Some(row) = futures::TryStreamExt::try_next(&mut rows).await? {
+                    yield SE::select_from_row(selection, row)?;
+                }
+            })
+        }
+
+        fn fetch_stream_full<'a, SE>(
+            &'a mut self,
+            stmt: String,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> futures::stream::LocalBoxStream<'a, crate::Result<SE>>
+        where
+            SE: taitan_orm_trait::SelectedEntity<Self::DB> + 'a,
+        {
+            Box::pin(async_stream::try_stream! {
+                let pool = self.get_pool()?;
+                let mut rows = sqlx::query_with(&stmt, args).fetch(pool);
+                while let Some(row) = futures::TryStreamExt::try_next(&mut rows).await? {
+                    yield SE::from_row_full(row)?;
+                }
+            })
+        }
+
+        async fn execute_with_id<'a>(
+            &'a mut self,
+            stmt: &'a str,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> crate::Result<(u64, Option<i64>)> {
+            let mut ex = self.get_connection().await?;
+            Self::generic_execute_with_id(&mut *ex, stmt, args).await
+        }
+
+        async fn fetch_row_option<'a>(
+            &'a mut self,
+            stmt: &'a str,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> crate::Result<Option<<Self::DB as sqlx::Database>::Row>> {
+            let mut ex = self.get_connection().await?;
+            Self::generic_fetch_row_option(&mut *ex, stmt, args).await
+        }
+
+        async fn fetch_rows<'a>(
+            &'a mut self,
+            stmt: &'a str,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> crate::Result<Vec<<Self::DB as sqlx::Database>::Row>> {
+            let mut ex = self.get_connection().await?;
+            Self::generic_fetch_rows(&mut *ex, stmt, args).await
+        }
+
         async fn fetch_count<'s, 'a>(
             &'a mut self,
             stmt: &'s str,
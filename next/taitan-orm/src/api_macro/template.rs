@@ -3,7 +3,7 @@ macro_rules! execute_by_template_fn {
     ($gen_args_fn:path) => {
         async fn execute_by_template(&mut self, template: &dyn crate::traits::TemplateRecord) -> crate::Result<usize> {
             tracing::debug!(target: "taitan_orm", command = "execute_by_template", template = ?template);
-            let sql = template.get_sql(None);
+            let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
             let sql = self.get_generator().post_process(sql);
             tracing::debug!(target: "taitan_orm", command = "execute_by_template", sql = sql);
             let args = ($gen_args_fn)(template)?;
@@ -23,7 +23,7 @@ macro_rules! fetch_one_by_template_fn {
             SE: crate::traits::SelectedEntity<Self::DB> + Send + Unpin,
         {
             tracing::debug!(target: "taitan_orm", command = "procedure_by_template", template = ?template);
-            let sql = template.get_sql(None);
+            let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
             let sql = self.get_generator().post_process(sql);
             tracing::debug!(target: "taitan_orm", command = "procedure_by_template", sql = sql);
             let args = ($gen_args_fn)(template)?;
@@ -42,7 +42,7 @@ macro_rules! fetch_option_by_template_fn {
             SE: crate::traits::SelectedEntity<Self::DB> + Send + Unpin,
         {
             tracing::debug!(target: "taitan_orm", command = "select_by_template", template = ?template);
-            let sql = template.get_sql(None);
+            let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
             let sql = self.get_generator().post_process(sql);
             tracing::debug!(target: "taitan_orm", command = "select_by_template", sql = sql);
             let args = ($gen_args_fn)(template)?;
@@ -62,7 +62,7 @@ macro_rules! fetch_all_by_template_fn {
             SE: crate::traits::SelectedEntity<Self::DB> + Send + Unpin,
         {
             tracing::debug!(target: "taitan_orm", command = "search_by_template", template = ?template);
-            let sql = template.get_sql(None);
+            let sql = template.render_sql(None, self.get_generator().get_wrap_char())?;
             let sql = self.get_generator().post_process(sql);
             tracing::debug!(target: "taitan_orm", command = "search_by_template", sql = sql);
             let args = ($gen_args_fn)(template)?;
@@ -86,7 +86,7 @@ macro_rules! fetch_paged_by_template_fn {
         {
             tracing::debug!(target: "taitan_orm", command = "search_paged_by_template", template = ?template);
             let count_sql = template
-                .get_count_sql()
+                .render_count_sql(self.get_generator().get_wrap_char())?
                 .ok_or(crate::TaitanOrmError::TemplatePagedNotHasCountSql)?;
             let count_sql = self.get_generator().post_process(count_sql);
             tracing::debug!(target: "taitan_orm", command = "search_paged_by_template", count_sql = count_sql);
@@ -102,7 +102,7 @@ macro_rules! fetch_paged_by_template_fn {
                 return Ok(crate::page::PagedList::empty(page.page_size, page.page_num));
             }
 
-            let sql = template.get_sql(Some(page));
+            let sql = template.render_sql(Some(page), self.get_generator().get_wrap_char())?;
             let sql = self.get_generator().post_process(sql);
             tracing::debug!(target: "taitan_orm", command = "search_paged_by_template", sql = sql);
             let args = ($gen_args_fn)(template)?;
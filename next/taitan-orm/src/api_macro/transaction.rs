@@ -17,6 +17,63 @@ macro_rules! transaction_impl {
             Self::generic_execute_plain(&mut *(self.transaction), stmt, args).await
         }
 
+        fn fetch_stream_<'a, SE>(
+            &'a mut self,
+            stmt: String,
+            selection: &'a SE,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> futures::stream::LocalBoxStream<'a, crate::Result<SE>>
+        where
+            SE: taitan_orm_trait::SelectedEntity<Self::DB> + 'a,
+        {
+            Box::pin(async_stream::try_stream! {
+                let mut rows = sqlx::query_with(&stmt, args).fetch(&mut *self.transaction);
+                while let Some(row) = futures::TryStreamExt::try_next(&mut rows).await? {
+                    yield SE::select_from_row(selection, row)?;
+                }
+            })
+        }
+
+        fn fetch_stream_full<'a, SE>(
+            &'a mut self,
+            stmt: String,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> futures::stream::LocalBoxStream<'a, crate::Result<SE>>
+        where
+            SE: taitan_orm_trait::SelectedEntity<Self::DB> + 'a,
+        {
+            Box::pin(async_stream::try_stream! {
+                let mut rows = sqlx::query_with(&stmt, args).fetch(&mut *self.transaction);
+                while let Some(row) = futures::TryStreamExt::try_next(&mut rows).await? {
+                    yield SE::from_row_full(row)?;
+                }
+            })
+        }
+
+        async fn execute_with_id<'a>(
+            &'a mut self,
+            stmt: &'a str,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> crate::Result<(u64, Option<i64>)> {
+            Self::generic_execute_with_id(&mut *self.transaction, stmt, args).await
+        }
+
+        async fn fetch_row_option<'a>(
+            &'a mut self,
+            stmt: &'a str,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> crate::Result<Option<<Self::DB as sqlx::Database>::Row>> {
+            Self::generic_fetch_row_option(&mut *self.transaction, stmt, args).await
+        }
+
+        async fn fetch_rows<'a>(
+            &'a mut self,
+            stmt: &'a str,
+            args: <Self::DB as sqlx::Database>::Arguments<'a>,
+        ) -> crate::Result<Vec<<Self::DB as sqlx::Database>::Row>> {
+            Self::generic_fetch_rows(&mut *self.transaction, stmt, args).await
+        }
+
         async fn fetch_exists<'a>(
             &'a mut self,
             stmt: &'a str,
@@ -25,6 +25,10 @@ impl SqlGenericExecutor for MySqlDatabase {
         query_result.rows_affected()
     }
 
+    fn get_last_insert_id(query_result: &<Self::DB as sqlx::Database>::QueryResult) -> Option<i64> {
+        Some(query_result.last_insert_id() as i64)
+    }
+
 }
 // SqlExecutor + SqlGeneratorContainer + Extractor
 impl SqlGeneratorContainer for MySqlDatabase {
@@ -34,6 +34,10 @@ impl<'t> SqlGenericExecutor for MySqlTransaction<'t> {
     fn get_affected_rows(query_result: &<Self::DB as sqlx::Database>::QueryResult) -> u64 {
         query_result.rows_affected()
     }
+
+    fn get_last_insert_id(query_result: &<Self::DB as sqlx::Database>::QueryResult) -> Option<i64> {
+        Some(query_result.last_insert_id() as i64)
+    }
 }
 
 impl<'t> SqlExecutor for MySqlTransaction<'t> {
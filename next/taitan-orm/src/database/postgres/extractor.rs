@@ -1,63 +1,111 @@
 use sqlx::Database;
-use taitan_orm_trait::{Entity, Location, Mutation, TemplateRecord, Unique};
+use taitan_orm_trait::{Entity, Location, Mutation, OutputTemplateRecord, TemplateRecord, Unique};
 use taitan_orm_trait::pagination::Pagination;
 use crate::database::postgres::database::PostgresDatabase;
+use crate::database::postgres::transaction::PostgresTransaction;
 use crate::extractor::Extractor;
 
-impl Extractor for PostgresDatabase {
-
-    #[inline(always)]
-    fn extract_pagination_arguments(page: &Pagination) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(page.gen_page_arguments_postgres()?)
-    }
-
-    #[inline(always)]
-    fn extract_unique_arguments<M: Mutation>(unique: &dyn Unique<Mutation=M>) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(unique.gen_unique_arguments_postgres()?)
-    }
-
-    #[inline(always)]
-    fn extract_location_arguments(location: &dyn Location) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(location.gen_location_arguments_postgres()?)
-    }
-
-    #[inline(always)]
-    fn extract_insert_arguments(entity: &dyn Entity) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(entity.gen_insert_arguments_postgres()?)
-    }
-
-    #[inline(always)]
-    fn extract_upsert_arguments(entity: &dyn Entity) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(entity.gen_upsert_arguments_postgres()?)
-    }
-
-    #[inline(always)]
-    fn extract_update_arguments<'a, M: Mutation>(mutation: &'a M, unique: &'a dyn Unique<Mutation=M>) -> crate::Result<<Self::DB as Database>::Arguments<'a>> {
-        Ok(unique.gen_update_arguments_postgres(mutation)?)
-    }
-
-    #[inline(always)]
-    fn extract_change_arguments<'a, M: Mutation>(mutation: &'a M, location: &'a M::Location) -> crate::Result<<Self::DB as Database>::Arguments<'a>> {
-        Ok(mutation.gen_change_arguments_postgres(location)?)
-    }
-
-    #[inline(always)]
-    fn extract_delete_arguments<M: Mutation>(unique: &dyn Unique<Mutation=M>) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(unique.gen_unique_arguments_postgres()?)
-    }
-
-    #[inline(always)]
-    fn extract_purify_arguments(location: &dyn Location) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(location.gen_location_arguments_postgres()?)
-    }
-
-    #[inline(always)]
-    fn extract_template_arguments(template: &dyn TemplateRecord) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(template.gen_template_arguments_postgres()?)
-    }
-
-    #[inline(always)]
-    fn extract_template_count_arguments(template: &dyn TemplateRecord) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
-        Ok(template.gen_template_count_arguments_postgres()?)
-    }
-}
\ No newline at end of file
+// database and transaction share the same argument extraction
+macro_rules! postgres_extractor_impl {
+    ($t:ty) => {
+        impl Extractor for $t {
+
+            #[inline(always)]
+            fn extract_pagination_arguments(page: &Pagination) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(page.gen_page_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_unique_arguments<M: Mutation>(unique: &dyn Unique<Mutation=M>) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(unique.gen_unique_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_location_arguments(location: &dyn Location) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(location.gen_location_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_joined_location_arguments<'a>(locations: &[&'a dyn Location]) -> crate::Result<<Self::DB as Database>::Arguments<'a>> {
+                let mut args = sqlx::postgres::PgArguments::default();
+                for location in locations {
+                    location.add_location_arguments_postgres(&mut args)?;
+                }
+                Ok(args)
+            }
+
+            #[inline(always)]
+            fn extract_insert_arguments(entity: &dyn Entity) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(entity.gen_insert_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_upsert_arguments(entity: &dyn Entity) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(entity.gen_upsert_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_insert_all_arguments<'a>(entities: &[&'a dyn Entity]) -> crate::Result<<Self::DB as Database>::Arguments<'a>> {
+                let mut args = sqlx::postgres::PgArguments::default();
+                for entity in entities {
+                    entity.add_insert_arguments_postgres(&mut args)?;
+                }
+                Ok(args)
+            }
+
+            #[inline(always)]
+            fn extract_update_arguments<'a, M: Mutation>(mutation: &'a M, unique: &'a dyn Unique<Mutation=M>) -> crate::Result<<Self::DB as Database>::Arguments<'a>> {
+                let mut args = unique.gen_update_arguments_postgres(mutation)?;
+                mutation.add_version_arguments_postgres(&mut args)?;
+                Ok(args)
+            }
+
+            #[inline(always)]
+            fn extract_change_arguments<'a, M: Mutation>(mutation: &'a M, location: &'a dyn Location) -> crate::Result<<Self::DB as Database>::Arguments<'a>> {
+                let mut args = sqlx::postgres::PgArguments::default();
+                mutation.add_mutation_arguments_postgres(&mut args)?;
+                location.add_location_arguments_postgres(&mut args)?;
+                mutation.add_version_arguments_postgres(&mut args)?;
+                Ok(args)
+            }
+
+            #[inline(always)]
+            fn extract_delete_arguments<M: Mutation>(unique: &dyn Unique<Mutation=M>) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(unique.gen_unique_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_purify_arguments(location: &dyn Location) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(location.gen_location_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_template_arguments(template: &dyn TemplateRecord) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(template.gen_template_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_template_count_arguments(template: &dyn TemplateRecord) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(template.gen_template_count_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_template_output<O>(template: &dyn OutputTemplateRecord<Output = O>, row: <Self::DB as Database>::Row) -> crate::Result<O> {
+                Ok(template.decode_output_postgres(row)?)
+            }
+
+            #[inline(always)]
+            fn extract_reselect_arguments(entity: &dyn Entity) -> crate::Result<<Self::DB as Database>::Arguments<'_>> {
+                Ok(entity.gen_reselect_arguments_postgres()?)
+            }
+
+            #[inline(always)]
+            fn extract_returning_fields(entity: &mut dyn Entity, row: &<Self::DB as Database>::Row) -> crate::Result<()> {
+                Ok(entity.set_generated_fields_postgres(row)?)
+            }
+        }
+    };
+}
+
+postgres_extractor_impl!(PostgresDatabase);
+postgres_extractor_impl!(PostgresTransaction<'_>);
@@ -1,6 +1,6 @@
 use crate::database::sqlite::commanders::read::SqliteReadCommander;
 use crate::database::sqlite::{SqliteLocalConfig, SqliteTransaction, SqliteWriteCommander};
-use crate::sql_generator::DefaultSqlGenerator;
+use crate::sql_generator::{DefaultSqlGenerator, SQLITE_LEGACY_MAX_BIND_PARAMS, SQLITE_MAX_BIND_PARAMS};
 use crate::sql_generator_container::SqlGeneratorContainer;
 use crate::{executor_impl, CountResult, SqlExecutor, SqlGenericExecutor, TaitanOrmError};
 use path_absolutize::Absolutize;
@@ -38,9 +38,21 @@ impl SqliteDatabase {
         Ok(sqlite_pool)
     }
 
+    // sqlite before 3.32.0 allows only 999 bind parameters in a statement
+    async fn get_max_bind_params(pool: &SqlitePool) -> crate::Result<usize> {
+        let (version,): (String,) = sqlx::query_as("SELECT sqlite_version()").fetch_one(pool).await?;
+        let mut parts = version.split('.').map(|part| part.parse::<u32>().unwrap_or(0));
+        let major_minor = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+        if major_minor < (3, 32) {
+            Ok(SQLITE_LEGACY_MAX_BIND_PARAMS)
+        } else {
+            Ok(SQLITE_MAX_BIND_PARAMS)
+        }
+    }
+
     pub async fn build(config: SqliteLocalConfig<'_>) -> crate::Result<SqliteDatabase> {
         let pool = SqliteDatabase::init_local(&config.work_dir, &config.db_file).await?;
-        let generator = DefaultSqlGenerator::new();
+        let generator = DefaultSqlGenerator::with_max_bind_params(SqliteDatabase::get_max_bind_params(&pool).await?);
         let database = SqliteDatabase {
             sql_generator: generator,
             sqlite_pool: pool,
@@ -71,6 +83,10 @@ impl SqlGenericExecutor for SqliteDatabase {
     fn get_affected_rows(query_result: &<Self::DB as sqlx::Database>::QueryResult) -> u64 {
         query_result.rows_affected()
     }
+
+    fn get_last_insert_id(query_result: &<Self::DB as sqlx::Database>::QueryResult) -> Option<i64> {
+        Some(query_result.last_insert_rowid())
+    }
 }
 impl SqlExecutor for SqliteDatabase {
     executor_impl!(SqliteConnection);
@@ -40,6 +40,10 @@ impl<'t> SqlGenericExecutor for SqliteTransaction<'t> {
     fn get_affected_rows(query_result: &<Self::DB as sqlx::Database>::QueryResult) -> u64 {
         query_result.rows_affected()
     }
+
+    fn get_last_insert_id(query_result: &<Self::DB as sqlx::Database>::QueryResult) -> Option<i64> {
+        Some(query_result.last_insert_rowid())
+    }
 }
 
 impl<'t> crate::SqlExecutor for SqliteTransaction<'t> {
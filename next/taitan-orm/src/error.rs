@@ -26,6 +26,12 @@ pub enum TaitanOrmError {
     #[error("deserialize entity from row  error")]
     FromRowToEntityError,
 
+    #[error(transparent)]
+    NotValidCursor(#[from] taitan_orm_trait::NotValidCursorError),
+
+    #[error(transparent)]
+    NotValidTemplate(#[from] taitan_orm_trait::NotValidTemplateError),
+
     #[error("invalid order by fields")]
     OrderByFieldsError,
 
@@ -37,4 +43,10 @@ pub enum TaitanOrmError {
 
     #[error("dynamic request parse error: {0}")]
     DynamicRequestParseError(String),
+
+    #[error("auto increment id {0} is out of range of the field type")]
+    AutoIncrementOutOfRange(i64),
+
+    #[error("stale version, the row has been updated by others")]
+    StaleVersion,
 }
@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 use sqlx::Database;
 
-use taitan_orm_trait::{Entity, Location, Mutation, TemplateRecord, Unique};
+use taitan_orm_trait::{Entity, Location, Mutation, OutputTemplateRecord, TemplateRecord, Unique};
 use taitan_orm_trait::pagination::Pagination;
 use crate::SqlGenericExecutor;
 use crate::Result;
@@ -10,12 +10,21 @@ pub trait Extractor: SqlGenericExecutor {
     fn extract_pagination_arguments(page: &Pagination)-> Result<<Self::DB as Database>::Arguments<'_>>;
     fn extract_unique_arguments<M: Mutation>(unique: &dyn Unique<Mutation = M>) -> Result<<Self::DB as Database>::Arguments<'_>>;
     fn extract_location_arguments(location: &dyn Location) -> Result<<Self::DB as Database>::Arguments<'_>>;
+    // arguments of all locations in a joined search, in the order of locations
+    fn extract_joined_location_arguments<'a>(locations: &[&'a dyn Location]) -> Result<<Self::DB as Database>::Arguments<'a>>;
     fn extract_insert_arguments(entity: &dyn Entity) -> Result<<Self::DB as Database>::Arguments<'_>>;
     fn extract_upsert_arguments(entity: &dyn Entity) -> Result<<Self::DB as Database>::Arguments<'_>>;
+    fn extract_insert_all_arguments<'a>(entities: &[&'a dyn Entity]) -> Result<<Self::DB as Database>::Arguments<'a>>;
     fn extract_update_arguments<'a, M: Mutation>(mutation: &'a M, unique: &'a dyn Unique<Mutation = M>) -> Result<<Self::DB as Database>::Arguments<'a>>;
-    fn extract_change_arguments<'a, M: Mutation>(mutation: &'a M, location: &'a M::Location) -> Result<<Self::DB as Database>::Arguments<'a>>;
+    fn extract_change_arguments<'a, M: Mutation>(mutation: &'a M, location: &'a dyn Location) -> Result<<Self::DB as Database>::Arguments<'a>>;
     fn extract_delete_arguments<M: Mutation>(unique: &dyn Unique<Mutation = M>) -> Result<<Self::DB as Database>::Arguments<'_>>;
     fn extract_purify_arguments(location: &dyn Location) -> Result<<Self::DB as Database>::Arguments<'_>>;
     fn extract_template_arguments(template: &dyn TemplateRecord) -> Result<<Self::DB as Database>::Arguments<'_>>;
     fn extract_template_count_arguments(template: &dyn TemplateRecord) -> Result<<Self::DB as Database>::Arguments<'_>>;
+    // decode a row into the #[output] type of the template
+    fn extract_template_output<O>(template: &dyn OutputTemplateRecord<Output = O>, row: <Self::DB as Database>::Row) -> Result<O>;
+    // primary key or unique key of entity, which generated fields are re-selected by
+    fn extract_reselect_arguments(entity: &dyn Entity) -> Result<<Self::DB as Database>::Arguments<'_>>;
+    // write auto increment field and generated fields in the returning row back to entity
+    fn extract_returning_fields(entity: &mut dyn Entity, row: &<Self::DB as Database>::Row) -> Result<()>;
 }
\ No newline at end of file
@@ -21,6 +21,7 @@ mod api;
 
 
 pub use taitan_orm_trait::Optional;
+pub use taitan_orm_trait::Assign;
 
 // pub use db::DB;
 pub use dto::CountResult;
@@ -45,15 +46,26 @@ pub mod page {
     pub use taitan_orm_trait::paged_info::PagedInfo;
     pub use taitan_orm_trait::paged_list::PagedList;
     pub use taitan_orm_trait::build_paged_list;
+    pub use taitan_orm_trait::CursorPagination;
+    pub use taitan_orm_trait::CursorPagedList;
 }
 
 pub mod traits {
-    pub use taitan_orm_trait::{CountSql, Entity, Location, LocationExpr, Mutation, OrderBy, Schema, SelectedEntity, Selection, Unique};
+    pub use taitan_orm_trait::{Column, CountSql, Entity, Location, LocationExpr, LocationTree, Mutation, OrderBy, Schema, SelectedEntity, Selection, TableColumn, Unique};
+    pub use taitan_orm_trait::{Cursor, CursorCodec, CursorDirection, CursorValue, NotValidCursorError};
     pub use taitan_orm_trait::validate_order_by;
+    pub use taitan_orm_trait::{JoinedCondition, JoinedConditionArray, JoinedConditionPart, JoinedConditions, JoinedField, JoinedMode, JoinedSelected};
+    pub use taitan_orm_trait::{parse_order_by, FieldOrder, NullsOrder, OrderDirection};
+    pub use taitan_orm_trait::{AggregateExpr, AggregateFunction, Aggregated, Having, NumericKind};
+    pub use taitan_orm_trait::{AutoTimestamp, CopySource, DeletedScope, SoftDelete};
+    pub use taitan_orm_trait::{HookExecutor, HookFuture, WriteHooks};
+    pub use taitan_orm_trait::{collect_relation_keys, group_many, group_one, RelationKey};
     pub use taitan_orm_trait::pagination::Pagination;
     pub use taitan_orm_trait::paged_info::PagedInfo;
     pub use taitan_orm_trait::paged_list::PagedList;
+    pub use taitan_orm_trait::{CursorPagedList, CursorPagination};
     pub use taitan_orm_trait::ParsedTemplateSql;
     pub use taitan_orm_trait::TemplateValue;
-    pub use taitan_orm_trait::TemplateRecord;
+    pub use taitan_orm_trait::{OutputTemplateRecord, TemplateRecord};
+    pub use taitan_orm_trait::{keyword_raw_ident, list_place_holders, quote_raw_ident, NotValidTemplateError};
 }
\ No newline at end of file
@@ -41,7 +41,7 @@ pub trait SqlApi {
     async fn insert(&mut self, entity: &dyn Entity) -> Result<bool>;
     async fn upsert(&mut self, entity: &dyn Entity) -> Result<bool>;
     async fn update<M: Mutation>(&mut self, mutation: &M, unique: &dyn Unique<Mutation = M>) -> Result<bool>;
-    async fn change<M: Mutation>(&mut self, mutation: &M, location: &M::Location) -> Result<u64>;
+    async fn change<M: Mutation>(&mut self, mutation: &M, location: &dyn Location) -> Result<u64>;
     async fn delete<M: Mutation>(&mut self, unique: &dyn Unique<Mutation = M>) -> Result<bool>;
     async fn purify(&mut self, location: &dyn Location) -> Result<u64>;
 
@@ -1,6 +1,7 @@
 use std::ops::DerefMut;
+use futures::stream::LocalBoxStream;
 use crate::result::Result;
-use sqlx::{Connection, Database, Executor, IntoArguments};
+use sqlx::{ColumnIndex, Connection, Database, Decode, Executor, FromRow, IntoArguments, Row, Type};
 use sqlx::pool::PoolConnection;
 use crate::sql_generic_executor::SqlGenericExecutor;
 use taitan_orm_trait::SelectedEntity;
@@ -14,6 +15,9 @@ use crate::TaitanOrmError::NotImplement;
 本模块提供以下接口方法
 execute           (stmt, args) -> Result<u64>
 execute_plain     (stmt      ) -> Result<u64>
+execute_with_id   (stmt, args) -> Result<(u64, Option<i64>)>
+fetch_row_option  (stmt, args) -> Result<Option<Row>>
+fetch_rows        (stmt, args) -> Result<Vec<Row>>
 
 fetch_exists            (stmt, args) -> Result<bool>
 fetch_exists_plain      (stmt,     ) -> Result<bool>
@@ -31,6 +35,14 @@ fetch_one_full         (stmt, args) -> Result<SE>
 fetch_one_full_plain   (stmt,     ) -> Result<SE>
 fetch_option_full      (stmt, args) -> Result<Option<SE>>
 fetch_option_full_plain(stmt,     ) -> Result<Option<SE>>
+
+fetch_stream_          (stmt, se, args) -> LocalBoxStream<Result<SE>>
+fetch_stream_full      (stmt,     args) -> LocalBoxStream<Result<SE>>
+
+fetch_scalar      (stmt, args) -> Result<T>         first column of the only row, T: Decode
+fetch_scalars     (stmt, args) -> Result<Vec<T>>    first column of every row
+fetch_tuple       (stmt, args) -> Result<T>         the only row, T: FromRow such as (i64, String)
+fetch_tuples      (stmt, args) -> Result<Vec<T>>    every row
 */
 pub trait SqlExecutor: SqlGenericExecutor {
 
@@ -50,6 +62,27 @@ pub trait SqlExecutor: SqlGenericExecutor {
     // execute_plain     (stmt, _   ) -> Result<u64>
     async fn execute_plain<'a>(&'a mut self, stmt: &'a str) -> Result<u64>;
 
+    // execute_with_id   (stmt, args) -> Result<(u64, Option<i64>)>
+    async fn execute_with_id<'a>(
+        &'a mut self,
+        stmt: &'a str,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> Result<(u64, Option<i64>)>;
+
+    // fetch_row_option  (stmt, args) -> Result<Option<Row>>
+    async fn fetch_row_option<'a>(
+        &'a mut self,
+        stmt: &'a str,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> Result<Option<<Self::DB as Database>::Row>>;
+
+    // fetch_rows        (stmt, args) -> Result<Vec<Row>>
+    async fn fetch_rows<'a>(
+        &'a mut self,
+        stmt: &'a str,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> Result<Vec<<Self::DB as Database>::Row>>;
+
 
     // fetch_exists            (stmt, args) -> Result<bool>
     async fn fetch_exists<'a>(
@@ -211,4 +244,86 @@ pub trait SqlExecutor: SqlGenericExecutor {
 
     // fetch_one         (stmt, selection, args) -> Result<SE>
     // fetch_one_plain   (stmt, selection, _   ) -> Result<SE>
+
+    // fetch_stream_          (stmt, se, args) -> LocalBoxStream<Result<SE>>
+    // stmt is owned by the stream, rows are decoded one by one while polling
+    fn fetch_stream_<'a, SE>(
+        &'a mut self,
+        stmt: String,
+        selection: &'a SE,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> LocalBoxStream<'a, Result<SE>>
+    where
+        SE: SelectedEntity<Self::DB> + 'a;
+
+    // fetch_stream_full      (stmt,     args) -> LocalBoxStream<Result<SE>>
+    fn fetch_stream_full<'a, SE>(
+        &'a mut self,
+        stmt: String,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> LocalBoxStream<'a, Result<SE>>
+    where
+        SE: SelectedEntity<Self::DB> + 'a;
+
+    // fetch_scalar      (stmt, args) -> Result<T>, no row is RowNotFound, NULL needs T = Option<_>
+    async fn fetch_scalar<'a, T>(
+        &'a mut self,
+        stmt: &'a str,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> Result<T>
+    where
+        T: for<'r> Decode<'r, Self::DB> + Type<Self::DB> + Send + Unpin,
+        usize: ColumnIndex<<Self::DB as Database>::Row>,
+    {
+        let row = self.fetch_row_option(stmt, args).await?.ok_or(sqlx::Error::RowNotFound)?;
+        Ok(row.try_get::<T, usize>(0)?)
+    }
+
+    // fetch_scalars     (stmt, args) -> Result<Vec<T>>
+    async fn fetch_scalars<'a, T>(
+        &'a mut self,
+        stmt: &'a str,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> Result<Vec<T>>
+    where
+        T: for<'r> Decode<'r, Self::DB> + Type<Self::DB> + Send + Unpin,
+        usize: ColumnIndex<<Self::DB as Database>::Row>,
+    {
+        let rows = self.fetch_rows(stmt, args).await?;
+        let values = rows
+            .iter()
+            .map(|row| row.try_get::<T, usize>(0))
+            .collect::<std::result::Result<Vec<T>, sqlx::Error>>()?;
+        Ok(values)
+    }
+
+    // fetch_tuple       (stmt, args) -> Result<T>, no row is RowNotFound
+    async fn fetch_tuple<'a, T>(
+        &'a mut self,
+        stmt: &'a str,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> Result<T>
+    where
+        T: for<'r> FromRow<'r, <Self::DB as Database>::Row> + Send + Unpin,
+    {
+        let row = self.fetch_row_option(stmt, args).await?.ok_or(sqlx::Error::RowNotFound)?;
+        Ok(T::from_row(&row)?)
+    }
+
+    // fetch_tuples      (stmt, args) -> Result<Vec<T>>
+    async fn fetch_tuples<'a, T>(
+        &'a mut self,
+        stmt: &'a str,
+        args: <Self::DB as Database>::Arguments<'a>,
+    ) -> Result<Vec<T>>
+    where
+        T: for<'r> FromRow<'r, <Self::DB as Database>::Row> + Send + Unpin,
+    {
+        let rows = self.fetch_rows(stmt, args).await?;
+        let values = rows
+            .iter()
+            .map(|row| T::from_row(row))
+            .collect::<std::result::Result<Vec<T>, sqlx::Error>>()?;
+        Ok(values)
+    }
 }
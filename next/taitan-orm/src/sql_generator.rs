@@ -4,15 +4,39 @@ use taitan_orm_trait::{FieldName, FromClause};
 use taitan_orm_trait::JoinedConditions;
 use taitan_orm_trait::{Entity, Location, Mutation, Unique};
 use taitan_orm_trait::{OrderBy, Selection};
+use taitan_orm_trait::{AggregateExpr, AggregateFunction, NumericKind};
+use taitan_orm_trait::{DeletedScope, SoftDelete};
+use taitan_orm_trait::replace_place_holders;
+use taitan_orm_trait::{is_seek_uniform, CursorDirection, FieldOrder, NullsOrder, OrderDirection};
 
-#[derive(Default, Debug, Clone)]
-pub struct DefaultSqlGenerator {}
+#[derive(Debug, Clone)]
+pub struct DefaultSqlGenerator {
+    max_bind_params: usize,
+}
 impl DefaultSqlGenerator {
     pub fn new() -> Self {
-        Self {}
+        Self { max_bind_params: SQLITE_MAX_BIND_PARAMS }
+    }
+
+    // SqliteDatabase lowers the limit to 999 when the linked sqlite is older than 3.32.0
+    pub fn with_max_bind_params(max_bind_params: usize) -> Self {
+        Self { max_bind_params }
+    }
+}
+impl Default for DefaultSqlGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SqlGenerator for DefaultSqlGenerator {
+    fn get_max_bind_params(&self) -> usize {
+        self.max_bind_params
     }
 }
-impl SqlGenerator for DefaultSqlGenerator {}
+
+// SQLITE_MAX_VARIABLE_NUMBER is 32766 since sqlite 3.32.0, and 999 before
+pub(crate) const SQLITE_MAX_BIND_PARAMS: usize = 32766;
+pub(crate) const SQLITE_LEGACY_MAX_BIND_PARAMS: usize = 999;
 
 #[derive(Default, Debug, Clone)]
 pub struct MySqlGenerator {}
@@ -22,6 +46,75 @@ impl MySqlGenerator {
     }
 }
 impl SqlGenerator for MySqlGenerator {
+    fn is_returning_supported(&self) -> bool {
+        false
+    }
+
+    // mysql has no NULLS FIRST/LAST, sort by `f` IS NULL first instead
+    fn get_order_field_sql(&self, wrapped_field: &str, order: FieldOrder) -> String {
+        let direction = match order.direction {
+            OrderDirection::Asc => "",
+            OrderDirection::Desc => " DESC",
+        };
+        let nulls = match order.nulls {
+            NullsOrder::Default => "",
+            NullsOrder::First => " DESC",
+            NullsOrder::Last => " ASC",
+        };
+        match order.nulls {
+            NullsOrder::Default => format!("{}{}", wrapped_field, direction),
+            _ => format!("{} IS NULL{},{}{}", wrapped_field, nulls, wrapped_field, direction),
+        }
+    }
+
+    fn get_max_bind_params(&self) -> usize {
+        65535
+    }
+
+    // SUM/AVG of integer is DECIMAL in mysql
+    fn get_numeric_cast_type(&self, kind: NumericKind) -> Option<&'static str> {
+        match kind {
+            NumericKind::Integer => Some("SIGNED"),
+            NumericKind::Float => Some("DOUBLE"),
+            NumericKind::Decimal => None,
+        }
+    }
+
+    fn get_upsert_all_sql(&self, entity: &dyn Entity, rows: usize) -> String {
+        let table_name = entity.get_table_name();
+        let field_names = entity.get_insert_fields();
+        let fields = wrap_field_names(&field_names, self.get_wrap_char());
+        let values = generate_values_list_from_names(&field_names, rows);
+        let assign_clause = entity
+            .get_upsert_set_fields()
+            .iter()
+            .map(|e| {
+                format!(
+                    "{}{}{} = VALUES({}{}{})",
+                    self.get_wrap_char(),
+                    e.database_field_name(),
+                    self.get_wrap_char(),
+                    self.get_wrap_char(),
+                    e.database_field_name(),
+                    self.get_wrap_char()
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let upsert_sql = format!(
+            "INSERT INTO {}{}{} ({}) VALUES{} ON DUPLICATE KEY UPDATE {}",
+            self.get_wrap_char(),
+            table_name,
+            self.get_wrap_char(),
+            fields,
+            values,
+            assign_clause
+        )
+        .to_string();
+        self.post_process(upsert_sql)
+    }
+
     fn get_upsert_sql(&self, entity: &dyn Entity) -> String {
         let table_name = entity.get_table_name();
         let field_names = entity.get_insert_fields();
@@ -74,9 +167,27 @@ impl PostgresGenerator {
     }
 }
 impl SqlGenerator for PostgresGenerator {
+    // backtick is not an identifier quote in postgres
+    fn get_wrap_char(&self) -> char {
+        '"'
+    }
+
     fn post_process(&self, origin: String) -> String {
         self.pg_post_process(origin)
     }
+
+    fn get_max_bind_params(&self) -> usize {
+        65535
+    }
+
+    // SUM of bigint and AVG of integer are NUMERIC in postgres
+    fn get_numeric_cast_type(&self, kind: NumericKind) -> Option<&'static str> {
+        match kind {
+            NumericKind::Integer => Some("BIGINT"),
+            NumericKind::Float => Some("DOUBLE PRECISION"),
+            NumericKind::Decimal => None,
+        }
+    }
 }
 
 pub trait SqlGenerator {
@@ -92,16 +203,15 @@ pub trait SqlGenerator {
         '?'
     }
 
+    // placeholders are numbered by the order of bind arguments, not by their offset in the sql,
+    // ? in strings, quoted identifiers and comments of template sql is not a placeholder
     #[inline]
     fn pg_post_process(&self, origin_sql: String) -> String {
-        origin_sql
-            .chars()
-            .enumerate()
-            .map(|(i, c)| match c {
-                '?' => format!("${}", i + 1),
-                _ => c.to_string(),
-            })
-            .collect()
+        let mut index = 0;
+        replace_place_holders(&origin_sql, '?', || {
+            index += 1;
+            format!("${}", index)
+        })
     }
 
     #[inline(always)]
@@ -109,11 +219,47 @@ pub trait SqlGenerator {
         origin
     }
 
+    #[inline(always)]
+    fn is_returning_supported(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn get_max_bind_params(&self) -> usize {
+        SQLITE_MAX_BIND_PARAMS
+    }
+
+    // cast type of SUM/AVG result, sqlite returns INTEGER/REAL as it is
+    fn get_numeric_cast_type(&self, _kind: NumericKind) -> Option<&'static str> {
+        None
+    }
+
     fn get_last_row_id_sql(&self) -> &'static str {
         "SELECT last_insert_rowid() as `last_row_id`"
     }
 
+    /**
+    condition of rows visible in scope on a table with #[soft_delete]
+    None if the table has no #[soft_delete] column or all rows are visible
+    */
+    fn get_soft_delete_sql(&self, soft_delete: Option<SoftDelete>, scope: DeletedScope) -> Option<String> {
+        soft_delete.and_then(|soft_delete| soft_delete.get_scope_sql(scope, self.get_wrap_char()))
+    }
+
+    // where clause of location may contain OR, so it is wrapped before the soft delete condition
+    fn join_soft_delete_sql(&self, where_clause: String, soft_delete: Option<SoftDelete>, scope: DeletedScope) -> String {
+        match self.get_soft_delete_sql(soft_delete, scope) {
+            None => where_clause,
+            Some(scope_sql) if where_clause.is_empty() => scope_sql,
+            Some(scope_sql) => format!("({}) AND {}", where_clause, scope_sql),
+        }
+    }
+
     fn get_exists_sql<M: Mutation>(&self, primary: &dyn Unique<Mutation = M>) -> String {
+        self.get_scoped_exists_sql(primary, DeletedScope::Alive)
+    }
+
+    fn get_scoped_exists_sql<M: Mutation>(&self, primary: &dyn Unique<Mutation = M>, scope: DeletedScope) -> String {
         let table_name = primary.get_table_name();
 
         let located_fields = primary.get_unique_field_names();
@@ -122,6 +268,7 @@ pub trait SqlGenerator {
             self.get_wrap_char(),
             self.get_place_holder(),
         );
+        let where_clause = self.join_soft_delete_sql(where_clause, primary.get_soft_delete(), scope);
         let select_sql = format!(
             "SELECT 1 FROM {}{}{} WHERE {}",
             self.get_wrap_char(),
@@ -137,6 +284,15 @@ pub trait SqlGenerator {
         &self,
         selection: &dyn Selection,
         primary: &dyn Unique<Mutation = M>,
+    ) -> String {
+        self.get_scoped_select_sql(selection, primary, DeletedScope::Alive)
+    }
+
+    fn get_scoped_select_sql<M: Mutation>(
+        &self,
+        selection: &dyn Selection,
+        primary: &dyn Unique<Mutation = M>,
+        scope: DeletedScope,
     ) -> String {
         let table_name = primary.get_table_name();
         let selected_fields: Vec<String> = selection.get_selected_fields();
@@ -147,6 +303,7 @@ pub trait SqlGenerator {
             self.get_wrap_char(),
             self.get_place_holder(),
         );
+        let where_clause = self.join_soft_delete_sql(where_clause, primary.get_soft_delete(), scope);
         let select_sql = format!(
             "SELECT {} FROM {}{}{} WHERE {}",
             select_clause,
@@ -171,10 +328,24 @@ pub trait SqlGenerator {
         .to_string();
         self.post_process(select_sql)
     }
+    // count of devour, rows are filtered by the #[soft_delete] column of selection
+    fn get_scoped_count_table_sql(&self, selection: &dyn Selection, scope: DeletedScope) -> String {
+        let select_sql = self.get_count_table_sql(selection.get_table_name());
+        match self.get_soft_delete_sql(selection.get_soft_delete(), scope) {
+            None => select_sql,
+            Some(scope_sql) => format!("{} WHERE {}", select_sql, scope_sql),
+        }
+    }
+
     fn get_count_sql(&self, location: &dyn Location) -> String {
+        self.get_scoped_count_sql(location, DeletedScope::Alive)
+    }
+
+    fn get_scoped_count_sql(&self, location: &dyn Location, scope: DeletedScope) -> String {
         let table_name = location.get_table_name();
 
         let where_clause = location.get_where_clause(self.get_wrap_char(), self.get_place_holder());
+        let where_clause = self.join_soft_delete_sql(where_clause, location.get_soft_delete(), scope);
         let select_sql = format!(
             "SELECT COUNT(1) AS {}count{} FROM {}{}{} WHERE {}",
             self.get_wrap_char(),
@@ -188,6 +359,85 @@ pub trait SqlGenerator {
         self.post_process(select_sql)
     }
 
+    fn get_aggregate_sql(
+        &self,
+        function: AggregateFunction,
+        field_name: &str,
+        kind: Option<NumericKind>,
+        location: &dyn Location,
+    ) -> String {
+        let table_name = location.get_table_name();
+        let cast_type = kind.and_then(|kind| self.get_numeric_cast_type(kind));
+        let expr = function.get_expr(field_name, self.get_wrap_char(), cast_type);
+        let alias = match function {
+            AggregateFunction::CountDistinct => "count",
+            _ => "aggregate",
+        };
+        let where_sql = self.get_scoped_where_sql(&Some(location), location.get_soft_delete(), DeletedScope::Alive);
+        let select_sql = format!(
+            "SELECT {} AS {}{}{} FROM {}{}{} {}",
+            expr,
+            self.get_wrap_char(),
+            alias,
+            self.get_wrap_char(),
+            self.get_wrap_char(),
+            table_name,
+            self.get_wrap_char(),
+            where_sql
+        );
+        self.post_process(select_sql)
+    }
+
+    /**
+    SELECT `status`,COUNT(*) AS `count` FROM `order` WHERE .. GROUP BY `status` HAVING COUNT(*)>? ORDER BY `status`
+    group columns come first in the select list, then the aggregates, rows are ordered by group columns
+    */
+    fn get_grouped_sql(
+        &self,
+        group_by: &[&str],
+        aggregates: &[AggregateExpr],
+        location: &dyn Location,
+        having: &Option<&dyn Location>,
+    ) -> String {
+        let wrap_char = self.get_wrap_char();
+        let table_name = location.get_table_name();
+        let group_fields = group_by
+            .iter()
+            .map(|field| format!("{}{}{}", wrap_char, field, wrap_char))
+            .collect::<Vec<String>>()
+            .join(",");
+        let aggregate_fields = aggregates.iter().map(|aggregate| {
+            let cast_type = aggregate.get_kind().and_then(|kind| self.get_numeric_cast_type(kind));
+            format!(
+                "{} AS {}{}{}",
+                aggregate.get_expr(wrap_char, cast_type),
+                wrap_char,
+                aggregate.get_alias(),
+                wrap_char
+            )
+        });
+        let select_fields = std::iter::once(group_fields.clone())
+            .filter(|fields| !fields.is_empty())
+            .chain(aggregate_fields)
+            .collect::<Vec<String>>()
+            .join(",");
+        let where_sql = self.get_scoped_where_sql(&Some(location), location.get_soft_delete(), DeletedScope::Alive);
+        let mut select_sql = format!(
+            "SELECT {} FROM {}{}{} {}",
+            select_fields, wrap_char, table_name, wrap_char, where_sql
+        );
+        if !group_fields.is_empty() {
+            select_sql.push_str(&format!(" GROUP BY {}", group_fields));
+        }
+        if let Some(having) = having {
+            let having_clause = having.get_where_clause(wrap_char, self.get_place_holder());
+            select_sql.push_str(&format!(" HAVING {}", having_clause));
+        }
+        if !group_fields.is_empty() {
+            select_sql.push_str(&format!(" ORDER BY {}", group_fields));
+        }
+        self.post_process(select_sql)
+    }
 
     // fn get_limit_sql(&self, page: &Pagination) -> String {
     //     let offset = page.page_size * page.page_num;
@@ -210,14 +460,53 @@ pub trait SqlGenerator {
         match order_by {
             None => String::new(),
             Some(order_by) => {
-                let order_by_field_names = order_by.get_fields();
-                let order_by_fields =
-                    wrap_cow_str_fields(order_by_field_names, self.get_wrap_char());
+                let order_by_fields = self.get_order_by_fields_sql(*order_by, false);
                 format!("ORDER BY {}", order_by_fields)
             }
         }
     }
 
+    // `a` DESC,`b` NULLS FIRST, every field order is flipped when reverse is true
+    fn get_order_by_fields_sql(&self, order_by: &dyn OrderBy, reverse: bool) -> String {
+        order_by
+            .get_fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let order = order_by.get_field_order(i);
+                let order = if reverse { order.reverse() } else { order };
+                self.get_order_field_sql(&self.get_wrapped_field_sql(field), order)
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    // `field`
+    fn get_wrapped_field_sql(&self, field: &str) -> String {
+        let wrap_char = self.get_wrap_char();
+        format!("{}{}{}", wrap_char, field, wrap_char)
+    }
+
+    // `table`.`field`, field of joined tables
+    fn get_qualified_field_sql(&self, table_name: &str, field: &str) -> String {
+        let wrap_char = self.get_wrap_char();
+        format!("{}{}{}.{}{}{}", wrap_char, table_name, wrap_char, wrap_char, field, wrap_char)
+    }
+
+    // wrapped_field is `field` or `table`.`field`
+    fn get_order_field_sql(&self, wrapped_field: &str, order: FieldOrder) -> String {
+        let direction = match order.direction {
+            OrderDirection::Asc => "",
+            OrderDirection::Desc => " DESC",
+        };
+        let nulls = match order.nulls {
+            NullsOrder::Default => "",
+            NullsOrder::First => " NULLS FIRST",
+            NullsOrder::Last => " NULLS LAST",
+        };
+        format!("{}{}{}", wrapped_field, direction, nulls)
+    }
+
     fn get_where_sql(&self, location: &Option<&dyn Location>) -> String {
         match location {
             None => String::new(),
@@ -228,16 +517,44 @@ pub trait SqlGenerator {
         }
     }
 
+    fn get_scoped_where_sql(
+        &self,
+        location: &Option<&dyn Location>,
+        soft_delete: Option<SoftDelete>,
+        scope: DeletedScope,
+    ) -> String {
+        let where_fields = location
+            .map(|location| location.get_where_clause(self.get_wrap_char(), self.get_place_holder()))
+            .unwrap_or_default();
+        let where_fields = self.join_soft_delete_sql(where_fields, soft_delete, scope);
+        if where_fields.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", where_fields)
+        }
+    }
+
     fn get_search_paged_sql(
         &self,
         selection: &dyn Selection,
         location: &Option<&dyn Location>,
         order_by: &Option<&dyn OrderBy>,
         page: &Option<&Pagination>,
+    ) -> String {
+        self.get_scoped_search_paged_sql(selection, location, order_by, page, DeletedScope::Alive)
+    }
+
+    fn get_scoped_search_paged_sql(
+        &self,
+        selection: &dyn Selection,
+        location: &Option<&dyn Location>,
+        order_by: &Option<&dyn OrderBy>,
+        page: &Option<&Pagination>,
+        scope: DeletedScope,
     ) -> String {
         let order_by_clause = self.get_order_by_sql(order_by);
         let limit_clause = self.get_page_sql(page);
-        let where_clause = self.get_where_sql(location);
+        let where_clause = self.get_scoped_where_sql(location, selection.get_soft_delete(), scope);
 
         let selected_field_names = selection.get_selected_fields();
         let selected_fields = wrap_fields(&selected_field_names, self.get_wrap_char());
@@ -257,37 +574,197 @@ pub trait SqlGenerator {
         self.post_process(select_sql)
     }
 
+    /**
+    keyset pagination sql, no offset is used, limit is page_size + 1 usually,
+    the extra record tells whether there is more
+    1. direction Next: WHERE {loc} AND (a,b) > (?,?) ORDER BY a,b
+    2. direction Prev: WHERE {loc} AND (a,b) < (?,?) ORDER BY a DESC,b DESC
+    3. mixed directions: (a > ?) OR (a = ? AND b < ?), see get_seek_indexes
+    */
+    fn get_search_cursor_sql(
+        &self,
+        selection: &dyn Selection,
+        location: &Option<&dyn Location>,
+        order_by: &dyn OrderBy,
+        direction: &Option<CursorDirection>,
+        limit: u64,
+    ) -> String {
+        let wrap_char = self.get_wrap_char();
+        let reverse = *direction == Some(CursorDirection::Prev);
+
+        let mut conditions: Vec<String> = Vec::new();
+        if let Some(location) = location {
+            let where_fields = location.get_where_clause(wrap_char, self.get_place_holder());
+            let where_fields = self.join_soft_delete_sql(where_fields, selection.get_soft_delete(), DeletedScope::Alive);
+            if !where_fields.is_empty() {
+                conditions.push(where_fields);
+            }
+        } else if let Some(scope_sql) = self.get_soft_delete_sql(selection.get_soft_delete(), DeletedScope::Alive) {
+            conditions.push(scope_sql);
+        }
+        if direction.is_some() {
+            conditions.push(self.get_seek_sql(order_by, reverse));
+        }
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let order_by_clause = format!("ORDER BY {}", self.get_order_by_fields_sql(order_by, reverse));
+
+        let selected_field_names = selection.get_selected_fields();
+        let selected_fields = wrap_fields(&selected_field_names, wrap_char);
+        let table_name = selection.get_table_name();
+
+        let select_sql = format!(
+            "SELECT {} FROM {}{}{} {} {} LIMIT {}",
+            selected_fields, wrap_char, table_name, wrap_char, where_clause, order_by_clause, limit
+        );
+        self.post_process(select_sql)
+    }
+
+    // seek predicate of records after the cursor in the (maybe reversed) order
+    fn get_seek_sql(&self, order_by: &dyn OrderBy, reverse: bool) -> String {
+        let wrap_char = self.get_wrap_char();
+        let place_holder = self.get_place_holder();
+        let fields = order_by.get_fields();
+        let cmp_of = |i: usize| {
+            let order = order_by.get_field_order(i);
+            let order = if reverse { order.reverse() } else { order };
+            match order.direction {
+                OrderDirection::Asc => ">",
+                OrderDirection::Desc => "<",
+            }
+        };
+        if is_seek_uniform(order_by) {
+            let place_holders = vec![place_holder.to_string(); fields.len()].join(",");
+            return format!(
+                "({}) {} ({})",
+                wrap_cow_str_fields(fields, wrap_char),
+                cmp_of(0),
+                place_holders
+            );
+        }
+        let branches = (0..fields.len())
+            .map(|k| {
+                let mut predicates = (0..k)
+                    .map(|i| format!("{}{}{} = {}", wrap_char, fields[i], wrap_char, place_holder))
+                    .collect::<Vec<String>>();
+                predicates.push(format!("{}{}{} {} {}", wrap_char, fields[k], wrap_char, cmp_of(k), place_holder));
+                format!("({})", predicates.join(" AND "))
+            })
+            .collect::<Vec<String>>();
+        format!("({})", branches.join(" OR "))
+    }
+
+    /**
+    joined search sql, columns are qualified by table and aliased as `table.field`
+    where clauses of each location are joined by AND, empty ones are skipped,
+    deleted rows of every selected or located table with #[soft_delete] are skipped
+    order by fields are qualified by the table of order by
+    */
     fn get_page_joined_search_sql(
         &self,
         joined_conds: &JoinedConditions,
-        locations: &Vec<&dyn Location>,
-        _order_by: &Option<&dyn OrderBy>,
-        selections: &Vec<&dyn Selection>,
-        _page: &Pagination,
+        locations: &[&dyn Location],
+        order_by: &Option<&dyn OrderBy>,
+        selections: &[&dyn Selection],
+        page: &Option<&Pagination>,
     ) -> String {
-        let mut selected_field_names: Vec<String> = Vec::new();
+        let wrap_char = self.get_wrap_char();
+        let mut selected_fields: Vec<String> = Vec::new();
         for selection in selections {
-            let fields = selection.get_selected_fields();
-            selected_field_names.extend(fields);
+            let table_name = selection.get_table_name();
+            for field in selection.get_selected_fields() {
+                selected_fields.push(format!(
+                    "{} AS {}{}.{}{}",
+                    self.get_qualified_field_sql(table_name, &field),
+                    wrap_char, table_name, field, wrap_char
+                ));
+            }
         }
-        let selected_fields = wrap_fields(&selected_field_names, self.get_wrap_char());
+        let selected_fields = selected_fields.join(",");
 
-        let mut location_stmts: Vec<String> = Vec::new();
-        for location in locations {
-            let where_clause =
-                location.get_where_clause(self.get_wrap_char(), self.get_place_holder());
-            location_stmts.push(where_clause);
-        }
-        let where_clause = location_stmts.join(",");
-        let from_clause = joined_conds.get_from_clause();
+        let where_clause = self.get_joined_where_sql(locations, selections);
+        let order_by_clause = match order_by {
+            None => String::new(),
+            Some(order_by) => {
+                let table_name = order_by.get_table_name();
+                let order_by_fields = order_by
+                    .get_fields()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let field = if table_name.is_empty() {
+                            self.get_wrapped_field_sql(field)
+                        } else {
+                            self.get_qualified_field_sql(table_name, field)
+                        };
+                        self.get_order_field_sql(&field, order_by.get_field_order(i))
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",");
+                format!("ORDER BY {}", order_by_fields)
+            }
+        };
+        let limit_clause = self.get_page_sql(page);
+        let from_clause = joined_conds.get_wrapped_from_clause(Some(wrap_char));
         let sql: String = format!(
-            "SELECT {} FROM {} WHERE {}",
-            selected_fields, from_clause, where_clause
+            "SELECT {} FROM {} {} {} {}",
+            selected_fields, from_clause, where_clause, order_by_clause, limit_clause
         )
         .to_string();
         self.post_process(sql)
     }
 
+    fn get_joined_count_sql(
+        &self,
+        joined_conds: &JoinedConditions,
+        locations: &[&dyn Location],
+        selections: &[&dyn Selection],
+    ) -> String {
+        let wrap_char = self.get_wrap_char();
+        let where_clause = self.get_joined_where_sql(locations, selections);
+        let from_clause = joined_conds.get_wrapped_from_clause(Some(wrap_char));
+        let sql = format!(
+            "SELECT COUNT(1) AS {}count{} FROM {} {}",
+            wrap_char, wrap_char, from_clause, where_clause
+        );
+        self.post_process(sql)
+    }
+
+    fn get_joined_where_sql(&self, locations: &[&dyn Location], selections: &[&dyn Selection]) -> String {
+        let mut where_clauses = locations
+            .iter()
+            .map(|location| location.get_qualified_where_clause(self.get_wrap_char(), self.get_place_holder()))
+            .filter(|where_clause| !where_clause.is_empty())
+            .map(|where_clause| format!("({})", where_clause))
+            .collect::<Vec<String>>();
+        // a table is filtered once, no matter how many locations or selections it has
+        let soft_deletes = locations
+            .iter()
+            .map(|location| (location.get_table_name(), location.get_soft_delete()))
+            .chain(selections.iter().map(|selection| (selection.get_table_name(), selection.get_soft_delete())));
+        let mut filtered_tables: Vec<&str> = Vec::new();
+        for (table_name, soft_delete) in soft_deletes {
+            let Some(soft_delete) = soft_delete else { continue };
+            if filtered_tables.contains(&table_name) {
+                continue;
+            }
+            filtered_tables.push(table_name);
+            if let Some(scope_sql) =
+                soft_delete.get_qualified_scope_sql(table_name, DeletedScope::Alive, self.get_wrap_char())
+            {
+                where_clauses.push(scope_sql);
+            }
+        }
+        if where_clauses.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", where_clauses.join(" AND "))
+        }
+    }
+
     fn get_insert_sql(&self, entity: &dyn Entity) -> String {
         let table_name = entity.get_table_name();
         let field_names = entity.get_insert_fields();
@@ -310,19 +787,16 @@ pub trait SqlGenerator {
         let field_names = entity.get_insert_fields();
         let fields = wrap_field_names(&field_names, self.get_wrap_char());
         let marks = generate_question_mark_list_from_names(&field_names);
-        let auto_field_name = entity.get_auto_increment_field();
-        let create_sql = if auto_field_name.is_some() {
-            let auto_field_name = auto_field_name.unwrap();
+        let returning_names = get_returning_field_names(entity);
+        let create_sql = if !returning_names.is_empty() {
             format!(
-                "INSERT INTO {}{}{} ({}) VALUES({}) RETURNING {}{}{} AS last_row_id",
+                "INSERT INTO {}{}{} ({}) VALUES({}) RETURNING {}",
                 self.get_wrap_char(),
                 table_name,
                 self.get_wrap_char(),
                 fields,
                 marks,
-                self.get_wrap_char(),
-                auto_field_name,
-                self.get_wrap_char()
+                wrap_field_names(&returning_names, self.get_wrap_char())
             )
             .to_string()
         } else {
@@ -339,6 +813,111 @@ pub trait SqlGenerator {
         self.post_process(create_sql)
     }
 
+    // re-select auto increment field and generated fields for database without RETURNING
+    fn get_generated_select_sql(&self, entity: &dyn Entity, auto_id: i64) -> Option<String> {
+        let auto_field_name = entity.get_auto_increment_field()?;
+        let table_name = entity.get_table_name();
+        let returning_names = get_returning_field_names(entity);
+        let select_sql = format!(
+            "SELECT {} FROM {}{}{} WHERE {}{}{} = {}",
+            wrap_field_names(&returning_names, self.get_wrap_char()),
+            self.get_wrap_char(),
+            table_name,
+            self.get_wrap_char(),
+            self.get_wrap_char(),
+            auto_field_name,
+            self.get_wrap_char(),
+            auto_id
+        )
+        .to_string();
+        Some(self.post_process(select_sql))
+    }
+
+    // re-select auto increment field and generated fields by primary key or unique key given by entity
+    fn get_generated_reselect_sql(&self, entity: &dyn Entity) -> Option<String> {
+        let key_names = entity.get_reselect_key_names();
+        if key_names.is_empty() {
+            return None;
+        }
+        let wrap_char = self.get_wrap_char();
+        let where_clause = key_names
+            .iter()
+            .map(|name| format!("{}{}{} = {}", wrap_char, name, wrap_char, self.get_place_holder()))
+            .collect::<Vec<String>>()
+            .join(" AND ");
+        let select_sql = format!(
+            "SELECT {} FROM {}{}{} WHERE {}",
+            wrap_field_names(&get_returning_field_names(entity), wrap_char),
+            wrap_char,
+            entity.get_table_name(),
+            wrap_char,
+            where_clause
+        );
+        Some(self.post_process(select_sql))
+    }
+
+    // multi-row insert, all rows must share the insert fields of entity
+    fn get_insert_all_sql(&self, entity: &dyn Entity, rows: usize) -> String {
+        let table_name = entity.get_table_name();
+        let field_names = entity.get_insert_fields();
+        let fields = wrap_field_names(&field_names, self.get_wrap_char());
+        let values = generate_values_list_from_names(&field_names, rows);
+        let insert_sql = format!(
+            "INSERT INTO {}{}{} ({}) VALUES{}",
+            self.get_wrap_char(),
+            table_name,
+            self.get_wrap_char(),
+            fields,
+            values
+        )
+        .to_string();
+        self.post_process(insert_sql)
+    }
+
+    /**
+    multi-row upsert, update fields are assigned from the conflicting row, so only insert arguments are bound.
+    primary key is the conflict target, rows conflicting only on other unique keys are not updated
+    */
+    fn get_upsert_all_sql(&self, entity: &dyn Entity, rows: usize) -> String {
+        let table_name = entity.get_table_name();
+        let field_names = entity.get_insert_fields();
+        let fields = wrap_field_names(&field_names, self.get_wrap_char());
+        let values = generate_values_list_from_names(&field_names, rows);
+        let conflict_target = match entity.get_primary_key_names() {
+            [] => String::new(),
+            key_names => format!("({}) ", wrap_str_fields(key_names, self.get_wrap_char())),
+        };
+        let assign_clause = entity
+            .get_upsert_set_fields()
+            .iter()
+            .map(|e| {
+                format!(
+                    "{}{}{} = excluded.{}{}{}",
+                    self.get_wrap_char(),
+                    e.database_field_name(),
+                    self.get_wrap_char(),
+                    self.get_wrap_char(),
+                    e.database_field_name(),
+                    self.get_wrap_char()
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let upsert_sql = format!(
+            "INSERT INTO {}{}{} ({}) VALUES{} ON CONFLICT {}DO UPDATE SET {}",
+            self.get_wrap_char(),
+            table_name,
+            self.get_wrap_char(),
+            fields,
+            values,
+            conflict_target,
+            assign_clause
+        )
+        .to_string();
+        self.post_process(upsert_sql)
+    }
+
     fn get_upsert_sql(&self, entity: &dyn Entity) -> String {
         let table_name = entity.get_table_name();
 
@@ -366,31 +945,70 @@ pub trait SqlGenerator {
         self.post_process(upsert_sql)
     }
 
+    // SET clause of mutation, #[version] field is always increased by 1
+    fn get_mutation_set_sql<L: Location>(&self, mutation: &dyn Mutation<Location = L>) -> String {
+        let wrap_char = self.get_wrap_char();
+        let set_clause = mutation.get_set_clause(wrap_char, self.get_place_holder());
+        match mutation.get_version_field_name() {
+            None => set_clause,
+            Some(version) => {
+                let version_set = format!("{}{}{} = {}{}{} + 1", wrap_char, version, wrap_char, wrap_char, version, wrap_char);
+                if set_clause.is_empty() {
+                    version_set
+                } else {
+                    format!("{},{}", set_clause, version_set)
+                }
+            }
+        }
+    }
+
+    // `version` = ? when the expected version of mutation is set
+    fn get_version_where_sql<L: Location>(&self, mutation: &dyn Mutation<Location = L>) -> Option<String> {
+        match mutation.get_version_field_name() {
+            Some(version) if mutation.is_version_checked() => Some(format!(
+                "{}{}{} = {}",
+                self.get_wrap_char(),
+                version,
+                self.get_wrap_char(),
+                self.get_place_holder()
+            )),
+            _ => None,
+        }
+    }
+
+    // where clause of location may contain OR, so it is wrapped before the version check
+    fn join_version_where_sql<L: Location>(&self, where_clause: String, mutation: &dyn Mutation<Location = L>) -> String {
+        match self.get_version_where_sql(mutation) {
+            None => where_clause,
+            Some(version_sql) if where_clause.is_empty() => version_sql,
+            Some(version_sql) => format!("({}) AND {}", where_clause, version_sql),
+        }
+    }
+
     fn get_update_sql<M: Mutation>(
         &self,
         mutation: &M,
         unique: &dyn Unique<Mutation = M>,
     ) -> String {
         let table_name = unique.get_table_name();
-        let body_field_names = mutation.get_mutation_fields_name();
-        let body_fields = wrap_locate_fields_from_name(
-            &body_field_names,
-            self.get_wrap_char(),
-            self.get_place_holder(),
-        );
+        let body_fields = self.get_mutation_set_sql(mutation);
         let primary_field_names = unique.get_unique_field_names();
         let primary_fields = wrap_locate_str_fields(
             &primary_field_names,
             self.get_wrap_char(),
             self.get_place_holder(),
         );
+        let where_clause = match self.get_version_where_sql(mutation) {
+            Some(version_sql) => format!("{} AND {}", primary_fields, version_sql),
+            None => primary_fields,
+        };
         let update_sql = format!(
             "UPDATE {}{}{} SET {} WHERE {}",
             self.get_wrap_char(),
             table_name,
             self.get_wrap_char(),
             body_fields,
-            primary_fields
+            where_clause
         )
         .to_string();
         self.post_process(update_sql)
@@ -424,17 +1042,13 @@ pub trait SqlGenerator {
     fn get_change_sql<L: Location>(
         &self,
         mutation: &dyn Mutation<Location = L>,
-        location: &L,
+        location: &dyn Location,
     ) -> String {
         let table_name = location.get_table_name();
-        let mutation_fields = mutation.get_mutation_fields_name();
-        let update_clause = wrap_locate_fields_from_name(
-            &mutation_fields,
-            self.get_wrap_char(),
-            self.get_place_holder(),
-        );
+        let update_clause = self.get_mutation_set_sql(mutation);
 
         let where_clause = location.get_where_clause(self.get_wrap_char(), self.get_place_holder());
+        let where_clause = self.join_version_where_sql(where_clause, mutation);
         let update_sql = format!(
             "UPDATE {}{}{} SET {} WHERE {}",
             self.get_wrap_char(),
@@ -447,7 +1061,31 @@ pub trait SqlGenerator {
         self.post_process(update_sql)
     }
 
+    /**
+    on a table with #[soft_delete], delete marks the alive row as deleted instead of removing it:
+    UPDATE `user` SET `deleted` = TRUE WHERE (`id` = ?) AND `deleted` IS NOT TRUE
+    */
     fn get_delete_sql<M: Mutation>(&self, primary: &dyn Unique<Mutation = M>) -> String {
+        let Some(soft_delete) = primary.get_soft_delete() else {
+            return self.get_hard_delete_sql(primary);
+        };
+        let table_name = primary.get_table_name();
+        let field_names = primary.get_unique_field_names();
+        let where_clause =
+            wrap_locate_str_fields(field_names, self.get_wrap_char(), self.get_place_holder());
+        let where_clause = self.join_soft_delete_sql(where_clause, Some(soft_delete), DeletedScope::Alive);
+        let delete_sql = format!(
+            "UPDATE {}{}{} SET {} WHERE {}",
+            self.get_wrap_char(),
+            table_name,
+            self.get_wrap_char(),
+            soft_delete.get_delete_set_sql(self.get_wrap_char()),
+            where_clause
+        );
+        self.post_process(delete_sql)
+    }
+
+    fn get_hard_delete_sql<M: Mutation>(&self, primary: &dyn Unique<Mutation = M>) -> String {
         let table_name = primary.get_table_name();
         let field_names = primary.get_unique_field_names();
         let where_clause =
@@ -464,6 +1102,24 @@ pub trait SqlGenerator {
     }
 
     fn get_purify_sql(&self, location: &dyn Location) -> String {
+        let Some(soft_delete) = location.get_soft_delete() else {
+            return self.get_hard_purify_sql(location);
+        };
+        let table_name = location.get_table_name();
+        let where_clause = location.get_where_clause(self.get_wrap_char(), self.get_place_holder());
+        let where_clause = self.join_soft_delete_sql(where_clause, Some(soft_delete), DeletedScope::Alive);
+        let delete_sql = format!(
+            "UPDATE {}{}{} SET {} WHERE {}",
+            self.get_wrap_char(),
+            table_name,
+            self.get_wrap_char(),
+            soft_delete.get_delete_set_sql(self.get_wrap_char()),
+            where_clause
+        );
+        self.post_process(delete_sql)
+    }
+
+    fn get_hard_purify_sql(&self, location: &dyn Location) -> String {
         let table_name = location.get_table_name();
         let where_clause = location.get_where_clause(self.get_wrap_char(), self.get_place_holder());
         let delete_sql = format!(
@@ -477,6 +1133,16 @@ pub trait SqlGenerator {
         self.post_process(delete_sql)
     }
 }
+#[inline]
+fn get_returning_field_names(entity: &dyn Entity) -> Vec<FieldName> {
+    let mut names = Vec::new();
+    if let Some(auto_field_name) = entity.get_auto_increment_field() {
+        names.push(FieldName::new(Cow::Owned(auto_field_name.to_string()), false));
+    }
+    names.extend(entity.get_generated_fields());
+    names
+}
+
 #[inline]
 fn wrap_fields(fields: &[String], wrap_char: char) -> String {
     fields
@@ -587,4 +1253,10 @@ fn generate_question_mark_list_from_names(fields: &[FieldName]) -> String {
         })
         .collect::<Vec<String>>()
         .join(", ")
-}
\ No newline at end of file
+}
+
+#[inline]
+fn generate_values_list_from_names(fields: &[FieldName], rows: usize) -> String {
+    let row_marks = format!("({})", generate_question_mark_list_from_names(fields));
+    vec![row_marks; rows].join(", ")
+}
@@ -20,6 +20,8 @@ Option<Row> -> bool
 
 generic_execute           (ex, stmt, args) -> Result<u64>
 generic_execute_plain     (ex, stmt, _   ) -> Result<u64>
+generic_execute_with_id   (ex, stmt, args) -> Result<(u64, Option<i64>)>
+generic_fetch_row_option  (ex, stmt, args) -> Result<Option<Row>>
 
 generic_exists            (ex, stmt, args) -> Result<bool>
 generic_exists_plain      (ex, stmt, _   ) -> Result<bool>
@@ -55,6 +57,11 @@ pub trait SqlGenericExecutor {
 
     fn get_affected_rows(query_result: &<Self::DB as Database>::QueryResult) -> u64;
 
+    // database without RETURNING support should override this to report the generated id
+    fn get_last_insert_id(_query_result: &<Self::DB as Database>::QueryResult) -> Option<i64> {
+        None
+    }
+
     // 1. generic_exists           (ex, stmt, args) -> Result<bool>
     async fn generic_exists<'a, EX, A>(ex: EX, stmt: &'a str, args: A) -> Result<bool>
     where
@@ -150,6 +157,46 @@ pub trait SqlGenericExecutor {
         Ok(Self::get_affected_rows(&result))
     }
 
+    async fn generic_execute_with_id<'a, EX, A>(
+        ex: EX,
+        query: &'a str,
+        args: A,
+    ) -> Result<(u64, Option<i64>)>
+    where
+        EX: Executor<'a, Database = Self::DB>,
+        A: IntoArguments<'a, Self::DB> + 'a,
+    {
+        let query: Query<'a, Self::DB, A> = sqlx::query_with(query, args);
+        let result: <Self::DB as Database>::QueryResult = query.execute(ex).await?;
+        Ok((Self::get_affected_rows(&result), Self::get_last_insert_id(&result)))
+    }
+
+    async fn generic_fetch_row_option<'a, EX, A>(
+        ex: EX,
+        stmt: &'a str,
+        args: A,
+    ) -> Result<Option<<Self::DB as Database>::Row>>
+    where
+        EX: Executor<'a, Database = Self::DB>,
+        A: IntoArguments<'a, Self::DB> + 'a,
+    {
+        let query: Query<'a, Self::DB, A> = sqlx::query_with(stmt, args);
+        Ok(query.fetch_optional(ex).await?)
+    }
+
+    async fn generic_fetch_rows<'a, EX, A>(
+        ex: EX,
+        stmt: &'a str,
+        args: A,
+    ) -> Result<Vec<<Self::DB as Database>::Row>>
+    where
+        EX: Executor<'a, Database = Self::DB>,
+        A: IntoArguments<'a, Self::DB> + 'a,
+    {
+        let query: Query<'a, Self::DB, A> = sqlx::query_with(stmt, args);
+        Ok(query.fetch_all(ex).await?)
+    }
+
     // 5. generic_fetch_all         (ex, stmt, selection, args) -> Result<Vec<SE>>
     async fn generic_fetch_all<'a, EX, SE, A>(
         ex: EX,
@@ -453,16 +453,24 @@ impl Location for UserLocation {
         let mut args = SqliteArguments::default();
 
         if let Optional::Some(request_id) = &self.request_id {
-            args.add(&request_id.val)?;
+            for value in request_id.get_values()? {
+                args.add(value)?;
+            }
         }
         if let Optional::Some(name) = &self.name {
-            args.add(&name.val)?;
+            for value in name.get_values()? {
+                args.add(value)?;
+            }
         }
         if let Optional::Some(age) = &self.age {
-            args.add(&age.val)?;
+            for value in age.get_values()? {
+                args.add(value)?;
+            }
         }
         if let Optional::Some(birthday) = &self.birthday {
-            args.add(&birthday.val)?;
+            for value in birthday.get_values()? {
+                args.add(value)?;
+            }
         }
 
         Ok(args)
@@ -525,16 +533,24 @@ impl UpdateCommand for UserLocationMutationPair {
         }
 
         if let Optional::Some(request_id) = &self.1.request_id {
-            args.add(request_id.val)?;
+            for value in request_id.get_values()? {
+                args.add(value)?;
+            }
         }
         if let Optional::Some(name) = &self.1.name {
-            args.add(name.clone().val)?;
+            for value in name.get_values()? {
+                args.add(value)?;
+            }
         }
         if let Optional::Some(age) = &self.1.age {
-            args.add(age.val)?;
+            for value in age.get_values()? {
+                args.add(value)?;
+            }
         }
         if let Optional::Some(birthday) = &self.1.birthday {
-            args.add(birthday.val)?;
+            for value in birthday.get_values()? {
+                args.add(value)?;
+            }
         }
 
         Ok(args)
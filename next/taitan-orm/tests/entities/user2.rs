@@ -338,19 +338,29 @@ impl taitan_orm::traits::Location for UserLocation {
     ) -> Result<sqlx::sqlite::SqliteArguments<'_>, sqlx::error::BoxDynError> {
         let mut args = sqlx::sqlite::SqliteArguments::default();
         if let Some(id) = &self.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(request_id) = &self.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(age) = &self.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(name) = &self.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(birthday) = &self.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -359,19 +369,29 @@ impl taitan_orm::traits::Location for UserLocation {
     ) -> Result<sqlx::mysql::MySqlArguments, sqlx::error::BoxDynError> {
         let mut args = sqlx::mysql::MySqlArguments::default();
         if let Some(id) = &self.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(request_id) = &self.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(age) = &self.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(name) = &self.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(birthday) = &self.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -380,19 +400,29 @@ impl taitan_orm::traits::Location for UserLocation {
     ) -> Result<sqlx::postgres::PgArguments, sqlx::error::BoxDynError> {
         let mut args = sqlx::postgres::PgArguments::default();
         if let Some(id) = &self.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(request_id) = &self.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(age) = &self.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(name) = &self.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(birthday) = &self.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -441,19 +471,29 @@ impl taitan_orm::traits::Mutation for UserMutation {
             sqlx::Arguments::add(&mut args, birthday)?;
         }
         if let Some(id) = &location.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(request_id) = &location.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(age) = &location.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(name) = &location.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(birthday) = &location.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -475,19 +515,29 @@ impl taitan_orm::traits::Mutation for UserMutation {
             sqlx::Arguments::add(&mut args, birthday)?;
         }
         if let Some(id) = &location.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(request_id) = &location.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(age) = &location.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(name) = &location.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(birthday) = &location.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -509,19 +559,29 @@ impl taitan_orm::traits::Mutation for UserMutation {
             sqlx::Arguments::add(&mut args, birthday)?;
         }
         if let Some(id) = &location.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(request_id) = &location.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(age) = &location.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(name) = &location.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let Some(birthday) = &location.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -622,19 +622,29 @@ impl taitan_orm::traits::Location for UserLocation {
     ) -> Result<sqlx::sqlite::SqliteArguments<'_>, sqlx::error::BoxDynError> {
         let mut args = sqlx::sqlite::SqliteArguments::default();
         if let taitan_orm::Optional::Some(id) = &self.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(request_id) = &self.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(age) = &self.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(name) = &self.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(birthday) = &self.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -643,19 +653,29 @@ impl taitan_orm::traits::Location for UserLocation {
     ) -> Result<sqlx::mysql::MySqlArguments, sqlx::error::BoxDynError> {
         let mut args = sqlx::mysql::MySqlArguments::default();
         if let taitan_orm::Optional::Some(id) = &self.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(request_id) = &self.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(age) = &self.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(name) = &self.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(birthday) = &self.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -664,19 +684,29 @@ impl taitan_orm::traits::Location for UserLocation {
     ) -> Result<sqlx::postgres::PgArguments, sqlx::error::BoxDynError> {
         let mut args = sqlx::postgres::PgArguments::default();
         if let taitan_orm::Optional::Some(id) = &self.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(request_id) = &self.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(age) = &self.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(name) = &self.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(birthday) = &self.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -748,19 +778,29 @@ impl taitan_orm::traits::Mutation for UserMutation {
             sqlx::Arguments::add(&mut args, birthday)?;
         }
         if let taitan_orm::Optional::Some(id) = &location.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(request_id) = &location.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(age) = &location.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(name) = &location.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(birthday) = &location.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -782,19 +822,29 @@ impl taitan_orm::traits::Mutation for UserMutation {
             sqlx::Arguments::add(&mut args, birthday)?;
         }
         if let taitan_orm::Optional::Some(id) = &location.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(request_id) = &location.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(age) = &location.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(name) = &location.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(birthday) = &location.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -816,19 +866,29 @@ impl taitan_orm::traits::Mutation for UserMutation {
             sqlx::Arguments::add(&mut args, birthday)?;
         }
         if let taitan_orm::Optional::Some(id) = &location.id {
-            sqlx::Arguments::add(&mut args, &id.val)?;
+            for value in id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(request_id) = &location.request_id {
-            sqlx::Arguments::add(&mut args, &request_id.val)?;
+            for value in request_id.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(age) = &location.age {
-            sqlx::Arguments::add(&mut args, &age.val)?;
+            for value in age.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(name) = &location.name {
-            sqlx::Arguments::add(&mut args, &name.val)?;
+            for value in name.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         if let taitan_orm::Optional::Some(birthday) = &location.birthday {
-            sqlx::Arguments::add(&mut args, &birthday.val)?;
+            for value in birthday.get_values()? {
+                sqlx::Arguments::add(&mut args, value)?;
+            }
         }
         Ok(args)
     }
@@ -0,0 +1,135 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::{DefaultSqlGenerator, ReaderApi, Schema, SqlGenerator, WriterApi};
+use taitan_orm::traits::{AggregateExpr, AggregateFunction, LocationTree};
+use taitan_orm_trait::Optional;
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "purchase"]
+pub struct Purchase {
+    #[primary_key]
+    pub id: i64,
+
+    pub user_id: i64,
+
+    pub item: String,
+
+    pub qty: i32,
+
+    pub amount: Optional<f64>,
+}
+
+#[derive(sqlx::FromRow, Debug, PartialEq)]
+pub struct ItemStat {
+    item: String,
+    count: i64,
+    total: i64,
+}
+
+async fn insert_purchases(db: &mut SqliteDatabase) -> taitan_orm::Result<()> {
+    recreate_table(
+        db,
+        "purchase",
+        "CREATE TABLE `purchase`(`id` BIGINT PRIMARY KEY, `user_id` BIGINT, `item` VARCHAR(64), `qty` INT, `amount` REAL)",
+    )
+    .await?;
+    let purchases = [
+        (1, "apple", 3, Optional::Some(1.5)),
+        (1, "pear", 2, Optional::Some(4.0)),
+        (2, "apple", 5, Optional::Null),
+        (3, "melon", 1, Optional::Some(8.5)),
+    ];
+    for (i, (user_id, item, qty, amount)) in purchases.into_iter().enumerate() {
+        let purchase = Purchase { id: i as i64 + 1, user_id, item: item.to_string(), qty, amount };
+        db.insert(&purchase).await?;
+    }
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn aggregate_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("aggregate_spec.db").await?;
+    insert_purchases(&mut db).await?;
+
+    let location = PurchaseColumns::id().gt(0);
+    let total_qty: Option<i64> = db.sum(PurchaseColumns::qty(), &location).await?;
+    assert_eq!(total_qty, Some(11));
+    let total_amount: Option<f64> = db.sum(PurchaseColumns::amount(), &location).await?;
+    assert_eq!(total_amount, Some(14.0));
+    // NULL is ignored by AVG
+    assert_eq!(db.avg(PurchaseColumns::amount(), &location).await?, Some(14.0 / 3.0));
+    assert_eq!(db.avg(PurchaseColumns::qty(), &location).await?, Some(2.75));
+    assert_eq!(db.min(PurchaseColumns::qty(), &location).await?, Some(1));
+    assert_eq!(db.max(PurchaseColumns::item(), &location).await?, Some("pear".to_string()));
+    assert_eq!(db.count_distinct(PurchaseColumns::user_id(), &location).await?, 3);
+    assert_eq!(db.count_distinct(PurchaseColumns::item(), &location).await?, 3);
+
+    // same filters as search, on the location of the column's table
+    let location = PurchaseLocation {
+        qty: PurchaseColumns::qty().ge(2).qty,
+        ..PurchaseColumns::user_id().eq(1)
+    };
+    assert_eq!(db.sum(PurchaseColumns::qty(), &location).await?, Some(5));
+    assert_eq!(db.max(PurchaseColumns::amount(), &location).await?, Some(4.0));
+    assert_eq!(db.count_distinct(PurchaseColumns::item(), &location).await?, 2);
+
+    // empty location aggregates all rows without a dangling WHERE
+    let location = PurchaseLocation::default();
+    assert_eq!(
+        DefaultSqlGenerator::new().get_aggregate_sql(AggregateFunction::Sum, "qty", None, &location),
+        "SELECT SUM(`qty`) AS `aggregate` FROM `purchase` "
+    );
+    assert_eq!(db.sum(PurchaseColumns::qty(), &location).await?, Some(11));
+    assert_eq!(db.count_distinct(PurchaseColumns::item(), &location).await?, 3);
+
+    // aggregate of empty set is NULL
+    let location = PurchaseColumns::user_id().eq(9);
+    assert_eq!(db.sum(PurchaseColumns::qty(), &location).await?, None);
+    assert_eq!(db.max(PurchaseColumns::item(), &location).await?, None);
+    assert_eq!(db.count_distinct(PurchaseColumns::item(), &location).await?, 0);
+
+    let mut trx = db.transaction().await?;
+    let location = PurchaseColumns::item().eq("apple".to_string());
+    assert_eq!(trx.sum(PurchaseColumns::qty(), &location).await?, Some(8));
+    assert_eq!(trx.min(PurchaseColumns::amount(), &location).await?, Some(1.5));
+    trx.commit().await?;
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn grouped_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("grouped_spec.db").await?;
+    insert_purchases(&mut db).await?;
+
+    let location = PurchaseColumns::id().gt(0);
+    let aggregates = [AggregateExpr::count_all(), PurchaseColumns::qty().sum()];
+    let rows: Vec<(String, i64, i64)> = db.search_grouped(&[&PurchaseColumns::item()], &aggregates, &location, &None).await?;
+    assert_eq!(
+        rows,
+        vec![("apple".to_string(), 2, 8), ("melon".to_string(), 1, 1), ("pear".to_string(), 1, 2)]
+    );
+
+    // HAVING arguments are bound after WHERE arguments
+    let location = PurchaseColumns::qty().ge(2);
+    let having = AggregateExpr::count_all().gt(1i64);
+    let aggregates = [AggregateExpr::count_all(), PurchaseColumns::qty().sum().alias("total")];
+    let stats: Vec<ItemStat> = db
+        .search_grouped(&[&PurchaseColumns::item()], &aggregates, &location, &Some(&having))
+        .await?;
+    assert_eq!(stats, vec![ItemStat { item: "apple".to_string(), count: 2, total: 8 }]);
+
+    let having = LocationTree::leaf(PurchaseColumns::qty().sum().lt(3i64)).or(PurchaseColumns::amount().max().gt(8.0));
+    let rows: Vec<(i64, String, Option<f64>)> = db
+        .search_grouped(&[&PurchaseColumns::user_id(), &PurchaseColumns::item()], &[PurchaseColumns::amount().max()], &PurchaseColumns::id().gt(1), &Some(&having))
+        .await?;
+    assert_eq!(rows, vec![(1, "pear".to_string(), Some(4.0)), (3, "melon".to_string(), Some(8.5))]);
+
+    // without group columns there is only one row of aggregates
+    let mut trx = db.transaction().await?;
+    let aggregates = [PurchaseColumns::user_id().count_distinct(), PurchaseColumns::qty().avg()];
+    let rows: Vec<(i64, Option<f64>)> = trx.search_grouped(&[], &aggregates, &PurchaseColumns::id().gt(0), &None).await?;
+    assert_eq!(rows, vec![(3, Some(2.75))]);
+    trx.commit().await?;
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::{DefaultSqlGenerator, Schema, SqlExecutor, SqlGenerator, SqlGeneratorContainer, WriterApi};
+use taitan_orm_trait::Optional;
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "tag"]
+pub struct TagEntity {
+    #[primary_key]
+    pub id: i64,
+
+    pub name: String,
+
+    pub weight: i64,
+}
+
+async fn prepare_tag_table(db: &mut SqliteDatabase) -> taitan_orm::Result<()> {
+    recreate_table(
+        db,
+        "tag",
+        "CREATE TABLE `tag`\
+    (`id` BIGINT PRIMARY KEY, \
+    `name` VARCHAR(64) UNIQUE, \
+    `weight` BIGINT)",
+    )
+    .await?;
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn batch_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("batch_spec.db").await?;
+    prepare_tag_table(&mut db).await?;
+
+    // 20000 rows bind 60000 parameters, a single statement would fail with too many sql variables
+    assert_eq!(db.get_generator().get_max_bind_params(), 32766);
+    let tags: Vec<TagEntity> = (1..=20000)
+        .map(|i| TagEntity {
+            id: i,
+            name: format!("tag-{}", i),
+            weight: i % 10,
+        })
+        .collect();
+    let result = db.insert_all(&tags).await?;
+    assert_eq!(result, 20000);
+    let count = db.fetch_count_plain("SELECT COUNT(*) AS count FROM `tag`").await?;
+    assert_eq!(count, 20000);
+
+    // primary key is the conflict target, conflicting rows are updated
+    assert_eq!(
+        DefaultSqlGenerator::new().get_upsert_all_sql(&tags[0], 2),
+        "INSERT INTO `tag` (`id`,`name`,`weight`) VALUES(?, ?, ?), (?, ?, ?) \
+        ON CONFLICT (`id`) DO UPDATE SET `name` = excluded.`name`,`weight` = excluded.`weight`"
+    );
+    let tags: Vec<TagEntity> = [(2, "renamed-2"), (20001, "new"), (3, "renamed-3")]
+        .iter()
+        .map(|(id, name)| TagEntity {
+            id: *id,
+            name: name.to_string(),
+            weight: 100,
+        })
+        .collect();
+    let result = db.upsert_all(&tags).await?;
+    assert_eq!(result, 3);
+    let count = db.fetch_count_plain("SELECT COUNT(*) AS count FROM `tag`").await?;
+    assert_eq!(count, 20001);
+    let (name, weight): (String, i64) = db.fetch_tuple("SELECT `name`, `weight` FROM `tag` WHERE `id` = 2", Default::default()).await?;
+    assert_eq!((name.as_str(), weight), ("renamed-2", 100));
+    Ok(())
+}
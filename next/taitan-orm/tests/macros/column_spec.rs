@@ -0,0 +1,81 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::{Assign, ReaderApi, Schema, WriterApi};
+use taitan_orm_trait::{Location, LocationTree, Optional, Selection};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "staff"]
+pub struct Staff {
+    #[primary_key]
+    pub id: i64,
+
+    pub name: String,
+
+    pub age: Optional<i32>,
+}
+
+async fn search_ids(db: &mut SqliteDatabase, location: &dyn Location) -> taitan_orm::Result<Vec<i64>> {
+    let selection = StaffSelectedEntity::full_fields();
+    let order_by = StaffOrdering::build(["id"]).unwrap();
+    let staffs: Vec<StaffSelectedEntity> = db.search(&selection, location, &Some(&order_by), &None).await?;
+    let ids: Vec<i64> = staffs.iter().map(|s| s.id.unwrap()).collect();
+    assert_eq!(db.count(location).await?, ids.len() as u64);
+    Ok(ids)
+}
+
+#[sqlx_macros::test]
+pub async fn column_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("column_spec.db").await?;
+    recreate_table(
+        &mut db,
+        "staff",
+        "CREATE TABLE `staff`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `age` INT)",
+    )
+    .await?;
+    let staffs = [("allen", Optional::Some(17)), ("alice", Optional::Some(23)), ("bob", Optional::Some(35)), ("anna", Optional::Null)];
+    for (i, (name, age)) in staffs.into_iter().enumerate() {
+        let staff = Staff {
+            id: i as i64 + 1,
+            name: name.to_string(),
+            age,
+        };
+        db.insert(&staff).await?;
+    }
+
+    let location = StaffColumns::age().gt(18);
+    assert_eq!(search_ids(&mut db, &location).await?, vec![2, 3]);
+
+    let location = StaffColumns::age().gt(18).and(StaffColumns::name().like("a%"));
+    assert_eq!(search_ids(&mut db, &location).await?, vec![2]);
+
+    // several conditions on the same column
+    let location = StaffColumns::age().ge(17).and(StaffColumns::age().lt(30)).or(StaffColumns::age().is_null());
+    assert_eq!(search_ids(&mut db, &location).await?, vec![1, 2, 4]);
+
+    let location = !LocationTree::leaf(StaffColumns::name().is_in(vec!["bob".to_string(), "anna".to_string()]));
+    assert_eq!(search_ids(&mut db, &location).await?, vec![1, 2]);
+
+    // mutation arguments are bound before where clause arguments
+    let mutation = StaffMutation {
+        age: Assign::Set(40),
+        ..Default::default()
+    };
+    // change takes composed conditions of columns as search does
+    let location = StaffColumns::age().is_not_null().and(StaffColumns::name().like("a%"));
+    assert_eq!(db.change(&mutation, &location).await?, 2);
+    assert_eq!(search_ids(&mut db, &StaffColumns::age().eq(40)).await?, vec![1, 2]);
+    assert_eq!(db.change(&mutation, &StaffColumns::id().eq(3)).await?, 1);
+
+    let mutation = StaffMutation {
+        age: Assign::Set(50),
+        ..Default::default()
+    };
+    let location = StaffColumns::name().eq("bob".to_string()).or(StaffColumns::age().is_null());
+    assert_eq!(db.change(&mutation, &location).await?, 2);
+    assert_eq!(search_ids(&mut db, &StaffColumns::age().eq(50)).await?, vec![3, 4]);
+
+    assert_eq!(db.purify(&StaffColumns::age().between(30, 45)).await?, 2);
+    assert_eq!(search_ids(&mut db, &StaffColumns::id().is_not_null()).await?, vec![3, 4]);
+    Ok(())
+}
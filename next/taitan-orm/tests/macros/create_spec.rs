@@ -0,0 +1,99 @@
+use sqlx::sqlx_macros;
+use taitan_orm::{DefaultSqlGenerator, Schema, SqlExecutor, SqlGenerator, WriterApi};
+use taitan_orm_trait::{Entity, Optional};
+use super::spec_helper::{build_sqlite, prepare_article_table, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "article"]
+pub struct ArticleEntity {
+    #[primary_key]
+    #[auto_increment]
+    pub id: Optional<i64>,
+
+    pub title: String,
+
+    #[generated]
+    #[field_name = "view_count"]
+    pub views: Optional<i64>,
+}
+
+// auto increment field of other integer types is converted from the i64 id
+#[derive(Schema, Clone, Debug)]
+#[table_name = "label"]
+pub struct LabelEntity {
+    #[primary_key]
+    #[auto_increment]
+    pub id: Option<i16>,
+
+    pub name: String,
+}
+
+// no auto increment id, generated fields are re-selected by unique key when RETURNING is not supported
+#[derive(Schema, Clone, Debug)]
+#[table_name = "device"]
+#[unique_key = "serial"]
+pub struct DeviceEntity {
+    #[primary_key]
+    #[generated]
+    pub id: Optional<i64>,
+
+    pub serial: String,
+
+    #[generated]
+    pub state: Optional<i32>,
+}
+
+#[sqlx_macros::test]
+pub async fn create_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("create_spec.db").await?;
+    prepare_article_table(&mut db).await?;
+
+    let mut article = ArticleEntity {
+        id: Optional::None,
+        title: "first".to_string(),
+        views: Optional::None,
+    };
+    assert_eq!(article.get_generated_fields().len(), 1);
+    let success = db.create(&mut article).await?;
+    assert!(success);
+    assert_eq!(article.id, Optional::Some(1));
+    assert_eq!(article.views, Optional::Some(7));
+
+    let mut article = ArticleEntity {
+        id: Optional::None,
+        title: "second".to_string(),
+        views: Optional::None,
+    };
+    let success = db.create(&mut article).await?;
+    assert!(success);
+    assert_eq!(article.id, Optional::Some(2));
+    assert_eq!(article.views, Optional::Some(7));
+
+    recreate_table(
+        &mut db,
+        "label",
+        "CREATE TABLE `label`(`id` INTEGER PRIMARY KEY AUTOINCREMENT, `name` VARCHAR(64))",
+    )
+    .await?;
+    let mut label = LabelEntity { id: None, name: "first".to_string() };
+    assert!(db.create(&mut label).await?);
+    assert_eq!(label.id, Some(1));
+    assert!(label.set_auto_increment_field(Some(32767)));
+    assert!(!label.set_auto_increment_field(Some(32768)));
+    assert_eq!(label.id, Some(32767));
+
+    recreate_table(
+        &mut db,
+        "device",
+        "CREATE TABLE `device`(`id` INTEGER PRIMARY KEY, `serial` VARCHAR(64) UNIQUE, `state` INTEGER DEFAULT 3)",
+    )
+    .await?;
+    let device = DeviceEntity { id: Optional::None, serial: "A-1".to_string(), state: Optional::None };
+    assert!(db.insert(&device).await?);
+    let reselect_sql = DefaultSqlGenerator::new().get_generated_reselect_sql(&device).unwrap();
+    assert_eq!(reselect_sql, "SELECT `id`,`state` FROM `device` WHERE `serial` = ?");
+    let (id, state): (i64, i32) = db.fetch_tuple(&reselect_sql, device.gen_reselect_arguments_sqlite()?).await?;
+    assert_eq!((id, state), (1, 3));
+    assert!(DefaultSqlGenerator::new().get_generated_reselect_sql(&article).is_none());
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::page::CursorPagination;
+use taitan_orm::{ReaderApi, Schema, WriterApi};
+use taitan_orm_trait::{CmpOperator, LocationExpr, Optional, Selection};
+use time::macros::datetime;
+use time::{Duration, PrimitiveDateTime};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "score_record"]
+pub struct ScoreRecord {
+    #[primary_key]
+    pub id: i64,
+
+    pub score: i32,
+
+    pub created: PrimitiveDateTime,
+}
+
+async fn prepare_score_table(db: &mut SqliteDatabase) -> taitan_orm::Result<()> {
+    recreate_table(
+        db,
+        "score_record",
+        "CREATE TABLE `score_record`\
+    (`id` BIGINT PRIMARY KEY, \
+    `score` INT, \
+    `created` DATETIME)",
+    )
+    .await?;
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn cursor_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("cursor_spec.db").await?;
+    prepare_score_table(&mut db).await?;
+
+    // scores repeat and created goes backwards while repeating too, so created decides the order inside a score,
+    // and the seek predicate must fall back to id to keep the order stable
+    let records: Vec<ScoreRecord> = (1..=25)
+        .map(|i| ScoreRecord {
+            id: i,
+            score: (i % 5) as i32,
+            created: datetime!(2024-01-01 0:00) + Duration::minutes((25 - i) / 10),
+        })
+        .collect();
+    db.insert_all(&records).await?;
+
+    let selection = ScoreRecordSelectedEntity::full_fields();
+    let order_by = ScoreRecordOrdering::build(["score", "created", "id"]).unwrap();
+    let expected: Vec<i64> = {
+        let mut sorted: Vec<(i32, PrimitiveDateTime, i64)> = records.iter().map(|r| (r.score, r.created, r.id)).collect();
+        sorted.sort();
+        sorted.into_iter().map(|(_, _, id)| id).collect()
+    };
+
+    let mut ids: Vec<i64> = Vec::new();
+    let mut page = CursorPagination::first(10);
+    let mut last_prev_cursor: Option<String>;
+    loop {
+        let paged = db.devour_cursor_paged(&selection, &order_by, &page).await?;
+        ids.extend(paged.data.iter().map(|r| r.id.unwrap()));
+        last_prev_cursor = paged.prev_cursor.clone();
+        match paged.next_cursor {
+            None => break,
+            Some(cursor) => page = CursorPagination::new(10, cursor),
+        }
+    }
+    assert_eq!(ids, expected);
+
+    // go back from the last page
+    let paged = db
+        .devour_cursor_paged(&selection, &order_by, &CursorPagination::new(10, last_prev_cursor.unwrap()))
+        .await?;
+    let back_ids: Vec<i64> = paged.data.iter().map(|r| r.id.unwrap()).collect();
+    assert_eq!(back_ids, expected[10..20].to_vec());
+    assert!(paged.prev_cursor.is_some());
+    assert!(paged.next_cursor.is_some());
+
+    // seek predicate is combined with location
+    let location = ScoreRecordLocation {
+        score: Optional::Some(LocationExpr::new(CmpOperator::GreaterOrEq, 3)),
+        ..Default::default()
+    };
+    let paged = db
+        .search_cursor_paged(&selection, &location, &order_by, &CursorPagination::first(6))
+        .await?;
+    assert_eq!(paged.data.len(), 6);
+    let next_cursor = paged.next_cursor.unwrap();
+    let paged = db
+        .search_cursor_paged(&selection, &location, &order_by, &CursorPagination::new(6, next_cursor))
+        .await?;
+    let ids: Vec<i64> = paged.data.iter().map(|r| r.id.unwrap()).collect();
+    assert_eq!(ids, expected[21..25].to_vec());
+    assert!(paged.next_cursor.is_none());
+
+    let result = db
+        .devour_cursor_paged(&selection, &order_by, &CursorPagination::new(10, "invalid"))
+        .await;
+    assert!(result.is_err());
+
+    // mixed directions expand the seek predicate to (a < ?) OR (a = ? AND b > ?) ...
+    let order_by = ScoreRecordOrdering::parse("-score,created,id").unwrap();
+    let expected: Vec<i64> = {
+        let mut sorted: Vec<(i32, PrimitiveDateTime, i64)> = records.iter().map(|r| (-r.score, r.created, r.id)).collect();
+        sorted.sort();
+        sorted.into_iter().map(|(_, _, id)| id).collect()
+    };
+    let mut ids: Vec<i64> = Vec::new();
+    let mut page = CursorPagination::first(7);
+    loop {
+        let paged = db.devour_cursor_paged(&selection, &order_by, &page).await?;
+        ids.extend(paged.data.iter().map(|r| r.id.unwrap()));
+        match paged.next_cursor {
+            None => break,
+            Some(cursor) => page = CursorPagination::new(7, cursor),
+        }
+    }
+    assert_eq!(ids, expected);
+    assert!(ScoreRecordOrdering::parse("-score").is_err());
+    Ok(())
+}
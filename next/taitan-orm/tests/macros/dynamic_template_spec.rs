@@ -0,0 +1,296 @@
+use sqlx::{sqlx_macros, Arguments};
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::{Schema, SqlExecutor, TemplateApi, WriterApi};
+use taitan_orm_macro::TemplateRecord;
+use taitan_orm_trait::pagination::Pagination;
+use taitan_orm_trait::{Optional, TemplateRecord};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "pet"]
+pub struct Pet {
+    #[primary_key]
+    pub id: i64,
+
+    pub name: String,
+
+    pub age: i32,
+}
+
+#[derive(TemplateRecord, Clone, Debug, Default)]
+#[sql = "SELECT `id`, `name`, `age` FROM `pet` WHERE 1 = 1 \
+    {% if age.is_some() %} AND `age` > #{age} {% endif %} \
+    {% if let Some(min) = min_id %} AND `id` >= #{min} {% endif %} \
+    {% if !names.is_empty() %} AND `name` IN ( {% for name in names %} #{name} {% if !loop.last %} , {% endif %} {% endfor %} ) {% endif %} \
+    {% if order_by_age %} ORDER BY `age` , `id` {% elif max_id > 0 %} AND `id` <= #{max_id} ORDER BY `id` {% else %} ORDER BY `id` {% endif %}"]
+pub struct PetSearchTemplate {
+    age: Option<i32>,
+    min_id: Option<i64>,
+    max_id: i64,
+    names: Vec<String>,
+    order_by_age: bool,
+}
+
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT `id`, `name`, `age` FROM `pet` WHERE `id` IN (#{ids}) AND `name` != #{name} ORDER BY `id`"]
+#[count_sql = "SELECT COUNT(*) AS `count` FROM `pet` WHERE `id` IN (#{ids}) AND `name` != #{name}"]
+pub struct PetInTemplate<'a> {
+    ids: Vec<i64>,
+    name: &'a str,
+    #[limit_field]
+    page: Pagination,
+}
+
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT `id`, `name`, `age` FROM `pet` WHERE 1 = 1 \
+    {% if !ids.is_empty() %} AND `id` NOT IN (#{ids}) {% endif %} ORDER BY `id`"]
+pub struct PetNotInTemplate {
+    ids: Vec<i64>,
+}
+
+// paths are relative to CARGO_MANIFEST_DIR, the shared filter is included by both sql files
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql_file = "tests/sql/pet_report.sql"]
+#[count_sql_file = "tests/sql/pet_report_count.sql"]
+pub struct PetReportTemplate<'a> {
+    min_age: i32,
+    name: &'a str,
+    #[limit_field]
+    page: Pagination,
+}
+
+// count sql is derived as SELECT COUNT(*) FROM (...) without the ORDER BY tail
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT `id`, `name`, `age` FROM `pet` WHERE `id` IN (#{ids}) \
+    {% if let Some(age) = min_age %} AND `age` >= #{age} {% endif %} ORDER BY `age` DESC, `id`"]
+pub struct PetDerivedCountTemplate {
+    ids: Vec<i64>,
+    min_age: Option<i32>,
+    #[limit_field]
+    page: Pagination,
+}
+
+// rows are decoded into the output type, no SelectedEntity at call site
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT `id`, `name`, `age` FROM `pet` WHERE `age` > #{age} ORDER BY `id`"]
+#[count_sql = "SELECT COUNT(*) AS `count` FROM `pet` WHERE `age` > #{age}"]
+#[output = "PetSelectedEntity"]
+pub struct PetOlderTemplate {
+    age: i32,
+    #[limit_field]
+    page: Pagination,
+}
+
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT MAX(`age`) FROM `pet` WHERE `name` != #{name}"]
+#[output = "Option<i32>"]
+pub struct PetMaxAgeTemplate<'a> {
+    name: &'a str,
+}
+
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT `name`, `age` FROM `pet` WHERE `id` IN (#{ids}) ORDER BY `id`"]
+#[output = "(String, i32)"]
+pub struct PetNameAgeTemplate {
+    ids: Vec<i64>,
+}
+
+// no output, the template only executes
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "UPDATE `pet` SET `age` = `age` + 1 WHERE `id` = #{id}"]
+pub struct PetGrowTemplate {
+    id: i64,
+}
+
+async fn search_ids(db: &mut SqliteDatabase, template: &PetSearchTemplate) -> taitan_orm::Result<Vec<i64>> {
+    let pets: Vec<PetSelectedEntity> = db.fetch_all_by_template(template).await?;
+    Ok(pets.into_iter().map(|pet| pet.id.unwrap()).collect())
+}
+
+#[sqlx_macros::test]
+pub async fn dynamic_template_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("dynamic_template_spec.db").await?;
+    recreate_table(&mut db, "pet", "CREATE TABLE `pet`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `age` INT)").await?;
+    for (i, (name, age)) in [("tom", 3), ("jerry", 1), ("spike", 5), ("tyke", 2)].into_iter().enumerate() {
+        db.insert(&Pet { id: i as i64 + 1, name: name.to_string(), age }).await?;
+    }
+
+    let template = PetSearchTemplate::default();
+    assert_eq!(template.gen_template_arguments_sqlite().unwrap().len(), 0);
+    assert_eq!(search_ids(&mut db, &template).await?, vec![1, 2, 3, 4]);
+
+    let template = PetSearchTemplate { age: Some(1), ..Default::default() };
+    assert_eq!(search_ids(&mut db, &template).await?, vec![1, 3, 4]);
+
+    let template = PetSearchTemplate { min_id: Some(2), max_id: 3, ..Default::default() };
+    assert_eq!(search_ids(&mut db, &template).await?, vec![2, 3]);
+
+    let names = vec!["tom".to_string(), "spike".to_string(), "tyke".to_string()];
+    let template = PetSearchTemplate { names, order_by_age: true, ..Default::default() };
+    assert_eq!(search_ids(&mut db, &template).await?, vec![4, 1, 3]);
+
+    let names = vec!["tom".to_string(), "jerry".to_string(), "tyke".to_string()];
+    let template = PetSearchTemplate { age: Some(1), min_id: Some(2), max_id: 9, names, order_by_age: false };
+    let sql = template.get_sql(None)?;
+    assert_eq!(sql.matches('?').count(), 6);
+    assert_eq!(template.gen_template_arguments_sqlite().unwrap().len(), 6);
+    assert_eq!(search_ids(&mut db, &template).await?, vec![4]);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn list_template_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("list_template_spec.db").await?;
+    recreate_table(&mut db, "pet", "CREATE TABLE `pet`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `age` INT)").await?;
+    for (i, (name, age)) in [("tom", 3), ("jerry", 1), ("spike", 5), ("tyke", 2)].into_iter().enumerate() {
+        db.insert(&Pet { id: i as i64 + 1, name: name.to_string(), age }).await?;
+    }
+
+    // one placeholder and one argument for every element
+    let template = PetInTemplate { ids: vec![1, 2, 4], name: "jerry", page: Pagination::new(10, 0) };
+    assert_eq!(template.get_sql(None)?, "SELECT `id`, `name`, `age` FROM `pet` WHERE `id` IN (?,?,?) AND `name` != ? ORDER BY `id`");
+    assert_eq!(template.gen_template_arguments_sqlite().unwrap().len(), 4);
+    assert_eq!(template.gen_template_count_arguments_sqlite().unwrap().len(), 4);
+    let paged: taitan_orm::page::PagedList<sqlx::Sqlite, PetSelectedEntity> = db.fetch_paged_by_template(&template).await?;
+    assert_eq!(paged.page.total, 2);
+    let ids: Vec<i64> = paged.data.iter().map(|pet| pet.id.unwrap()).collect();
+    assert_eq!(ids, vec![1, 4]);
+
+    // empty list can not be rendered, neither sql nor count sql
+    let template = PetInTemplate { ids: Vec::new(), name: "jerry", page: Pagination::new(10, 0) };
+    assert!(template.get_sql(None).is_err());
+    assert!(template.get_count_sql().is_err());
+    let result: taitan_orm::Result<Vec<PetSelectedEntity>> = db.fetch_all_by_template(&template).await;
+    assert!(result.is_err());
+    let template = PetDerivedCountTemplate { ids: Vec::new(), min_age: None, page: Pagination::new(2, 0) };
+    assert!(template.get_count_sql().is_err());
+
+    // NOT IN excludes the listed rows, an empty list is guarded so nothing is excluded
+    let template = PetNotInTemplate { ids: vec![1, 3] };
+    assert_eq!(template.get_sql(None)?, "SELECT `id`, `name`, `age` FROM `pet` WHERE 1 = 1  AND `id` NOT IN (?,?)  ORDER BY `id`");
+    let pets: Vec<PetSelectedEntity> = db.fetch_all_by_template(&template).await?;
+    let ids: Vec<i64> = pets.iter().map(|pet| pet.id.unwrap()).collect();
+    assert_eq!(ids, vec![2, 4]);
+    let template = PetNotInTemplate { ids: Vec::new() };
+    let pets: Vec<PetSelectedEntity> = db.fetch_all_by_template(&template).await?;
+    assert_eq!(pets.len(), 4);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn sql_file_template_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("sql_file_template_spec.db").await?;
+    recreate_table(&mut db, "pet", "CREATE TABLE `pet`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `age` INT)").await?;
+    for (i, (name, age)) in [("tom", 3), ("jerry", 1), ("spike", 5), ("tyke", 2)].into_iter().enumerate() {
+        db.insert(&Pet { id: i as i64 + 1, name: name.to_string(), age }).await?;
+    }
+
+    let template = PetReportTemplate { min_age: 2, name: "tom", page: Pagination::new(10, 0) };
+    assert_eq!(
+        template.get_sql(None)?,
+        "SELECT `id`, `name`, `age`\nFROM `pet`\nWHERE -- pets older than age, except the named one\n`age` >= ? AND `name` != ?\nORDER BY `age` DESC, `id`"
+    );
+    assert_eq!(template.get_variables(), vec!["min_age", "name"]);
+    assert_eq!(template.gen_template_count_arguments_sqlite().unwrap().len(), 2);
+    let paged: taitan_orm::page::PagedList<sqlx::Sqlite, PetSelectedEntity> = db.fetch_paged_by_template(&template).await?;
+    assert_eq!(paged.page.total, 2);
+    let ids: Vec<i64> = paged.data.iter().map(|pet| pet.id.unwrap()).collect();
+    assert_eq!(ids, vec![3, 4]);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn typed_template_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("typed_template_spec.db").await?;
+    recreate_table(&mut db, "pet", "CREATE TABLE `pet`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `age` INT)").await?;
+    for (i, (name, age)) in [("tom", 3), ("jerry", 1), ("spike", 5), ("tyke", 2)].into_iter().enumerate() {
+        db.insert(&Pet { id: i as i64 + 1, name: name.to_string(), age }).await?;
+    }
+
+    let affected = db.execute_by_template(&PetGrowTemplate { id: 2 }).await?;
+    assert_eq!(affected, 1);
+
+    let template = PetOlderTemplate { age: 1, page: Pagination::new(2, 0) };
+    let pets = db.query(&template).await?;
+    let ids: Vec<i64> = pets.iter().map(|pet| pet.id.unwrap()).collect();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+    let paged = db.query_paged(&template).await?;
+    assert_eq!(paged.page.total, 4);
+    assert_eq!(paged.data.len(), 2);
+    assert_eq!(paged.data[1].name, Optional::Some("jerry".to_string()));
+
+    let max_age = db.query_option(&PetMaxAgeTemplate { name: "spike" }).await?;
+    assert_eq!(max_age, Some(Some(3)));
+
+    let name_ages = db.query(&PetNameAgeTemplate { ids: vec![2, 4] }).await?;
+    assert_eq!(name_ages, vec![("jerry".to_string(), 2), ("tyke".to_string(), 2)]);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn derived_count_template_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("derived_count_template_spec.db").await?;
+    recreate_table(&mut db, "pet", "CREATE TABLE `pet`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `age` INT)").await?;
+    for (i, (name, age)) in [("tom", 3), ("jerry", 1), ("spike", 5), ("tyke", 2)].into_iter().enumerate() {
+        db.insert(&Pet { id: i as i64 + 1, name: name.to_string(), age }).await?;
+    }
+
+    let template = PetDerivedCountTemplate { ids: vec![1, 2, 3, 4], min_age: Some(2), page: Pagination::new(2, 0) };
+    assert_eq!(
+        template.get_count_sql()?.unwrap(),
+        "SELECT COUNT(*) AS count FROM (SELECT `id`, `name`, `age` FROM `pet` WHERE `id` IN (?,?,?,?)  AND `age` >= ? ) AS taitan_count"
+    );
+    assert_eq!(template.gen_template_count_arguments_sqlite().unwrap().len(), 5);
+    let paged: taitan_orm::page::PagedList<sqlx::Sqlite, PetSelectedEntity> = db.fetch_paged_by_template(&template).await?;
+    assert_eq!(paged.page.total, 3);
+    let ids: Vec<i64> = paged.data.iter().map(|pet| pet.id.unwrap()).collect();
+    assert_eq!(ids, vec![3, 1]);
+
+    let template = PetDerivedCountTemplate { ids: vec![2, 4], min_age: None, page: Pagination::new(2, 0) };
+    let paged: taitan_orm::page::PagedList<sqlx::Sqlite, PetSelectedEntity> = db.fetch_paged_by_template(&template).await?;
+    assert_eq!(paged.page.total, 2);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn scalar_tuple_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("scalar_tuple_spec.db").await?;
+    recreate_table(&mut db, "pet", "CREATE TABLE `pet`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `age` INT)").await?;
+    for (i, (name, age)) in [("tom", 3), ("jerry", 1), ("spike", 5), ("tyke", 2)].into_iter().enumerate() {
+        db.insert(&Pet { id: i as i64 + 1, name: name.to_string(), age }).await?;
+    }
+
+    let mut args = sqlx::sqlite::SqliteArguments::default();
+    args.add(2).unwrap();
+    let max_age: i32 = db.fetch_scalar("SELECT MAX(`age`) FROM `pet` WHERE `age` > ?", args).await?;
+    assert_eq!(max_age, 5);
+    let missing: Option<i32> = db.fetch_scalar("SELECT MAX(`age`) FROM `pet` WHERE `age` > 9", Default::default()).await?;
+    assert_eq!(missing, None);
+    let result: taitan_orm::Result<i64> = db.fetch_scalar("SELECT `id` FROM `pet` WHERE `id` > 9", Default::default()).await;
+    assert!(result.is_err());
+    let names: Vec<String> = db.fetch_scalars("SELECT `name` FROM `pet` ORDER BY `age`", Default::default()).await?;
+    assert_eq!(names, vec!["jerry", "tyke", "tom", "spike"]);
+    let (count, total): (i64, i64) = db.fetch_tuple("SELECT COUNT(*), SUM(`age`) FROM `pet`", Default::default()).await?;
+    assert_eq!((count, total), (4, 11));
+    let rows: Vec<(i64, String)> = db.fetch_tuples("SELECT `id`, `name` FROM `pet` WHERE `age` < 3 ORDER BY `id`", Default::default()).await?;
+    assert_eq!(rows, vec![(2, "jerry".to_string()), (4, "tyke".to_string())]);
+
+    let template = PetSearchTemplate { age: Some(2), ..Default::default() };
+    let ids: Vec<i64> = db.fetch_scalars_by_template(&template).await?;
+    assert_eq!(ids, vec![1, 3]);
+    let pets: Vec<(i64, String, i32)> = db.fetch_tuples_by_template(&template).await?;
+    assert_eq!(pets[1], (3, "spike".to_string(), 5));
+    let template = PetSearchTemplate { names: vec!["tyke".to_string()], ..Default::default() };
+    let id: i64 = db.fetch_scalar_by_template(&template).await?;
+    assert_eq!(id, 4);
+
+    let mut trx = db.transaction().await?;
+    let count: i64 = trx.fetch_scalar("SELECT COUNT(*) FROM `pet`", Default::default()).await?;
+    assert_eq!(count, 4);
+    let (name, age): (String, i32) = trx.fetch_tuple_by_template(&template).await.map(|(_, name, age): (i64, String, i32)| (name, age))?;
+    assert_eq!((name.as_str(), age), ("tyke", 2));
+    let ids: Vec<i64> = trx.fetch_scalars("SELECT `id` FROM `pet` ORDER BY `id` DESC", Default::default()).await?;
+    assert_eq!(ids, vec![4, 3, 2, 1]);
+    trx.commit().await?;
+    Ok(())
+}
@@ -3,7 +3,7 @@ use sqlx::sqlx_macros;
 use time::PrimitiveDateTime;
 use uuid::Uuid;
 use taitan_orm::Schema;
-use taitan_orm_trait::Optional;
+use taitan_orm_trait::{Optional, Selection};
 
 #[derive(Schema, Clone, Debug)]
 #[table_name = "user"]
@@ -26,9 +26,15 @@ pub struct UserEntity {
 
 #[sqlx_macros::test]
 pub async fn entity_macro_spec() -> taitan_orm::Result<()> {
-
-
-
-
+    // only Selected fields are selected, Null and None are not, renamed fields by their column name
+    let selection = UserSelectedEntity {
+        id: Optional::Selected,
+        request_id: Optional::Selected,
+        age: Optional::Null,
+        name: Optional::None,
+        ..Default::default()
+    };
+    assert_eq!(selection.get_selected_fields(), vec!["id", "r_id"]);
+    assert!(UserSelectedEntity::default().get_selected_fields().is_empty());
     Ok(())
 }
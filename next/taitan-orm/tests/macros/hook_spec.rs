@@ -0,0 +1,169 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::traits::{HookExecutor, HookFuture, WriteHooks};
+use taitan_orm::{Assign, Schema, SqlExecutor, WriterApi};
+use taitan_orm_trait::Optional;
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "account"]
+#[hooks(entity, mutation, unique)]
+pub struct Account {
+    #[primary_key]
+    pub id: i64,
+
+    pub email: String,
+}
+
+// no #[hooks], get_hooks keeps the default None
+#[derive(Schema, Clone, Debug)]
+#[table_name = "audit"]
+pub struct Audit {
+    #[primary_key]
+    #[auto_increment]
+    pub id: Option<i64>,
+
+    pub action: String,
+}
+
+// before hooks change the value to be written
+fn normalize_email(email: &mut String) -> Result<(), Box<dyn std::error::Error>> {
+    *email = email.trim().to_lowercase();
+    if !email.contains('@') {
+        return Err(format!("invalid email: {}", email).into());
+    }
+    Ok(())
+}
+
+impl WriteHooks for Account {
+    fn before_insert<'a>(&'a mut self, _executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async move { normalize_email(&mut self.email) })
+    }
+
+    fn after_insert<'a>(&'a self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async move {
+            let audit = Audit { id: None, action: format!("insert {}", self.id) };
+            executor.insert(&audit).await?;
+            Ok(())
+        })
+    }
+}
+
+impl WriteHooks for AccountMutation {
+    fn before_update<'a>(&'a mut self, _executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async move {
+            match &mut self.email {
+                Assign::Set(email) => normalize_email(email),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    fn after_update<'a>(&'a self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async move {
+            executor.execute_plain("INSERT INTO `audit`(`action`) VALUES ('update')").await?;
+            Ok(())
+        })
+    }
+}
+
+impl WriteHooks for AccountPrimary {
+    fn before_delete<'a>(&'a mut self, _executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async move {
+            if self.id == 0 {
+                return Err("account 0 can not be deleted".into());
+            }
+            Ok(())
+        })
+    }
+
+    fn after_delete<'a>(&'a self, executor: &'a mut dyn HookExecutor) -> HookFuture<'a> {
+        Box::pin(async move {
+            let audit = Audit { id: None, action: format!("delete {}", self.id) };
+            executor.insert(&audit).await?;
+            Ok(())
+        })
+    }
+}
+
+async fn fetch_actions(db: &mut SqliteDatabase) -> taitan_orm::Result<Vec<String>> {
+    let actions: Vec<(String,)> = db.fetch_tuples("SELECT `action` FROM `audit` ORDER BY `id`", Default::default()).await?;
+    Ok(actions.into_iter().map(|(action,)| action).collect())
+}
+
+async fn fetch_email(db: &mut SqliteDatabase, id: i64) -> taitan_orm::Result<String> {
+    let sql = format!("SELECT `email` FROM `account` WHERE `id` = {}", id);
+    let (email,): (String,) = db.fetch_tuple(&sql, Default::default()).await?;
+    Ok(email)
+}
+
+async fn count_accounts(db: &mut SqliteDatabase) -> taitan_orm::Result<i64> {
+    let (count,): (i64,) = db.fetch_tuple("SELECT COUNT(*) FROM `account`", Default::default()).await?;
+    Ok(count)
+}
+
+#[sqlx_macros::test]
+pub async fn hook_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("hook_spec.db").await?;
+    recreate_table(&mut db, "account", "CREATE TABLE `account`(`id` BIGINT PRIMARY KEY, `email` VARCHAR(64))").await?;
+    recreate_table(
+        &mut db,
+        "audit",
+        "CREATE TABLE `audit`(`id` INTEGER PRIMARY KEY AUTOINCREMENT, `action` VARCHAR(64))",
+    )
+    .await?;
+
+    // error of before hook aborts the insert
+    let invalid = Account { id: 1, email: "nobody".to_string() };
+    assert!(db.insert(&invalid).await.is_err());
+    assert_eq!(count_accounts(&mut db).await?, 0);
+    assert!(fetch_actions(&mut db).await?.is_empty());
+
+    // the value changed by before hook is written, the borrowed entity is kept as it is
+    let account = Account { id: 1, email: " One@Taitan.ORG ".to_string() };
+    assert!(db.insert(&account).await?);
+    assert_eq!(account.email, " One@Taitan.ORG ");
+    assert_eq!(fetch_email(&mut db, 1).await?, "one@taitan.org");
+    assert!(db.upsert(&account).await?);
+    assert_eq!(fetch_actions(&mut db).await?, vec!["insert 1", "insert 1"]);
+
+    let mutation = AccountMutation { email: Assign::Set("invalid".to_string()) };
+    assert!(db.update(&mutation, &AccountPrimary { id: 1 }).await.is_err());
+    let mutation = AccountMutation { email: Assign::Set("First@Taitan.org".to_string()) };
+    assert!(db.update(&mutation, &AccountPrimary { id: 1 }).await?);
+    assert_eq!(fetch_email(&mut db, 1).await?, "first@taitan.org");
+    // after hooks run only when rows are affected
+    assert!(!db.update(&mutation, &AccountPrimary { id: 2 }).await?);
+    assert_eq!(db.change(&mutation, &AccountColumns::id().ge(1)).await?, 1);
+    assert_eq!(fetch_actions(&mut db).await?, vec!["insert 1", "insert 1", "update", "update"]);
+
+    // writes of hooks share the transaction, rollback discards them together
+    let mut trx = db.transaction().await?;
+    assert!(trx.insert(&Account { id: 2, email: "two@taitan.org".to_string() }).await?);
+    assert!(trx.delete(&AccountPrimary { id: 1 }).await?);
+    trx.rollback().await?;
+    assert_eq!(count_accounts(&mut db).await?, 1);
+    assert_eq!(fetch_actions(&mut db).await?.len(), 4);
+
+    let mut trx = db.transaction().await?;
+    assert!(trx.insert(&Account { id: 0, email: "zero@taitan.org".to_string() }).await?);
+    assert!(trx.delete(&AccountPrimary { id: 0 }).await.is_err());
+    assert!(trx.delete(&AccountPrimary { id: 1 }).await?);
+    trx.commit().await?;
+    assert_eq!(count_accounts(&mut db).await?, 1);
+    assert_eq!(fetch_actions(&mut db).await?, vec!["insert 1", "insert 1", "update", "update", "insert 0", "delete 1"]);
+
+    // create changes the given entity in place
+    let mut account = Account { id: 3, email: "Three@Taitan.org".to_string() };
+    assert!(db.create(&mut account).await?);
+    assert_eq!(account.email, "three@taitan.org");
+    assert_eq!(fetch_email(&mut db, 3).await?, "three@taitan.org");
+    assert!(db.insert_all(&[Account { id: 4, email: "Four@Taitan.org".to_string() }]).await? > 0);
+    assert_eq!(fetch_email(&mut db, 4).await?, "four@taitan.org");
+    assert_eq!(fetch_actions(&mut db).await?.len(), 8);
+
+    // entity without #[hooks] is written as before
+    assert!(db.insert(&Audit { id: Some(100), action: "manual".to_string() }).await?);
+    assert_eq!(fetch_actions(&mut db).await?.len(), 9);
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::page::Pagination;
+use taitan_orm::traits::{JoinedCondition, JoinedConditions, JoinedField, JoinedMode};
+use taitan_orm::{DefaultSqlGenerator, ReaderApi, Schema, SqlGenerator, WriterApi};
+use taitan_orm_trait::{Location, Optional, Selection};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "classroom"]
+pub struct Classroom {
+    #[primary_key]
+    pub id: i64,
+
+    pub name: String,
+}
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "student"]
+pub struct Student {
+    #[primary_key]
+    pub id: i64,
+
+    pub name: String,
+
+    pub class_id: i64,
+}
+
+async fn prepare_joined_tables(db: &mut SqliteDatabase) -> taitan_orm::Result<()> {
+    recreate_table(db, "classroom", "CREATE TABLE `classroom`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64))").await?;
+    recreate_table(
+        db,
+        "student",
+        "CREATE TABLE `student`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `class_id` BIGINT)",
+    )
+    .await?;
+    for (id, name) in [(1, "one"), (2, "two")] {
+        db.insert(&Classroom { id, name: name.to_string() }).await?;
+    }
+    for (id, name, class_id) in [(10, "allen", 1), (11, "bob", 2), (12, "carl", 1), (13, "dave", 3)] {
+        db.insert(&Student { id, name: name.to_string(), class_id }).await?;
+    }
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn joined_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("joined_spec.db").await?;
+    prepare_joined_tables(&mut db).await?;
+
+    let joined_conds = JoinedConditions::Two(JoinedCondition::new(
+        JoinedMode::Inner,
+        "student",
+        "classroom",
+        vec![(JoinedField::new("student", "class_id"), JoinedField::new("classroom", "id"))],
+    ));
+    let selection = (StudentSelectedEntity::full_fields(), ClassroomSelectedEntity::full_fields());
+    let order_by = StudentOrdering::build(["id"]).unwrap();
+
+    // both tables have id and name, columns must be qualified
+    let student_location = StudentColumns::id().gt(10);
+    let classroom_location = ClassroomColumns::name().eq("one".to_string());
+    let locations: [&dyn Location; 2] = [&student_location, &classroom_location];
+    let result = db.search_joined(&joined_conds, &locations, &selection, &Some(&order_by)).await?;
+    assert_eq!(result.len(), 1);
+    let (student, classroom) = &result[0];
+    assert_eq!(student.id, Optional::Some(12));
+    assert_eq!(student.name, Optional::Some("carl".to_string()));
+    assert_eq!(classroom.id, Optional::Some(1));
+    assert_eq!(classroom.name, Optional::Some("one".to_string()));
+
+    // partial selection keeps columns of each entity in order
+    let selection = (
+        StudentSelectedEntity {
+            name: Optional::Selected,
+            ..Default::default()
+        },
+        ClassroomSelectedEntity::full_fields(),
+    );
+    let empty_location = StudentLocation::default();
+    let locations: [&dyn Location; 1] = [&empty_location];
+    let result = db.search_joined(&joined_conds, &locations, &selection, &Some(&order_by)).await?;
+    let names: Vec<(String, String)> = result
+        .iter()
+        .map(|(s, c)| (s.name.clone().unwrap(), c.name.clone().unwrap()))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("allen".to_string(), "one".to_string()),
+            ("bob".to_string(), "two".to_string()),
+            ("carl".to_string(), "one".to_string())
+        ]
+    );
+    assert_eq!(result[0].0.id, Optional::None);
+    // order by fields are qualified by the table of the ordering
+    let generator = DefaultSqlGenerator::new();
+    assert_eq!(generator.get_qualified_field_sql("student", "id"), "`student`.`id`");
+    let sql = generator.get_page_joined_search_sql(&joined_conds, &locations, &Some(&order_by), &[&selection.0, &selection.1], &None);
+    assert!(sql.contains("ORDER BY `student`.`id`"));
+
+    let page = Pagination::new(2, 1);
+    let paged = db.search_joined_paged(&joined_conds, &locations, &selection, &order_by, &page).await?;
+    assert_eq!(paged.page.total, 3);
+    assert_eq!(paged.data.len(), 1);
+    assert_eq!(paged.data[0].0.name, Optional::Some("carl".to_string()));
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::{ReaderApi, Schema, WriterApi};
+use taitan_orm_trait::{CmpOperator, Location, LocationExpr, LocationTree, Optional, Selection};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "member"]
+pub struct Member {
+    #[primary_key]
+    pub id: i64,
+
+    pub name: String,
+
+    pub level: Optional<i32>,
+}
+
+async fn prepare_member_table(db: &mut SqliteDatabase) -> taitan_orm::Result<()> {
+    recreate_table(
+        db,
+        "member",
+        "CREATE TABLE `member`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `level` INT)",
+    )
+    .await?;
+    Ok(())
+}
+
+async fn search_ids(db: &mut SqliteDatabase, location: &dyn Location) -> taitan_orm::Result<Vec<i64>> {
+    let selection = MemberSelectedEntity::full_fields();
+    let order_by = MemberOrdering::build(["id"]).unwrap();
+    let members: Vec<MemberSelectedEntity> = db.search(&selection, location, &Some(&order_by), &None).await?;
+    let ids: Vec<i64> = members.iter().map(|m| m.id.unwrap()).collect();
+    assert_eq!(db.count(location).await?, ids.len() as u64);
+    Ok(ids)
+}
+
+#[sqlx_macros::test]
+pub async fn location_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("location_spec.db").await?;
+    prepare_member_table(&mut db).await?;
+    let levels = [Optional::Some(1), Optional::Some(2), Optional::Null, Optional::Some(3), Optional::Some(4)];
+    for (i, level) in levels.into_iter().enumerate() {
+        let member = Member {
+            id: i as i64 + 1,
+            name: format!("member-{}", i + 1),
+            level,
+        };
+        db.insert(&member).await?;
+    }
+
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::is_in(vec![1, 3, 5])),
+        ..Default::default()
+    };
+    assert_eq!(search_ids(&mut db, &location).await?, vec![1, 4]);
+
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::not_in(vec![1, 3])),
+        ..Default::default()
+    };
+    assert_eq!(search_ids(&mut db, &location).await?, vec![2, 5]);
+
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::is_in(Vec::new())),
+        ..Default::default()
+    };
+    assert_eq!(search_ids(&mut db, &location).await?, Vec::<i64>::new());
+
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::between(2, 3)),
+        ..Default::default()
+    };
+    assert_eq!(search_ids(&mut db, &location).await?, vec![2, 4]);
+
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::is_null()),
+        ..Default::default()
+    };
+    assert_eq!(search_ids(&mut db, &location).await?, vec![3]);
+
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::is_not_null()),
+        ..Default::default()
+    };
+    assert_eq!(search_ids(&mut db, &location).await?, vec![1, 2, 4, 5]);
+
+    // multiple fields are joined with AND
+    let location = MemberLocation {
+        name: Optional::Some(LocationExpr::new(CmpOperator::NotLike, "%-1".to_string())),
+        level: Optional::Some(LocationExpr::new(CmpOperator::NotEq, 4)),
+        ..Default::default()
+    };
+    assert_eq!(search_ids(&mut db, &location).await?, vec![2, 4]);
+
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::new(CmpOperator::Between, 2)),
+        ..Default::default()
+    };
+    assert!(db.count(&location).await.is_err());
+
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::is_in(vec![2, 4])),
+        ..Default::default()
+    };
+    assert_eq!(db.purify(&location).await?, 2);
+    let location = MemberLocation {
+        level: Optional::Some(LocationExpr::not_in(Vec::new())),
+        ..Default::default()
+    };
+    assert_eq!(search_ids(&mut db, &location).await?, vec![1, 3, 4]);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn location_tree_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("location_tree_spec.db").await?;
+    prepare_member_table(&mut db).await?;
+    let levels = [Optional::Some(1), Optional::Some(2), Optional::Null, Optional::Some(3), Optional::Some(4)];
+    for (i, level) in levels.into_iter().enumerate() {
+        let member = Member {
+            id: i as i64 + 1,
+            name: format!("member-{}", i + 1),
+            level,
+        };
+        db.insert(&member).await?;
+    }
+
+    // (level >= 3 OR level IS NULL) AND NOT (name LIKE '%-5')
+    let high_level = MemberLocation {
+        level: Optional::Some(LocationExpr::new(CmpOperator::GreaterOrEq, 3)),
+        ..Default::default()
+    };
+    let no_level = MemberLocation {
+        level: Optional::Some(LocationExpr::is_null()),
+        ..Default::default()
+    };
+    let last_member = MemberLocation {
+        name: Optional::Some(LocationExpr::new(CmpOperator::Like, "%-5".to_string())),
+        ..Default::default()
+    };
+    let location = LocationTree::leaf(high_level)
+        .or(no_level)
+        .and(!LocationTree::leaf(last_member));
+    assert_eq!(search_ids(&mut db, &location).await?, vec![3, 4]);
+
+    // arguments follow the order of where clause: 1, 2, 4
+    let location = LocationTree::any([
+        LocationTree::leaf(MemberLocation {
+            id: Optional::Some(LocationExpr::new(CmpOperator::Eq, 1)),
+            ..Default::default()
+        }),
+        LocationTree::leaf(MemberLocation {
+            id: Optional::Some(LocationExpr::new(CmpOperator::GreaterThan, 2)),
+            level: Optional::Some(LocationExpr::new(CmpOperator::LessOrEq, 4)),
+            ..Default::default()
+        }),
+    ]);
+    assert_eq!(search_ids(&mut db, &location).await?, vec![1, 4, 5]);
+
+    assert_eq!(db.purify(&location).await?, 3);
+    assert_eq!(search_ids(&mut db, &LocationTree::leaf(MemberLocation::default())).await?, vec![2, 3]);
+    Ok(())
+}
@@ -1,3 +1,20 @@
 pub mod entity_spec;
 mod template_spec;
 mod execute_loop;
+pub mod create_spec;
+mod batch_spec;
+mod stream_spec;
+mod cursor_spec;
+mod ordering_spec;
+mod location_spec;
+mod column_spec;
+mod joined_spec;
+mod relation_spec;
+mod dynamic_template_spec;
+mod aggregate_spec;
+mod mutation_spec;
+mod version_spec;
+mod soft_delete_spec;
+mod timestamp_spec;
+mod hook_spec;
+mod spec_helper;
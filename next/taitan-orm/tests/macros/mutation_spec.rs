@@ -0,0 +1,92 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::{Assign, ReaderApi, Schema, SqlExecutor, SqlGenerator, WriterApi};
+use taitan_orm_trait::{Mutation, Optional, Selection};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "account"]
+pub struct Account {
+    #[primary_key]
+    pub id: i64,
+
+    pub views: i64,
+
+    pub balance: f64,
+
+    pub init_balance: f64,
+
+    pub memo: Optional<String>,
+}
+
+async fn select_account(db: &mut SqliteDatabase, id: i64) -> taitan_orm::Result<AccountSelectedEntity> {
+    let selection = AccountSelectedEntity::full_fields();
+    let account: Option<AccountSelectedEntity> = db.select(&selection, &AccountPrimary { id }).await?;
+    Ok(account.unwrap())
+}
+
+#[sqlx_macros::test]
+pub async fn mutation_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("mutation_spec.db").await?;
+    recreate_table(
+        &mut db,
+        "account",
+        "CREATE TABLE `account`(`id` BIGINT PRIMARY KEY, `views` BIGINT, `balance` REAL, `init_balance` REAL, `memo` VARCHAR(64))",
+    )
+    .await?;
+    for id in 1..=3 {
+        let account = Account { id, views: 10, balance: 100.0, init_balance: 50.0, memo: Optional::Some("new".to_string()) };
+        db.insert(&account).await?;
+    }
+
+    let mutation = AccountMutation {
+        views: Assign::Incr(5),
+        balance: Assign::Decr(30.5),
+        memo: Assign::Null,
+        ..Default::default()
+    };
+    assert_eq!(
+        mutation.get_set_clause('`', '?'),
+        "`views` = `views` + ?,`balance` = `balance` - ?,`memo` = NULL"
+    );
+    assert_eq!(
+        taitan_orm::DefaultSqlGenerator::new().get_update_sql(&mutation, &AccountPrimary { id: 1 }),
+        "UPDATE `account` SET `views` = `views` + ?,`balance` = `balance` - ?,`memo` = NULL WHERE `id` = ?"
+    );
+    let generator = taitan_orm::DefaultSqlGenerator::new();
+    assert_eq!(
+        generator.pg_post_process(generator.get_update_sql(&mutation, &AccountPrimary { id: 1 })),
+        "UPDATE `account` SET `views` = `views` + $1,`balance` = `balance` - $2,`memo` = NULL WHERE `id` = $3"
+    );
+    assert!(db.update(&mutation, &AccountPrimary { id: 1 }).await?);
+    // increments are applied by the database, so they are accumulated
+    assert!(db.update(&mutation, &AccountPrimary { id: 1 }).await?);
+    let account = select_account(&mut db, 1).await?;
+    assert_eq!(account.views, Optional::Some(20));
+    assert_eq!(account.balance, Optional::Some(39.0));
+    let memo: Option<String> = db.fetch_scalar("SELECT `memo` FROM `account` WHERE `id` = 1", Default::default()).await?;
+    assert_eq!(memo, None);
+
+    // arguments of mutation are bound before location arguments, Copy and Null bind nothing
+    let mutation = AccountMutation {
+        balance: Assign::copy_from(AccountColumns::init_balance()),
+        views: Assign::Set(0),
+        memo: Optional::Some("reset".to_string()).into(),
+        ..Default::default()
+    };
+    assert_eq!(db.change(&mutation, &AccountColumns::id().ge(2)).await?, 2);
+    let account = select_account(&mut db, 3).await?;
+    assert_eq!(account.views, Optional::Some(0));
+    assert_eq!(account.balance, Optional::Some(50.0));
+    assert_eq!(account.memo, Optional::Some("reset".to_string()));
+    let account = select_account(&mut db, 1).await?;
+    assert_eq!(account.views, Optional::Some(20));
+
+    let mut trx = db.transaction().await?;
+    let mutation = AccountMutation { views: Assign::Decr(1), ..Default::default() };
+    assert_eq!(trx.change(&mutation, &AccountColumns::views().lt(5)).await?, 2);
+    trx.commit().await?;
+    let account = select_account(&mut db, 2).await?;
+    assert_eq!(account.views, Optional::Some(-1));
+    Ok(())
+}
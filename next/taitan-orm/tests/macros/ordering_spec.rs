@@ -0,0 +1,59 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::traits::FieldOrder;
+use taitan_orm::{ReaderApi, Schema, WriterApi};
+use taitan_orm_trait::{Optional, Selection};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "rank_record"]
+pub struct RankRecord {
+    #[primary_key]
+    pub id: i64,
+
+    pub rank: Optional<i32>,
+}
+
+async fn prepare_rank_table(db: &mut SqliteDatabase) -> taitan_orm::Result<()> {
+    recreate_table(db, "rank_record", "CREATE TABLE `rank_record`(`id` BIGINT PRIMARY KEY, `rank` INT)").await?;
+    Ok(())
+}
+
+async fn devour_ids(db: &mut SqliteDatabase, order_by: &RankRecordOrdering<'_>) -> taitan_orm::Result<Vec<i64>> {
+    let selection = RankRecordSelectedEntity::full_fields();
+    let records: Vec<RankRecordSelectedEntity> = db.devour(&selection, &Some(order_by), &None).await?;
+    Ok(records.iter().map(|r| r.id.unwrap()).collect())
+}
+
+#[sqlx_macros::test]
+pub async fn ordering_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("ordering_spec.db").await?;
+    prepare_rank_table(&mut db).await?;
+    let ranks = [Optional::Some(2), Optional::Null, Optional::Some(1), Optional::Some(2)];
+    let records: Vec<RankRecord> = ranks
+        .into_iter()
+        .enumerate()
+        .map(|(i, rank)| RankRecord { id: i as i64 + 1, rank })
+        .collect();
+    for record in &records {
+        db.insert(record).await?;
+    }
+
+    let order_by = RankRecordOrdering::build(["rank", "id"]).unwrap();
+    assert_eq!(devour_ids(&mut db, &order_by).await?, vec![2, 3, 1, 4]);
+
+    let order_by = RankRecordOrdering::parse("-rank,-id").unwrap();
+    assert_eq!(devour_ids(&mut db, &order_by).await?, vec![4, 1, 3, 2]);
+
+    let order_by = RankRecordOrdering::parse("rank:nulls_last,-id").unwrap();
+    assert_eq!(devour_ids(&mut db, &order_by).await?, vec![3, 4, 1, 2]);
+
+    let order_by =
+        RankRecordOrdering::build_with_orders([("rank", FieldOrder::desc().nulls_first()), ("id", FieldOrder::asc())])
+            .unwrap();
+    assert_eq!(devour_ids(&mut db, &order_by).await?, vec![2, 1, 4, 3]);
+
+    assert!(RankRecordOrdering::parse("-rank").is_err());
+    assert!(RankRecordOrdering::parse("-unknown,id").is_err());
+    Ok(())
+}
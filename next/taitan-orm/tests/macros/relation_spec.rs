@@ -0,0 +1,111 @@
+use sqlx::sqlx_macros;
+use taitan_orm::{ReaderApi, Schema, WriterApi};
+use taitan_orm_trait::{Optional, Selection};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "author"]
+#[has_many(name = "books", table = "book", foreign_key = "author_id")]
+#[has_one(name = "biography", table = "biography", foreign_key = "author_id")]
+pub struct Author {
+    #[primary_key]
+    pub id: i64,
+
+    pub name: String,
+}
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "book"]
+#[belongs_to(name = "author", table = "author", foreign_key = "author_id")]
+pub struct Book {
+    #[primary_key]
+    pub id: i64,
+
+    pub author_id: Optional<i64>,
+
+    pub title: String,
+}
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "biography"]
+pub struct Biography {
+    #[primary_key]
+    pub id: i64,
+
+    pub author_id: i64,
+
+    pub content: String,
+}
+
+fn titles(books: &[BookSelectedEntity]) -> Vec<String> {
+    let mut titles: Vec<String> = books.iter().map(|b| b.title.clone().unwrap()).collect();
+    titles.sort();
+    titles
+}
+
+#[sqlx_macros::test]
+pub async fn relation_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("relation_spec.db").await?;
+    recreate_table(&mut db, "author", "CREATE TABLE `author`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64))").await?;
+    recreate_table(
+        &mut db,
+        "book",
+        "CREATE TABLE `book`(`id` BIGINT PRIMARY KEY, `author_id` BIGINT, `title` VARCHAR(64))",
+    )
+    .await?;
+    recreate_table(
+        &mut db,
+        "biography",
+        "CREATE TABLE `biography`(`id` BIGINT PRIMARY KEY, `author_id` BIGINT, `content` VARCHAR(64))",
+    )
+    .await?;
+
+    let authors: Vec<Author> = ["allen", "bob", "carl"]
+        .iter()
+        .enumerate()
+        .map(|(i, name)| Author { id: i as i64 + 1, name: name.to_string() })
+        .collect();
+    for author in authors.iter() {
+        db.insert(author).await?;
+    }
+    let books = [(1, Optional::Some(1), "a1"), (2, Optional::Some(2), "b1"), (3, Optional::Some(1), "a2"), (4, Optional::Null, "x")];
+    for (id, author_id, title) in books {
+        db.insert(&Book { id, author_id, title: title.to_string() }).await?;
+    }
+    db.insert(&Biography { id: 1, author_id: 2, content: "bob's life".to_string() }).await?;
+
+    // author_id is not selected, but still loaded to match books with authors
+    let book_selection = BookSelectedEntity { title: Optional::Selected, ..Default::default() };
+    let loaded = authors[0].load_books(&mut db, &book_selection).await?;
+    assert_eq!(titles(&loaded), vec!["a1", "a2"]);
+    assert!(loaded.iter().all(|b| b.author_id == Optional::Some(1) && b.id.not_some()));
+
+    let loaded = Author::load_many_books(&mut db, &authors, &book_selection).await?;
+    assert_eq!(loaded.len(), 3);
+    assert_eq!(titles(&loaded[0]), vec!["a1", "a2"]);
+    assert_eq!(titles(&loaded[1]), vec!["b1"]);
+    assert!(loaded[2].is_empty());
+
+    let biography_selection = BiographySelectedEntity::full_fields();
+    let loaded = Author::load_many_biography(&mut db, &authors, &biography_selection).await?;
+    assert!(loaded[0].is_none() && loaded[2].is_none());
+    assert_eq!(loaded[1].as_ref().unwrap().content, Optional::Some("bob's life".to_string()));
+
+    // loaders of selected entity, book without author relates to nothing
+    let order_by = BookOrdering::build(["id"]).unwrap();
+    let selected_books: Vec<BookSelectedEntity> = db
+        .search(&BookSelectedEntity::full_fields(), &BookColumns::id().is_not_null(), &Some(&order_by), &None)
+        .await?;
+    let author_selection = AuthorSelectedEntity { name: Optional::Selected, ..Default::default() };
+    let loaded = BookSelectedEntity::load_many_author(&mut db, &selected_books, &author_selection).await?;
+    let names: Vec<Option<String>> = loaded.into_iter().map(|a| a.map(|a| a.name.unwrap())).collect();
+    assert_eq!(names, vec![Some("allen".to_string()), Some("bob".to_string()), Some("allen".to_string()), None]);
+
+    let book = Book { id: 4, author_id: Optional::Some(3), title: "c1".to_string() };
+    let author = book.load_author(&mut db, &author_selection).await?;
+    assert_eq!(author.unwrap().name, Optional::Some("carl".to_string()));
+
+    let loaded = Author::load_many_books(&mut db, &[], &book_selection).await?;
+    assert!(loaded.is_empty());
+    Ok(())
+}
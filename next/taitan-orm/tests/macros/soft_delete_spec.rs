@@ -0,0 +1,187 @@
+use sqlx::sqlx_macros;
+use taitan_orm::page::Pagination;
+use taitan_orm::traits::{JoinedCondition, JoinedConditions, JoinedField, JoinedMode};
+use taitan_orm::{Assign, ReaderApi, Schema, SqlExecutor, SqlGenerator, WriterApi};
+use taitan_orm_trait::{Optional, Selection};
+use time::PrimitiveDateTime;
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "member"]
+pub struct Member {
+    #[primary_key]
+    pub id: i64,
+
+    pub name: String,
+
+    #[soft_delete]
+    pub deleted: bool,
+}
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "ticket"]
+pub struct Ticket {
+    #[primary_key]
+    pub id: i64,
+
+    pub title: String,
+
+    #[soft_delete]
+    pub deleted_at: Optional<PrimitiveDateTime>,
+}
+
+#[sqlx_macros::test]
+pub async fn soft_delete_flag_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("soft_delete_flag_spec.db").await?;
+    recreate_table(
+        &mut db,
+        "member",
+        "CREATE TABLE `member`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `deleted` BOOLEAN NOT NULL)",
+    )
+    .await?;
+    for (id, name) in [(1, "a"), (2, "b"), (3, "c")] {
+        db.insert(&Member { id, name: name.to_string(), deleted: false }).await?;
+    }
+
+    let generator = taitan_orm::DefaultSqlGenerator::new();
+    assert_eq!(
+        generator.get_delete_sql(&MemberPrimary { id: 1 }),
+        "UPDATE `member` SET `deleted` = TRUE WHERE (`id` = ?) AND `deleted` IS NOT TRUE"
+    );
+    assert_eq!(
+        generator.get_count_sql(&MemberColumns::id().ge(1)),
+        "SELECT COUNT(1) AS `count` FROM `member` WHERE (`id`>=?) AND `deleted` IS NOT TRUE"
+    );
+
+    assert!(db.delete(&MemberPrimary { id: 1 }).await?);
+    // the row is still in the table, but it can not be deleted twice
+    assert!(!db.delete(&MemberPrimary { id: 1 }).await?);
+    assert_eq!(db.count_all("member").await?, 3);
+
+    assert!(!db.exists(&MemberPrimary { id: 1 }).await?);
+    assert!(db.exists_with_deleted(&MemberPrimary { id: 1 }).await?);
+    assert!(db.exists_only_deleted(&MemberPrimary { id: 1 }).await?);
+    assert!(!db.exists_only_deleted(&MemberPrimary { id: 2 }).await?);
+
+    let selection = MemberSelectedEntity::full_fields();
+    let member: Option<MemberSelectedEntity> = db.select(&selection, &MemberPrimary { id: 1 }).await?;
+    assert!(member.is_none());
+    let member: Option<MemberSelectedEntity> = db.select_with_deleted(&selection, &MemberPrimary { id: 1 }).await?;
+    assert_eq!(member.unwrap().deleted, Optional::Some(true));
+    let member: Option<MemberSelectedEntity> = db.select_only_deleted(&selection, &MemberPrimary { id: 2 }).await?;
+    assert!(member.is_none());
+
+    let location = MemberColumns::id().ge(1);
+    assert_eq!(db.count(&location).await?, 2);
+    assert_eq!(db.count_with_deleted(&location).await?, 3);
+    assert_eq!(db.count_only_deleted(&location).await?, 1);
+    let order_by = MemberOrdering::build(["id"]).unwrap();
+    let members: Vec<MemberSelectedEntity> = db.search(&selection, &location, &Some(&order_by), &None).await?;
+    assert_eq!(members.iter().map(|m| m.id.unwrap()).collect::<Vec<i64>>(), vec![2, 3]);
+    let members: Vec<MemberSelectedEntity> = db.search_only_deleted(&selection, &location, &None, &None).await?;
+    assert_eq!(members.len(), 1);
+    let members: Vec<MemberSelectedEntity> = db.devour(&selection, &None, &None).await?;
+    assert_eq!(members.len(), 2);
+    let members: Vec<MemberSelectedEntity> = db.devour_with_deleted(&selection, &None, &None).await?;
+    assert_eq!(members.len(), 3);
+    let members: Vec<MemberSelectedEntity> = db.devour_only_deleted(&selection, &None, &None).await?;
+    assert_eq!(members.len(), 1);
+
+    assert_eq!(db.purify(&MemberColumns::name().eq("b".to_string())).await?, 1);
+    assert_eq!(db.count(&location).await?, 1);
+
+    // deleted row is restored by update of the #[soft_delete] field
+    let mutation = MemberMutation { deleted: Assign::Set(false), ..Default::default() };
+    assert!(db.update(&mutation, &MemberPrimary { id: 1 }).await?);
+    assert!(db.exists(&MemberPrimary { id: 1 }).await?);
+
+    assert!(db.hard_delete(&MemberPrimary { id: 1 }).await?);
+    assert!(!db.exists_with_deleted(&MemberPrimary { id: 1 }).await?);
+    assert_eq!(db.hard_purify(&location).await?, 2);
+    assert_eq!(db.count_all("member").await?, 0);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn soft_delete_timestamp_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("soft_delete_timestamp_spec.db").await?;
+    recreate_table(
+        &mut db,
+        "ticket",
+        "CREATE TABLE `ticket`(`id` BIGINT PRIMARY KEY, `title` VARCHAR(64), `deleted_at` DATETIME)",
+    )
+    .await?;
+    for id in 1..=3 {
+        db.insert(&Ticket { id, title: format!("ticket-{}", id), deleted_at: Optional::None }).await?;
+    }
+
+    let mut trx = db.transaction().await?;
+    assert_eq!(trx.purify(&TicketColumns::id().le(2)).await?, 2);
+    assert_eq!(trx.count(&TicketColumns::id().ge(1)).await?, 1);
+    trx.commit().await?;
+
+    let deleted_at: Option<PrimitiveDateTime> =
+        db.fetch_scalar("SELECT `deleted_at` FROM `ticket` WHERE `id` = 1", Default::default()).await?;
+    assert!(deleted_at.is_some());
+    let selection = TicketSelectedEntity::full_fields();
+    let tickets: Vec<TicketSelectedEntity> = db.devour(&selection, &None, &None).await?;
+    assert_eq!(tickets.len(), 1);
+    assert_eq!(tickets[0].id, Optional::Some(3));
+    let tickets: Vec<TicketSelectedEntity> = db.search_only_deleted(&selection, &TicketColumns::id().ge(1), &None, &None).await?;
+    assert_eq!(tickets.len(), 2);
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn soft_delete_joined_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("soft_delete_joined_spec.db").await?;
+    recreate_table(
+        &mut db,
+        "member",
+        "CREATE TABLE `member`(`id` BIGINT PRIMARY KEY, `name` VARCHAR(64), `deleted` BOOLEAN NOT NULL)",
+    )
+    .await?;
+    recreate_table(
+        &mut db,
+        "ticket",
+        "CREATE TABLE `ticket`(`id` BIGINT PRIMARY KEY, `title` VARCHAR(64), `deleted_at` DATETIME)",
+    )
+    .await?;
+    for id in 1..=3 {
+        db.insert(&Member { id, name: format!("member-{}", id), deleted: false }).await?;
+        db.insert(&Ticket { id, title: format!("ticket-{}", id), deleted_at: Optional::None }).await?;
+    }
+    assert!(db.delete(&MemberPrimary { id: 1 }).await?);
+    assert!(db.delete(&TicketPrimary { id: 2 }).await?);
+
+    let joined_conds = JoinedConditions::Two(JoinedCondition::new(
+        JoinedMode::Inner,
+        "member",
+        "ticket",
+        vec![(JoinedField::new("member", "id"), JoinedField::new("ticket", "id"))],
+    ));
+    let selection = (MemberSelectedEntity::full_fields(), TicketSelectedEntity::full_fields());
+    let location = MemberColumns::id().ge(1);
+    assert_eq!(
+        taitan_orm::DefaultSqlGenerator::new().get_joined_count_sql(&joined_conds, &[&location], &[&selection.0, &selection.1]),
+        "SELECT COUNT(1) AS `count` FROM `member` INNER JOIN `ticket` ON `member`.`id` = `ticket`.`id` \
+         WHERE (`member`.`id`>=?) AND `member`.`deleted` IS NOT TRUE AND `ticket`.`deleted_at` IS NULL"
+    );
+
+    // deleted rows of both tables are skipped, also without location
+    let rows: Vec<(MemberSelectedEntity, TicketSelectedEntity)> =
+        db.search_joined(&joined_conds, &[&location], &selection, &None).await?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].0.id, Optional::Some(3));
+    assert_eq!(rows[0].1.id, Optional::Some(3));
+    let rows: Vec<(MemberSelectedEntity, TicketSelectedEntity)> =
+        db.search_joined(&joined_conds, &[], &selection, &None).await?;
+    assert_eq!(rows.len(), 1);
+
+    let order_by = MemberOrdering::build(["id"]).unwrap();
+    let page = Pagination::new(10, 0);
+    let paged = db.search_joined_paged(&joined_conds, &[&location], &selection, &order_by, &page).await?;
+    assert_eq!(paged.page.total, 1);
+    assert_eq!(paged.data.len(), 1);
+    Ok(())
+}
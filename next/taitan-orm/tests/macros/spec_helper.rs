@@ -0,0 +1,31 @@
+use taitan_orm::database::sqlite::{SqliteDatabase, SqliteLocalConfig};
+use taitan_orm::SqlExecutor;
+
+// every spec works on its own db file under ./workspace, so specs can run in parallel
+pub async fn build_sqlite(db_file: &str) -> taitan_orm::Result<SqliteDatabase> {
+    let config = SqliteLocalConfig {
+        work_dir: "./workspace".into(),
+        db_file: db_file.into(),
+    };
+    SqliteDatabase::build(config).await
+}
+
+// drop the table left by the previous run and create it again
+pub async fn recreate_table(db: &mut SqliteDatabase, table_name: &str, create_sql: &str) -> taitan_orm::Result<()> {
+    db.execute_plain(&format!("DROP TABLE IF EXISTS `{}`", table_name)).await?;
+    db.execute_plain(create_sql).await?;
+    Ok(())
+}
+
+// table of ArticleEntity in create_spec, shared by the specs writing and reading articles
+pub async fn prepare_article_table(db: &mut SqliteDatabase) -> taitan_orm::Result<()> {
+    recreate_table(
+        db,
+        "article",
+        "CREATE TABLE `article`\
+    (`id` INTEGER PRIMARY KEY AUTOINCREMENT, \
+    `title` VARCHAR(64), \
+    `view_count` BIGINT DEFAULT 7)",
+    )
+    .await
+}
@@ -0,0 +1,68 @@
+use futures::TryStreamExt;
+use sqlx::sqlx_macros;
+use taitan_orm::{ReaderApi, TemplateApi, WriterApi};
+use taitan_orm_macro::TemplateRecord;
+use taitan_orm_trait::{CmpOperator, LocationExpr, Optional, Selection};
+
+use super::create_spec::{ArticleEntity, ArticleLocation, ArticleOrdering, ArticleSelectedEntity};
+use super::spec_helper::{build_sqlite, prepare_article_table};
+
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT * FROM `article` WHERE `id` > #{id} ORDER BY `id`"]
+pub struct ArticleAfterTemplate {
+    id: i64,
+}
+
+#[sqlx_macros::test]
+pub async fn stream_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("stream_spec.db").await?;
+    prepare_article_table(&mut db).await?;
+    let articles: Vec<ArticleEntity> = (0..10)
+        .map(|i| ArticleEntity {
+            id: Optional::None,
+            title: format!("article-{}", i),
+            views: Optional::None,
+        })
+        .collect();
+    db.insert_all(&articles).await?;
+
+    let selection = ArticleSelectedEntity::full_fields();
+    let order_by = ArticleOrdering::build(["id"]).unwrap();
+    let location = ArticleLocation {
+        id: Optional::Some(LocationExpr::new(CmpOperator::GreaterThan, 5)),
+        ..Default::default()
+    };
+
+    let mut stream = db.search_stream(&selection, &location, &Some(&order_by))?;
+    let mut ids = Vec::new();
+    while let Some(article) = stream.try_next().await? {
+        ids.push(article.id.unwrap());
+    }
+    drop(stream);
+    assert_eq!(ids, vec![6, 7, 8, 9, 10]);
+
+    let articles: Vec<ArticleSelectedEntity> = db
+        .devour_stream(&selection, &Some(&order_by))?
+        .try_collect()
+        .await?;
+    assert_eq!(articles.len(), 10);
+    assert_eq!(articles[0].title, Optional::Some("article-0".to_string()));
+
+    let template = ArticleAfterTemplate { id: 8 };
+    let articles: Vec<ArticleSelectedEntity> =
+        db.fetch_stream_by_template(&template)?.try_collect().await?;
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[1].views, Optional::Some(7));
+
+    let mut trx = db.transaction().await?;
+    let articles: Vec<ArticleSelectedEntity> = trx
+        .search_stream(&selection, &location, &Some(&order_by))?
+        .try_collect()
+        .await?;
+    assert_eq!(articles.len(), 5);
+    let articles: Vec<ArticleSelectedEntity> =
+        trx.fetch_stream_by_template(&template)?.try_collect().await?;
+    assert_eq!(articles.len(), 2);
+    trx.commit().await?;
+    Ok(())
+}
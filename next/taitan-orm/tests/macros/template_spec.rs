@@ -1,6 +1,6 @@
 use sqlx::sqlx_macros;
 use std::borrow::Cow;
-use taitan_orm::Schema;
+use taitan_orm::SqlGenerator;
 use taitan_orm_macro::TemplateRecord;
 use taitan_orm_trait::TemplateRecord;
 use time::PrimitiveDateTime;
@@ -44,42 +44,143 @@ pub struct TestTemplate5<'a> {
     page: Pagination,
 }
 
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT u.name, '#{not_bound} {{ }}' AS tag -- ${not_rendered}
+    FROM ${table} u WHERE u.id != #{id} AND u.age::text || '?' <> #{age}"]
+pub struct TestTemplate6<'a> {
+    table: Cow<'a, str>,
+    id: i64,
+    age: String,
+}
+
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT * FROM ${table} ORDER BY ${order_field} ${direction}"]
+pub struct TestTemplate7<'a> {
+    table: &'a str,
+    #[raw(one_of = "name, age")]
+    order_field: &'a str,
+    #[raw(keyword_of = "ASC, DESC")]
+    direction: &'a str,
+}
+
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT 'it\\'s {% endraw %} #{not_bound}' AS tag, {#a} FROM ${table} WHERE id = #{id}"]
+#[backslash_escape]
+pub struct TestTemplate8<'a> {
+    table: &'a str,
+    id: i64,
+}
+
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT * FROM ${table} WHERE path = 'C:\\' AND id = #{id}"]
+pub struct TestTemplate9<'a> {
+    table: &'a str,
+    id: i64,
+}
+#[derive(TemplateRecord, Clone, Debug)]
+#[sql = "SELECT * FROM `user` WHERE name = '?' -- ?
+    AND id = #{id} AND age > #{age}"]
+pub struct TestTemplate10 {
+    id: i64,
+    age: i32,
+}
 
 #[sqlx_macros::test]
 pub async fn template_macro_spec() -> taitan_orm::Result<()> {
     let template = TestTemplate1 {
         name: Cow::Borrowed("wang"),
     };
-    let sql = template.get_sql(None);
-    assert_eq!(sql, "select * from wang");
+    let sql = template.get_sql(None)?;
+    assert_eq!(sql, "select * from `wang`");
 
     let template = TestTemplate2 {
         name: String::from("wang"),
     };
-    let sql = template.get_sql(None);
+    let sql = template.get_sql(None)?;
     assert_eq!(sql, "select * from ?");
 
     let template = TestTemplate3 {
         name: Cow::Borrowed("wang"),
         age: 23,
     };
-    let sql = template.get_sql(None);
-    assert_eq!(sql, "select * from wang ?");
+    let sql = template.get_sql(None)?;
+    assert_eq!(sql, "select * from `wang` ?");
 
     let template = TestTemplate4 {
         name: Cow::Borrowed("wang"),
         age: 23,
     };
-    let sql = template.get_sql(None);
-    assert_eq!(sql, "select * from wang ? 'hello'");
+    let sql = template.get_sql(None)?;
+    assert_eq!(sql, "select * from `wang` ? 'hello'");
 
     let template = TestTemplate5 {
         name: Cow::Borrowed("wang"),
         age: 23,
         page: Pagination::new(100, 200)
     };
-    let sql = template.get_sql(None);
-    assert_eq!(sql, "select * from wang ? \"hello ${name}\" ? LIMIT ? ?");
+    let sql = template.get_sql(None)?;
+    assert_eq!(sql, "select * from `wang` ? \"hello ${name}\" ? LIMIT ? ?");
+
+    // sql is kept verbatim, only variables outside of strings and comments are replaced
+    let template = TestTemplate6 {
+        table: Cow::Borrowed("user"),
+        id: 1,
+        age: "23".to_string(),
+    };
+    let sql = template.get_sql(None)?;
+    assert_eq!(
+        sql,
+        "SELECT u.name, '#{not_bound} {{ }}' AS tag -- ${not_rendered}\n    FROM `user` u WHERE u.id != ? AND u.age::text || '?' <> ?"
+    );
+    assert_eq!(template.get_variables(), vec!["id", "age"]);
+
+    // raw value which is not an identifier can not be rendered
+    let template = TestTemplate1 {
+        name: Cow::Borrowed("user; DROP TABLE user"),
+    };
+    assert!(template.render_sql(None, '`').is_err());
+    assert!(template.get_sql(None).is_err());
+
+    // raw value is quoted by wrap char of the dialect, even if whitelisted by one_of, only keyword_of is rendered verbatim
+    let template = TestTemplate7 {
+        table: "public.user",
+        order_field: "name",
+        direction: "DESC",
+    };
+    let sql = template.render_sql(None, '"').unwrap();
+    assert_eq!(sql, "SELECT * FROM \"public\".\"user\" ORDER BY \"name\" DESC");
+    let template = TestTemplate7 {
+        table: "public.user",
+        order_field: "name",
+        direction: "DESC; DROP TABLE user",
+    };
+    assert!(template.render_sql(None, '"').is_err());
+    let template = TestTemplate7 {
+        table: "public.user",
+        order_field: "id",
+        direction: "DESC",
+    };
+    assert!(template.render_sql(None, '"').is_err());
+
+    // with #[backslash_escape] backslash escaped quote does not end the string, text like rinja tags is kept verbatim
+    let template = TestTemplate8 { table: "user", id: 1 };
+    assert_eq!(
+        template.get_sql(None)?,
+        "SELECT 'it\\'s {% endraw %} #{not_bound}' AS tag, {#a} FROM `user` WHERE id = ?"
+    );
+    assert_eq!(template.get_variables(), vec!["id"]);
+    // without it a backslash is an ordinary char
+    let template = TestTemplate9 { table: "file", id: 1 };
+    assert_eq!(template.get_sql(None)?, "SELECT * FROM `file` WHERE path = 'C:\\' AND id = ?");
+    assert_eq!(template.get_variables(), vec!["id"]);
+
+    // postgres numbers only the placeholders, ? in strings and comments is kept
+    let template = TestTemplate10 { id: 1, age: 18 };
+    let generator = taitan_orm::DefaultSqlGenerator::new();
+    assert_eq!(
+        generator.pg_post_process(template.get_sql(None)?),
+        "SELECT * FROM `user` WHERE name = '?' -- ?\n    AND id = $1 AND age > $2"
+    );
 
     Ok(())
 }
@@ -0,0 +1,118 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::{Assign, Schema, SqlExecutor, SqlGenerator, WriterApi};
+use taitan_orm_trait::Optional;
+use time::macros::datetime;
+use time::{OffsetDateTime, PrimitiveDateTime};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "post"]
+pub struct Post {
+    #[primary_key]
+    pub id: i64,
+
+    pub title: String,
+
+    #[created_at]
+    pub created_at: Optional<PrimitiveDateTime>,
+
+    #[updated_at]
+    pub updated_at: Optional<OffsetDateTime>,
+}
+
+const OLD_TIME: PrimitiveDateTime = datetime!(2000-01-01 0:00);
+
+async fn select_times(db: &mut SqliteDatabase, id: i64) -> taitan_orm::Result<(PrimitiveDateTime, OffsetDateTime)> {
+    let sql = format!("SELECT `created_at`, `updated_at` FROM `post` WHERE `id` = {}", id);
+    db.fetch_tuple(&sql, Default::default()).await
+}
+
+async fn reset_updated_at(db: &mut SqliteDatabase) -> taitan_orm::Result<()> {
+    db.execute_plain("UPDATE `post` SET `updated_at` = '2000-01-01T00:00:00Z'").await?;
+    Ok(())
+}
+
+#[sqlx_macros::test]
+pub async fn timestamp_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("timestamp_spec.db").await?;
+    recreate_table(
+        &mut db,
+        "post",
+        "CREATE TABLE `post`(`id` BIGINT PRIMARY KEY, `title` VARCHAR(64), `created_at` DATETIME, `updated_at` DATETIME)",
+    )
+    .await?;
+
+    // unset timestamps are written with the current time
+    let post = Post { id: 1, title: "first".to_string(), created_at: Optional::None, updated_at: Optional::Null };
+    assert!(db.insert(&post).await?);
+    let (created_at, updated_at) = select_times(&mut db, 1).await?;
+    assert!(created_at > OLD_TIME);
+    assert!(updated_at > OLD_TIME.assume_utc());
+    // insert borrows the entity, so it is kept as it is
+    assert_eq!(post.created_at, Optional::None);
+
+    // created_at is never overwritten by upsert
+    reset_updated_at(&mut db).await?;
+    assert!(db.upsert(&Post { title: "second".to_string(), ..post.clone() }).await?);
+    let (upserted_created_at, updated_at) = select_times(&mut db, 1).await?;
+    assert_eq!(upserted_created_at, created_at);
+    assert!(updated_at > OLD_TIME.assume_utc());
+
+    // explicit values are written as they are, such as rows imported from another system
+    let imported = Post {
+        id: 3,
+        title: "imported".to_string(),
+        created_at: Optional::Some(OLD_TIME),
+        updated_at: Optional::Some(OLD_TIME.assume_utc()),
+    };
+    assert!(db.insert(&imported).await?);
+    assert_eq!(select_times(&mut db, 3).await?, (OLD_TIME, OLD_TIME.assume_utc()));
+    assert!(db.upsert(&Post { title: "re-imported".to_string(), ..imported.clone() }).await?);
+    assert_eq!(select_times(&mut db, 3).await?, (OLD_TIME, OLD_TIME.assume_utc()));
+
+    // create fills the entity with the written timestamps
+    let mut created = Post { id: 4, title: "created".to_string(), created_at: Optional::None, updated_at: Optional::None };
+    assert!(db.create(&mut created).await?);
+    let (created_at, updated_at) = select_times(&mut db, 4).await?;
+    assert_eq!(created.created_at, Optional::Some(created_at));
+    assert_eq!(created.updated_at, Optional::Some(updated_at));
+    let mut kept = Post { id: 5, created_at: Optional::Some(OLD_TIME), ..created.clone() };
+    assert!(db.create(&mut kept).await?);
+    assert_eq!(kept.created_at, Optional::Some(OLD_TIME));
+    assert_eq!(select_times(&mut db, 5).await?.0, OLD_TIME);
+
+    let mutation = PostMutation { title: Assign::Set("third".to_string()), ..Default::default() };
+    assert_eq!(
+        taitan_orm::DefaultSqlGenerator::new().get_update_sql(&mutation, &PostPrimary { id: 1 }),
+        "UPDATE `post` SET `title` = ?,`updated_at` = ? WHERE `id` = ?"
+    );
+    reset_updated_at(&mut db).await?;
+    assert!(db.update(&mutation, &PostPrimary { id: 1 }).await?);
+    let (_, updated_at) = select_times(&mut db, 1).await?;
+    assert!(updated_at > OLD_TIME.assume_utc());
+
+    // rows of one insert_all are written with the same timestamps
+    let posts: Vec<Post> = (6..9).map(|id| Post { id, ..post.clone() }).collect();
+    assert_eq!(db.insert_all(&posts).await?, 3);
+    let times: Vec<(PrimitiveDateTime, OffsetDateTime)> = db
+        .fetch_tuples("SELECT `created_at`, `updated_at` FROM `post` WHERE `id` >= 6", Default::default())
+        .await?;
+    assert_eq!(times.len(), 3);
+    assert!(times.iter().all(|time| *time == times[0]));
+    assert_eq!(times[0].0, times[0].1.date().with_time(times[0].1.time()));
+    assert_eq!(posts[0].created_at, Optional::None);
+
+    db.insert(&Post { id: 2, ..post.clone() }).await?;
+    reset_updated_at(&mut db).await?;
+    assert_eq!(db.change(&PostMutation::default(), &PostColumns::id().le(2)).await?, 2);
+    let (_, updated_at) = select_times(&mut db, 2).await?;
+    assert!(updated_at > OLD_TIME.assume_utc());
+
+    // an explicit assignment is written as it is
+    let mutation = PostMutation { updated_at: Assign::Set(OLD_TIME.assume_utc()), ..Default::default() };
+    assert!(db.update(&mutation, &PostPrimary { id: 2 }).await?);
+    let (_, updated_at) = select_times(&mut db, 2).await?;
+    assert_eq!(updated_at, OLD_TIME.assume_utc());
+    Ok(())
+}
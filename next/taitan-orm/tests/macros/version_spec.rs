@@ -0,0 +1,90 @@
+use sqlx::sqlx_macros;
+use taitan_orm::database::sqlite::SqliteDatabase;
+use taitan_orm::{Assign, SqlExecutor, SqlGenerator, TaitanOrmError, WriterApi};
+use taitan_orm::Schema;
+use taitan_orm_trait::{Mutation, Optional};
+use super::spec_helper::{build_sqlite, recreate_table};
+
+#[derive(Schema, Clone, Debug)]
+#[table_name = "document"]
+pub struct Document {
+    #[primary_key]
+    pub id: i64,
+
+    pub title: String,
+
+    #[version]
+    pub version: i64,
+}
+
+async fn select_version(db: &mut SqliteDatabase, id: i64) -> taitan_orm::Result<i64> {
+    let sql = format!("SELECT `version` FROM `document` WHERE `id` = {}", id);
+    db.fetch_scalar(&sql, Default::default()).await
+}
+
+#[sqlx_macros::test]
+pub async fn version_spec() -> taitan_orm::Result<()> {
+    let mut db = build_sqlite("version_spec.db").await?;
+    recreate_table(
+        &mut db,
+        "document",
+        "CREATE TABLE `document`(`id` BIGINT PRIMARY KEY, `title` VARCHAR(64), `version` BIGINT)",
+    )
+    .await?;
+    for id in 1..=3 {
+        db.insert(&Document { id, title: "draft".to_string(), version: 0 }).await?;
+    }
+
+    let mutation = DocumentMutation { title: Assign::Set("first".to_string()), version: Some(0) };
+    assert_eq!(mutation.get_version_field_name(), Some("version"));
+    assert_eq!(
+        taitan_orm::DefaultSqlGenerator::new().get_update_sql(&mutation, &DocumentPrimary { id: 1 }),
+        "UPDATE `document` SET `title` = ?,`version` = `version` + 1 WHERE `id` = ? AND `version` = ?"
+    );
+    assert!(db.update(&mutation, &DocumentPrimary { id: 1 }).await?);
+    assert_eq!(select_version(&mut db, 1).await?, 1);
+
+    // the row has been updated with version 0, so the same mutation is stale now
+    let result = db.update(&mutation, &DocumentPrimary { id: 1 }).await;
+    assert!(matches!(result, Err(TaitanOrmError::StaleVersion)));
+    assert_eq!(select_version(&mut db, 1).await?, 1);
+
+    // missing row is not a conflict
+    assert!(!db.update(&mutation, &DocumentPrimary { id: 10 }).await?);
+
+    // version is increased even if it is not checked
+    let mutation = DocumentMutation { title: Assign::Set("second".to_string()), ..Default::default() };
+    assert!(!mutation.is_version_checked());
+    assert!(db.update(&mutation, &DocumentPrimary { id: 1 }).await?);
+    assert_eq!(select_version(&mut db, 1).await?, 2);
+
+    // change skips rows with other versions
+    assert!(db.update(&DocumentMutation::default(), &DocumentPrimary { id: 2 }).await?);
+    let mutation = DocumentMutation { title: Assign::Set("third".to_string()), version: Some(0) };
+    assert_eq!(
+        taitan_orm::DefaultSqlGenerator::new().get_change_sql(&mutation, &DocumentColumns::id().ge(2)),
+        "UPDATE `document` SET `title` = ?,`version` = `version` + 1 WHERE (`id`>=?) AND `version` = ?"
+    );
+    assert_eq!(db.change(&mutation, &DocumentColumns::id().ge(2)).await?, 1);
+    assert_eq!(select_version(&mut db, 2).await?, 1);
+    assert_eq!(select_version(&mut db, 3).await?, 1);
+
+    // version check is the whole where clause of an empty location
+    let mutation = DocumentMutation { title: Assign::Set("all".to_string()), version: Some(1) };
+    assert_eq!(
+        taitan_orm::DefaultSqlGenerator::new().get_change_sql(&mutation, &DocumentLocation::default()),
+        "UPDATE `document` SET `title` = ?,`version` = `version` + 1 WHERE `version` = ?"
+    );
+    let mut trx = db.transaction().await?;
+    assert_eq!(trx.change(&mutation, &DocumentLocation::default()).await?, 2);
+    trx.rollback().await?;
+
+    let mut trx = db.transaction().await?;
+    let mutation = DocumentMutation { title: Assign::Set("fourth".to_string()), version: Some(1) };
+    assert!(trx.update(&mutation, &DocumentPrimary { id: 3 }).await?);
+    let result = trx.update(&mutation, &DocumentPrimary { id: 3 }).await;
+    assert!(matches!(result, Err(TaitanOrmError::StaleVersion)));
+    trx.commit().await?;
+    assert_eq!(select_version(&mut db, 3).await?, 2);
+    Ok(())
+}
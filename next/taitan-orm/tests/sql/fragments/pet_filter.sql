@@ -0,0 +1,2 @@
+-- pets older than age, except the named one
+`age` >= #{min_age} AND `name` != #{name}
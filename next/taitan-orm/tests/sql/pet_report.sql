@@ -0,0 +1,4 @@
+SELECT `id`, `name`, `age`
+FROM `pet`
+WHERE {% include "tests/sql/fragments/pet_filter.sql" %}
+ORDER BY `age` DESC, `id`
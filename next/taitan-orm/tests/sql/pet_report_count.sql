@@ -0,0 +1,3 @@
+SELECT COUNT(*) AS `count`
+FROM `pet`
+WHERE {% include "tests/sql/fragments/pet_filter.sql" %}